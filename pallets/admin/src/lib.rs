@@ -31,7 +31,8 @@ use frame_support::{
   traits::EnsureOrigin,
 };
 use sp_std::vec::Vec;
-use pallet_network::{MinNodesCurveParametersSet};
+use pallet_network::{MinNodesCurveParametersSet, ProposalReason, SubnetMetadata, TreasurySplit, UnpaidEmissionsPolicy};
+use sp_std::collections::btree_set::BTreeSet;
 
 #[cfg(test)]
 mod mock;
@@ -214,27 +215,21 @@ pub mod pallet {
     #[pallet::weight(0)]
     pub fn set_max_subnet_rewards_weight(origin: OriginFor<T>, value: u128) -> DispatchResult {
       ensure_root(origin)?;
-      // T::NetworkAdminInterface::set_max_subnet_rewards_weight(value)
-      // Update
-      Ok(())
+      T::NetworkAdminInterface::set_max_subnet_rewards_weight(value)
     }
     
     #[pallet::call_index(17)]
     #[pallet::weight(0)]
     pub fn set_stake_reward_weight(origin: OriginFor<T>, value: u128) -> DispatchResult {
       ensure_root(origin)?;
-      // T::NetworkAdminInterface::set_stake_reward_weight(value)
-      // Update
-      Ok(())
+      T::NetworkAdminInterface::set_stake_reward_weight(value)
     }
 
     #[pallet::call_index(18)]
     #[pallet::weight(0)]
     pub fn set_subnet_per_peer_init_cost(origin: OriginFor<T>, value: u128) -> DispatchResult {
       ensure_root(origin)?;
-      // T::NetworkAdminInterface::set_subnet_per_peer_init_cost(value)
-      // Update
-      Ok(())
+      T::NetworkAdminInterface::set_subnet_per_peer_init_cost(value)
     }
 
     #[pallet::call_index(19)]
@@ -290,5 +285,173 @@ pub mod pallet {
       T::NetworkAdminInterface::set_min_nodes_slope_parameters(params);
       Ok(())
     }
+
+    #[pallet::call_index(25)]
+    #[pallet::weight(0)]
+    pub fn set_subnet_proposal_reasons(origin: OriginFor<T>, subnet_id: u32, reasons: BTreeSet<ProposalReason>) -> DispatchResult {
+      T::CollectiveOrigin::ensure_origin(origin)?;
+      T::NetworkAdminInterface::set_subnet_proposal_reasons(subnet_id, reasons)
+    }
+
+    #[pallet::call_index(26)]
+    #[pallet::weight(0)]
+    pub fn set_subnet_proposal_reason_attests(origin: OriginFor<T>, subnet_id: u32, reason: ProposalReason, value: u32) -> DispatchResult {
+      T::CollectiveOrigin::ensure_origin(origin)?;
+      T::NetworkAdminInterface::set_subnet_proposal_reason_attests(subnet_id, reason, value)
+    }
+
+    #[pallet::call_index(27)]
+    #[pallet::weight(0)]
+    pub fn resolve_proposal_appeal(origin: OriginFor<T>, subnet_id: u32, proposal_id: u32, uphold: bool) -> DispatchResult {
+      T::CollectiveOrigin::ensure_origin(origin)?;
+      T::NetworkAdminInterface::council_resolve_proposal_appeal(subnet_id, proposal_id, uphold)
+    }
+
+    #[pallet::call_index(28)]
+    #[pallet::weight(0)]
+    pub fn set_unpaid_emissions_policy(origin: OriginFor<T>, value: UnpaidEmissionsPolicy) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_unpaid_emissions_policy(value)
+    }
+
+    #[pallet::call_index(29)]
+    #[pallet::weight(0)]
+    pub fn set_rewards_receipt_retention_epochs(origin: OriginFor<T>, value: u32) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_rewards_receipt_retention_epochs(value)
+    }
+
+    #[pallet::call_index(30)]
+    #[pallet::weight(0)]
+    pub fn set_epoch_processing_nodes_per_block(origin: OriginFor<T>, value: u32) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_epoch_processing_nodes_per_block(value)
+    }
+
+    #[pallet::call_index(31)]
+    #[pallet::weight(0)]
+    pub fn set_treasury_split(origin: OriginFor<T>, value: TreasurySplit) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_treasury_split(value)
+    }
+
+    #[pallet::call_index(32)]
+    #[pallet::weight(0)]
+    pub fn treasury_spend(origin: OriginFor<T>, beneficiary: T::AccountId, amount: u128) -> DispatchResult {
+      T::CollectiveOrigin::ensure_origin(origin)?;
+      T::NetworkAdminInterface::council_treasury_spend(beneficiary, amount)
+    }
+
+    #[pallet::call_index(33)]
+    #[pallet::weight(0)]
+    pub fn set_min_subnet_registration_cost(origin: OriginFor<T>, value: u128) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_min_subnet_registration_cost(value)
+    }
+
+    #[pallet::call_index(34)]
+    #[pallet::weight(0)]
+    pub fn set_subnet_registration_cost_multiplier(origin: OriginFor<T>, value: u128) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_subnet_registration_cost_multiplier(value)
+    }
+
+    #[pallet::call_index(35)]
+    #[pallet::weight(0)]
+    pub fn set_subnet_registration_cost_decay_blocks(origin: OriginFor<T>, value: u64) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_subnet_registration_cost_decay_blocks(value)
+    }
+
+    #[pallet::call_index(36)]
+    #[pallet::weight(0)]
+    pub fn set_subnet_teardown_items_per_call(origin: OriginFor<T>, value: u32) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_subnet_teardown_items_per_call(value)
+    }
+
+    #[pallet::call_index(37)]
+    #[pallet::weight(0)]
+    pub fn set_subnet_owner_deposit(origin: OriginFor<T>, value: u128) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_subnet_owner_deposit(value)
+    }
+
+    #[pallet::call_index(38)]
+    #[pallet::weight(0)]
+    pub fn set_subnet_metadata(origin: OriginFor<T>, subnet_id: u32, metadata: SubnetMetadata) -> DispatchResult {
+      T::CollectiveOrigin::ensure_origin(origin)?;
+      T::NetworkAdminInterface::council_set_subnet_metadata(subnet_id, metadata)
+    }
+
+    #[pallet::call_index(39)]
+    #[pallet::weight(0)]
+    pub fn set_subnet_node_params_update_interval(origin: OriginFor<T>, value: u64) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_subnet_node_params_update_interval(value)
+    }
+
+    #[pallet::call_index(40)]
+    #[pallet::weight(0)]
+    pub fn set_max_subnet_node_deactivation_epochs(origin: OriginFor<T>, value: u64) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_max_subnet_node_deactivation_epochs(value)
+    }
+
+    #[pallet::call_index(41)]
+    #[pallet::weight(0)]
+    pub fn set_subnet_permissioned(origin: OriginFor<T>, subnet_id: u32, permissioned: bool) -> DispatchResult {
+      T::CollectiveOrigin::ensure_origin(origin)?;
+      T::NetworkAdminInterface::council_set_subnet_permissioned(subnet_id, permissioned)
+    }
+
+    #[pallet::call_index(42)]
+    #[pallet::weight(0)]
+    pub fn set_account_penalty_ban_epochs(origin: OriginFor<T>, value: u64) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_account_penalty_ban_epochs(value)
+    }
+
+    #[pallet::call_index(43)]
+    #[pallet::weight(0)]
+    pub fn set_subnet_pause_bond(origin: OriginFor<T>, value: u128) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_subnet_pause_bond(value)
+    }
+
+    #[pallet::call_index(44)]
+    #[pallet::weight(0)]
+    pub fn set_max_subnet_pause_epochs(origin: OriginFor<T>, value: u64) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_max_subnet_pause_epochs(value)
+    }
+
+    #[pallet::call_index(45)]
+    #[pallet::weight(0)]
+    pub fn set_subnet_paused(origin: OriginFor<T>, subnet_id: u32, paused: bool) -> DispatchResult {
+      T::CollectiveOrigin::ensure_origin(origin)?;
+      T::NetworkAdminInterface::council_set_subnet_paused(subnet_id, paused)
+    }
+
+    #[pallet::call_index(46)]
+    #[pallet::weight(0)]
+    pub fn set_appeal_period(origin: OriginFor<T>, value: u64) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_appeal_period(value)
+    }
+
+    #[pallet::call_index(47)]
+    #[pallet::weight(0)]
+    pub fn set_appeal_bond_amount(origin: OriginFor<T>, value: u128) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_appeal_bond_amount(value)
+    }
+
+    #[pallet::call_index(48)]
+    #[pallet::weight(0)]
+    pub fn set_min_subnet_pause_epochs(origin: OriginFor<T>, value: u64) -> DispatchResult {
+      ensure_root(origin)?;
+      T::NetworkAdminInterface::set_min_subnet_pause_epochs(value)
+    }
   }
 }
\ No newline at end of file
@@ -120,6 +120,8 @@ impl pallet_network::Config for Test {
 	type DelegateStakeEpochsRemovalWindow = DelegateStakeEpochsRemovalWindow;
   type MaxDelegateStakeUnlockings = MaxDelegateStakeUnlockings;
   type MaxStakeUnlockings = MaxStakeUnlockings;
+  type MaxSubnetNodes = ConstU32<1000>;
+  type MaxPathLength = ConstU32<256>;
   type MinProposalStake = MinProposalStake;
 }
 
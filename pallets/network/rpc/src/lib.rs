@@ -41,6 +41,16 @@ pub trait NetworkCustomApi<BlockHash> {
 	fn get_subnet_node_info(&self, subnet_id: u32, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
 	#[method(name = "network_isSubnetNodeByPeerId")]
 	fn is_subnet_node_by_peer_id(&self, subnet_id: u32, peer_id: Vec<u8>, at: Option<BlockHash>) -> RpcResult<bool>;
+	#[method(name = "network_getSubnetEmissionsPreview")]
+	fn get_subnet_emissions_preview(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+	#[method(name = "network_getSubnetRewardsReceipt")]
+	fn get_subnet_rewards_receipt(&self, subnet_id: u32, epoch: u32, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+	#[method(name = "network_getSubnetRegistrationCost")]
+	fn get_subnet_registration_cost(&self, memory_mb: u128, at: Option<BlockHash>) -> RpcResult<u128>;
+	#[method(name = "network_getSubnetMetadata")]
+	fn get_subnet_metadata(&self, subnet_id: u32, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+	#[method(name = "network_getSubnetNodeByParams")]
+	fn get_subnet_node_by_params(&self, subnet_id: u32, a: Vec<u8>, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
 }
 
 /// A struct that implements the `NetworkCustomApi`.
@@ -173,6 +183,41 @@ where
 			Error::RuntimeError(format!("Unable to get minimum subnet nodes: {:?}", e)).into()
 		})
 	}
+	fn get_subnet_emissions_preview(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_subnet_emissions_preview(at).map_err(|e| {
+			Error::RuntimeError(format!("Unable to get subnet emissions preview: {:?}", e)).into()
+		})
+	}
+	fn get_subnet_rewards_receipt(&self, subnet_id: u32, epoch: u32, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_subnet_rewards_receipt(at, subnet_id, epoch).map_err(|e| {
+			Error::RuntimeError(format!("Unable to get subnet rewards receipt: {:?}", e)).into()
+		})
+	}
+	fn get_subnet_registration_cost(&self, memory_mb: u128, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u128> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_subnet_registration_cost(at, memory_mb).map_err(|e| {
+			Error::RuntimeError(format!("Unable to get subnet registration cost: {:?}", e)).into()
+		})
+	}
+	fn get_subnet_metadata(&self, subnet_id: u32, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_subnet_metadata(at, subnet_id).map_err(|e| {
+			Error::RuntimeError(format!("Unable to get subnet metadata: {:?}", e)).into()
+		})
+	}
+	fn get_subnet_node_by_params(&self, subnet_id: u32, a: Vec<u8>, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		api.get_subnet_node_by_params(at, subnet_id, a).map_err(|e| {
+			Error::RuntimeError(format!("Unable to get subnet node by params: {:?}", e)).into()
+		})
+	}
 }
 
 // const RUNTIME_ERROR: i32 = 1;
@@ -32,5 +32,10 @@ sp_api::decl_runtime_apis! {
     fn get_minimum_delegate_stake(memory_mb: u128) -> u128;
     fn get_subnet_node_info(subnet_id: u32) -> Vec<u8>;
     fn is_subnet_node_by_peer_id(subnet_id: u32, peer_id: Vec<u8>) -> bool;
+    fn get_subnet_emissions_preview() -> Vec<u8>;
+    fn get_subnet_rewards_receipt(subnet_id: u32, epoch: u32) -> Vec<u8>;
+    fn get_subnet_registration_cost(memory_mb: u128) -> u128;
+    fn get_subnet_metadata(subnet_id: u32) -> Vec<u8>;
+    fn get_subnet_node_by_params(subnet_id: u32, a: Vec<u8>) -> Vec<u8>;
   }
 }
\ No newline at end of file
@@ -71,6 +71,22 @@ impl<T: Config> Pallet<T> {
     ProposalMinSubnetNodes::<T>::put(value);
     Ok(())
   }
+
+  pub fn set_appeal_period(value: u64) -> DispatchResult {
+    AppealPeriod::<T>::put(value);
+
+    Self::deposit_event(Event::SetAppealPeriod(value));
+
+    Ok(())
+  }
+
+  pub fn set_appeal_bond_amount(value: u128) -> DispatchResult {
+    AppealBondAmount::<T>::put(value);
+
+    Self::deposit_event(Event::SetAppealBondAmount(value));
+
+    Ok(())
+  }
   
   pub fn set_subnet_node_registration_epochs(value: u64) -> DispatchResult {
     SubnetNodeRegistrationEpochs::<T>::put(value);
@@ -107,6 +123,7 @@ impl<T: Config> Pallet<T> {
       initialized: subnet.initialized,
       registration_blocks: subnet.registration_blocks,
       activated: subnet.activated,
+      paused: subnet.paused,
     };
 
     SubnetsData::<T>::insert(subnet_id, subnet_data);
@@ -198,7 +215,7 @@ impl<T: Config> Pallet<T> {
     // for possible updates in future versions
     // * Remove `value <= Self::PERCENTAGE_FACTOR` if never used in mainnet
     ensure!(
-      value <= 1000 && value as u128 <= Self::PERCENTAGE_FACTOR,
+      value <= T::MaxSubnetNodes::get() && value as u128 <= Self::PERCENTAGE_FACTOR,
       Error::<T>::InvalidMaxSubnetNodes
     );
 
@@ -270,14 +287,41 @@ impl<T: Config> Pallet<T> {
   }
 
   pub fn set_max_subnet_rewards_weight(value: u128) -> DispatchResult {
+    ensure!(
+      value > 0 && value <= Self::PERCENTAGE_FACTOR,
+      Error::<T>::InvalidMaxSubnetRewardsWeight
+    );
+
+    MaxSubnetRewardsWeight::<T>::set(value);
+
+    Self::deposit_event(Event::SetMaxSubnetRewardsWeight(value));
+
     Ok(())
   }
 
   pub fn set_stake_reward_weight(value: u128) -> DispatchResult {
+    ensure!(
+      value <= Self::PERCENTAGE_FACTOR,
+      Error::<T>::InvalidStakeRewardWeight
+    );
+
+    StakeRewardWeight::<T>::set(value);
+
+    Self::deposit_event(Event::SetStakeRewardWeight(value));
+
     Ok(())
   }
 
   pub fn set_subnet_per_peer_init_cost(value: u128) -> DispatchResult {
+    ensure!(
+      value > 0,
+      Error::<T>::InvalidSubnetPerNodeInitCost
+    );
+
+    SubnetPerNodeInitCost::<T>::set(value);
+
+    Self::deposit_event(Event::SetSubnetPerNodeInitCost(value));
+
     Ok(())
   }
 
@@ -288,4 +332,207 @@ impl<T: Config> Pallet<T> {
   pub fn set_remove_subnet_node_epoch_percentage(value: u128) -> DispatchResult {
     Ok(())
   }
+
+  pub fn set_subnet_proposal_reasons(subnet_id: u32, reasons: BTreeSet<ProposalReason>) -> DispatchResult {
+    ensure!(
+      SubnetsData::<T>::contains_key(subnet_id),
+      Error::<T>::SubnetNotExist
+    );
+
+    SubnetProposalReasons::<T>::insert(subnet_id, reasons.clone());
+
+    Self::deposit_event(Event::SetSubnetProposalReasons(subnet_id, reasons));
+
+    Ok(())
+  }
+
+  pub fn set_subnet_proposal_reason_attests(subnet_id: u32, reason: ProposalReason, value: u32) -> DispatchResult {
+    ensure!(
+      SubnetsData::<T>::contains_key(subnet_id),
+      Error::<T>::SubnetNotExist
+    );
+
+    // --- Plaintiff and defendant cannot attest so the requirement must be reachable
+    //     by the remaining eligible voters of the smallest subnet that can propose
+    ensure!(
+      value <= ProposalMinSubnetNodes::<T>::get().saturating_sub(2),
+      Error::<T>::InvalidProposalReasonAttests
+    );
+
+    SubnetProposalReasonAttests::<T>::insert(subnet_id, reason, value);
+
+    Self::deposit_event(Event::SetSubnetProposalReasonAttests(subnet_id, reason, value));
+
+    Ok(())
+  }
+
+  pub fn set_unpaid_emissions_policy(value: UnpaidEmissionsPolicy) -> DispatchResult {
+    UnpaidEmissions::<T>::set(value);
+
+    Self::deposit_event(Event::SetUnpaidEmissionsPolicy(value));
+
+    Ok(())
+  }
+
+  pub fn set_rewards_receipt_retention_epochs(value: u32) -> DispatchResult {
+    ensure!(
+      value > 0,
+      Error::<T>::InvalidRewardsReceiptRetentionEpochs
+    );
+
+    RewardsReceiptRetentionEpochs::<T>::set(value);
+
+    Self::deposit_event(Event::SetRewardsReceiptRetentionEpochs(value));
+
+    Ok(())
+  }
+
+  pub fn set_epoch_processing_nodes_per_block(value: u32) -> DispatchResult {
+    // --- A subnet is never split across blocks
+    ensure!(
+      value >= MaxSubnetNodes::<T>::get(),
+      Error::<T>::InvalidEpochProcessingNodesPerBlock
+    );
+
+    EpochProcessingNodesPerBlock::<T>::set(value);
+
+    Self::deposit_event(Event::SetEpochProcessingNodesPerBlock(value));
+
+    Ok(())
+  }
+
+  pub fn set_treasury_split(value: TreasurySplit) -> DispatchResult {
+    ensure!(
+      value.registration_fee <= Self::PERCENTAGE_FACTOR &&
+      value.slash <= Self::PERCENTAGE_FACTOR &&
+      value.proposal_dust <= Self::PERCENTAGE_FACTOR &&
+      value.block_subsidy <= Self::PERCENTAGE_FACTOR,
+      Error::<T>::InvalidTreasurySplit
+    );
+
+    TreasurySplits::<T>::set(value);
+
+    Self::deposit_event(Event::SetTreasurySplit(value));
+
+    Ok(())
+  }
+
+  pub fn set_min_subnet_registration_cost(value: u128) -> DispatchResult {
+    MinSubnetRegistrationCost::<T>::set(value);
+
+    Self::deposit_event(Event::SetMinSubnetRegistrationCost(value));
+
+    Ok(())
+  }
+
+  pub fn set_subnet_registration_cost_multiplier(value: u128) -> DispatchResult {
+    // --- The registration cost must never decrease on registration
+    ensure!(
+      value >= Self::PERCENTAGE_FACTOR,
+      Error::<T>::InvalidSubnetRegistrationCostMultiplier
+    );
+
+    SubnetRegistrationCostMultiplier::<T>::set(value);
+
+    Self::deposit_event(Event::SetSubnetRegistrationCostMultiplier(value));
+
+    Ok(())
+  }
+
+  pub fn set_subnet_registration_cost_decay_blocks(value: u64) -> DispatchResult {
+    ensure!(
+      value > 0,
+      Error::<T>::InvalidSubnetRegistrationCostDecayBlocks
+    );
+
+    SubnetRegistrationCostDecayBlocks::<T>::set(value);
+
+    Self::deposit_event(Event::SetSubnetRegistrationCostDecayBlocks(value));
+
+    Ok(())
+  }
+
+  pub fn set_subnet_teardown_items_per_call(value: u32) -> DispatchResult {
+    ensure!(
+      value > 0,
+      Error::<T>::InvalidSubnetTeardownItemsPerCall
+    );
+
+    SubnetTeardownItemsPerCall::<T>::set(value);
+
+    Self::deposit_event(Event::SetSubnetTeardownItemsPerCall(value));
+
+    Ok(())
+  }
+
+  pub fn set_subnet_owner_deposit(value: u128) -> DispatchResult {
+    SubnetOwnerDeposit::<T>::set(value);
+
+    Self::deposit_event(Event::SetSubnetOwnerDeposit(value));
+
+    Ok(())
+  }
+
+  pub fn set_subnet_node_params_update_interval(value: u64) -> DispatchResult {
+    SubnetNodeParamsUpdateInterval::<T>::set(value);
+
+    Self::deposit_event(Event::SetSubnetNodeParamsUpdateInterval(value));
+
+    Ok(())
+  }
+
+  pub fn set_max_subnet_node_deactivation_epochs(value: u64) -> DispatchResult {
+    ensure!(
+      value > 0,
+      Error::<T>::InvalidMaxSubnetNodeDeactivationEpochs
+    );
+
+    MaxSubnetNodeDeactivationEpochs::<T>::set(value);
+
+    Self::deposit_event(Event::SetMaxSubnetNodeDeactivationEpochs(value));
+
+    Ok(())
+  }
+
+  pub fn set_account_penalty_ban_epochs(value: u64) -> DispatchResult {
+    AccountPenaltyBanEpochs::<T>::set(value);
+
+    Self::deposit_event(Event::SetAccountPenaltyBanEpochs(value));
+
+    Ok(())
+  }
+
+  pub fn set_subnet_pause_bond(value: u128) -> DispatchResult {
+    SubnetPauseBond::<T>::set(value);
+
+    Self::deposit_event(Event::SetSubnetPauseBond(value));
+
+    Ok(())
+  }
+
+  pub fn set_max_subnet_pause_epochs(value: u64) -> DispatchResult {
+    ensure!(
+      value > 0 && value >= MinSubnetPauseEpochs::<T>::get(),
+      Error::<T>::InvalidMaxSubnetPauseEpochs
+    );
+
+    MaxSubnetPauseEpochs::<T>::set(value);
+
+    Self::deposit_event(Event::SetMaxSubnetPauseEpochs(value));
+
+    Ok(())
+  }
+
+  pub fn set_min_subnet_pause_epochs(value: u64) -> DispatchResult {
+    ensure!(
+      value <= MaxSubnetPauseEpochs::<T>::get(),
+      Error::<T>::InvalidMinSubnetPauseEpochs
+    );
+
+    MinSubnetPauseEpochs::<T>::set(value);
+
+    Self::deposit_event(Event::SetMinSubnetPauseEpochs(value));
+
+    Ok(())
+  }
 }
\ No newline at end of file
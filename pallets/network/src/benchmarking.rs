@@ -71,11 +71,30 @@ fn funded_initializer<T: Config>(name: &'static str, index: u32) -> T::AccountId
 	let caller: T::AccountId = account(name, index, SEED);
 	// Give the account half of the maximum value of the `Balance` type.
 	// Otherwise some transfers will fail with an overflow error.
-	let deposit_amount: u128 = Network::<T>::get_subnet_initialization_cost(0) + 1000000;
+	let deposit_amount: u128 = Network::<T>::get_subnet_initialization_cost(0, DEFAULT_SUBNET_MEM_MB) + 1000000;
 	T::Currency::deposit_creating(&caller, deposit_amount.try_into().ok().expect("REASON"));
 	caller
 }
 
+fn register_owned_subnet<T: Config>(subnet_path: Vec<u8>) -> (T::AccountId, u32) {
+	let owner = funded_initializer::<T>("funded_initializer", 0);
+
+	let register_subnet_data = RegistrationSubnetData {
+		path: subnet_path.clone().try_into().unwrap(),
+		memory_mb: DEFAULT_SUBNET_MEM_MB,
+		registration_blocks: DEFAULT_SUBNET_REGISTRATION_BLOCKS,
+	};
+
+	assert_ok!(
+		Network::<T>::register_subnet(
+			RawOrigin::Signed(owner.clone()).into(),
+			register_subnet_data,
+		)
+	);
+
+	(owner, SubnetPaths::<T>::get(subnet_path).unwrap())
+}
+
 fn get_min_subnet_nodes<T: Config>() -> u32 {
 	let base_node_memory: u128 = BaseSubnetNodeMemoryMB::<T>::get();
 	Network::<T>::get_min_subnet_nodes(base_node_memory, DEFAULT_SUBNET_MEM_MB)
@@ -91,7 +110,7 @@ fn build_activated_subnet<T: Config>(
 	let funded_initializer = funded_initializer::<T>("funded_initializer", 0);
 
   let add_subnet_data = RegistrationSubnetData {
-    path: subnet_path.clone().into(),
+    path: subnet_path.clone().try_into().unwrap(),
     memory_mb: DEFAULT_SUBNET_MEM_MB,
     registration_blocks: DEFAULT_SUBNET_REGISTRATION_BLOCKS,
   };
@@ -230,6 +249,68 @@ fn subnet_node_data(start: u32, end: u32) -> Vec<SubnetNodeData> {
   subnet_node_data
 }
 
+// Set the block number to the first block of the next epoch, returns the epoch
+fn set_next_epoch_block<T: Config>() -> u32 {
+	let epoch_length = T::EpochLength::get();
+	let current_block_number = get_current_block_as_u64::<T>();
+	let next_epoch_block = current_block_number - (current_block_number % epoch_length) + epoch_length;
+	frame_system::Pallet::<T>::set_block_number(u64_to_block::<T>(next_epoch_block));
+	(next_epoch_block / epoch_length) as u32
+}
+
+// Build ``s`` activated subnets with ``n`` subnet nodes each
+fn build_activated_subnets<T: Config>(s: u32, n: u32) {
+	for subnet in 0..s {
+		let subnet_path: Vec<u8> = format!("petals-team-{subnet}/StableBeluga2").into();
+		build_activated_subnet::<T>(subnet_path, subnet * n, (subnet + 1) * n, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+	}
+}
+
+// Propose the removal of subnet node ``1`` by subnet node ``0``, challenged and voted for by all other
+// ``n`` subnet nodes, up to the end of the voting period
+fn build_voted_proposal<T: Config>(subnet_id: u32, n: u32) {
+	let proposer = funded_account::<T>("subnet_node_account", 0);
+	let defendant = funded_account::<T>("subnet_node_account", 1);
+	let data: BoundedVec<u8, DefaultProposalEvidenceLimit> = BoundedVec::new();
+
+	assert_ok!(
+		Network::<T>::propose(
+			RawOrigin::Signed(proposer.clone()).into(), 
+			subnet_id, 
+			peer(1), 
+			ProposalReason::Offline,
+			data.clone()
+		)
+	);
+
+	assert_ok!(
+		Network::<T>::challenge_proposal(
+			RawOrigin::Signed(defendant.clone()).into(), 
+			subnet_id, 
+			0, 
+			data.clone()
+		)
+	);
+
+	for i in 2..n {
+		let voter = funded_account::<T>("subnet_node_account", i);
+		assert_ok!(
+			Network::<T>::vote(
+				RawOrigin::Signed(voter.clone()).into(), 
+				subnet_id, 
+				0, 
+				VoteType::Yay
+			)
+		);
+	}
+
+	let voting_period = VotingPeriod::<T>::get();
+	frame_system::Pallet::<T>::set_block_number(
+		frame_system::Pallet::<T>::block_number() + 
+		u64_to_block::<T>(voting_period + 1)
+	);
+}
+
 pub fn u64_to_block<T: frame_system::Config>(input: u64) -> BlockNumberFor<T> {
 	input.try_into().ok().expect("REASON")
 }
@@ -263,7 +344,7 @@ mod benchmarks {
 		let funded_initializer = funded_initializer::<T>("funded_initializer", 0);
 
 		let register_subnet_data = RegistrationSubnetData {
-			path: DEFAULT_SUBNET_PATH.into(),
+			path: DEFAULT_SUBNET_PATH.as_bytes().to_vec().try_into().unwrap(),
 			memory_mb: DEFAULT_SUBNET_MEM_MB,
 			registration_blocks: DEFAULT_SUBNET_REGISTRATION_BLOCKS,
 		};
@@ -294,7 +375,7 @@ mod benchmarks {
 		let amount: u128 = DEFAULT_SUBNET_NODE_STAKE;
 	
 		let register_subnet_data = RegistrationSubnetData {
-			path: DEFAULT_SUBNET_PATH.into(),
+			path: DEFAULT_SUBNET_PATH.as_bytes().to_vec().try_into().unwrap(),
 			memory_mb: DEFAULT_SUBNET_MEM_MB,
 			registration_blocks: DEFAULT_SUBNET_REGISTRATION_BLOCKS,
 		};
@@ -466,38 +547,68 @@ mod benchmarks {
 		assert_eq!(TotalSubnetNodes::<T>::get(subnet_id), end+1);
 	}
 
-	// #[benchmark]
-	// fn deactivate_subnet_node() {
-	// 	let end = 12;
-	// 	build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, end, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
-	// 	let subnet_node_account = funded_account::<T>("subnet_node_account", end+1);
-
-	// 	let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
-	// 	assert_ok!(
-	// 		Network::<T>::register_subnet_node(
-	// 			RawOrigin::Signed(subnet_node_account.clone()).into(), 
-	// 			subnet_id, 
-	// 			peer(end+1), 
-	// 			DEFAULT_SUBNET_NODE_STAKE,
-	// 			None,
-	// 			None,
-	// 			None,
-	// 		) 
-	// 	);
-
-	// 	assert_ok!(
-	// 		Network::<T>::activate_subnet_node(
-	// 			RawOrigin::Signed(subnet_node_account.clone()).into(), 
-	// 			subnet_id, 
-	// 		) 
-	// 	);
-
-	// 	#[extrinsic_call]
-	// 	deactivate_subnet_node(RawOrigin::Signed(subnet_node_account.clone()), subnet_id);
-
-	// 	assert_eq!(TotalSubnetNodes::<T>::get(subnet_id), end+1);
-	// 	assert_eq!(TotalActiveSubnetNodes::<T>::get(subnet_id), end);
-	// }
+	#[benchmark]
+	fn deactivate_subnet_node() {
+		let end = 12;
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, end, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_node_account = funded_account::<T>("subnet_node_account", end+1);
+
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+		assert_ok!(
+			Network::<T>::add_subnet_node(
+				RawOrigin::Signed(subnet_node_account.clone()).into(), 
+				subnet_id, 
+				peer(end+1), 
+				DEFAULT_SUBNET_NODE_STAKE,
+				None,
+				None,
+				None,
+			) 
+		);
+
+		#[extrinsic_call]
+		deactivate_subnet_node(RawOrigin::Signed(subnet_node_account.clone()), subnet_id);
+
+		assert_eq!(TotalSubnetNodes::<T>::get(subnet_id), end+1);
+		assert_eq!(TotalActiveSubnetNodes::<T>::get(subnet_id), end);
+		assert_eq!(
+			SubnetNodesData::<T>::get(subnet_id, subnet_node_account).unwrap().classification.class,
+			SubnetNodeClass::Deactivated
+		);
+	}
+
+	#[benchmark]
+	fn reactivate_subnet_node() {
+		let end = 12;
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, end, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_node_account = funded_account::<T>("subnet_node_account", end+1);
+
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+		assert_ok!(
+			Network::<T>::add_subnet_node(
+				RawOrigin::Signed(subnet_node_account.clone()).into(), 
+				subnet_id, 
+				peer(end+1), 
+				DEFAULT_SUBNET_NODE_STAKE,
+				None,
+				None,
+				None,
+			) 
+		);
+		assert_ok!(
+			Network::<T>::deactivate_subnet_node(
+				RawOrigin::Signed(subnet_node_account.clone()).into(), 
+				subnet_id, 
+			) 
+		);
+
+		// --- A class at or under ``Included`` is restored and its deactivation ledger removed
+		#[extrinsic_call]
+		reactivate_subnet_node(RawOrigin::Signed(subnet_node_account.clone()), subnet_id);
+
+		assert_eq!(TotalActiveSubnetNodes::<T>::get(subnet_id), end+1);
+		assert!(!DeactivateSubnetNodeLedger::<T>::contains_key(subnet_id, subnet_node_account));
+	}
 
 	#[benchmark]
 	fn remove_subnet_node() {
@@ -745,39 +856,25 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn claim_delegate_stake_unbondings() {
+	fn claim_delegate_stake_unbondings(u: Linear<1, 32>) {
 		let end = 12;
 		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, end, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
 		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
 
 		let delegate_account: T::AccountId = funded_account::<T>("delegate_account", 0);
-		assert_ok!(
-			Network::<T>::add_to_delegate_stake(
-				RawOrigin::Signed(delegate_account.clone()).into(), 
-				subnet_id, 
-				DEFAULT_DELEGATE_STAKE_TO_BE_ADDED
-			)
-		);
-		let delegate_shares = AccountSubnetDelegateStakeShares::<T>::get(delegate_account.clone(), subnet_id);
 
-		assert_ok!(
-			Network::<T>::remove_delegate_stake(
-				RawOrigin::Signed(delegate_account.clone()).into(), 
-				subnet_id, 
-				delegate_shares
-			)
-		);
+		// --- One unbonding per epoch
+		let mut unbondings: BTreeMap<u64, u128> = BTreeMap::new();
+		for epoch in 0..u {
+			unbondings.insert(epoch as u64, DEFAULT_DELEGATE_STAKE_TO_BE_ADDED);
+		}
+		DelegateStakeUnbondingLedger::<T>::insert(delegate_account.clone(), subnet_id, unbondings);
 
 		let epoch_length = T::EpochLength::get();
     let delegate_stake_cooldown_epochs = T::DelegateStakeCooldownEpochs::get();
 
-		let unbondings: BTreeMap<u64, u128> = DelegateStakeUnbondingLedger::<T>::get(delegate_account.clone(), subnet_id);
-    assert_eq!(unbondings.len(), 1);
-    let (ledger_epoch, ledger_balance) = unbondings.iter().next().unwrap();
-
 		frame_system::Pallet::<T>::set_block_number(
-			frame_system::Pallet::<T>::block_number() + 
-			u64_to_block::<T>((epoch_length + 1) * delegate_stake_cooldown_epochs)
+			u64_to_block::<T>(epoch_length * (u as u64 + delegate_stake_cooldown_epochs + 1))
 		);
 
 		let balance = T::Currency::free_balance(&delegate_account.clone());
@@ -789,13 +886,51 @@ mod benchmarks {
 		);
 
 		let after_claim_balance = T::Currency::free_balance(&delegate_account.clone());
-		let ledger_balance_as_balance = u128_to_balance::<T>(*ledger_balance);
-    assert_eq!(after_claim_balance, balance + ledger_balance_as_balance.unwrap());
+		let claimed_as_balance = u128_to_balance::<T>(DEFAULT_DELEGATE_STAKE_TO_BE_ADDED * u as u128);
+    assert_eq!(after_claim_balance, balance + claimed_as_balance.unwrap());
 
     let unbondings: BTreeMap<u64, u128> = DelegateStakeUnbondingLedger::<T>::get(delegate_account.clone(), subnet_id);
     assert_eq!(unbondings.len(), 0);
 	}
 
+	#[benchmark]
+	fn claim_stake_unbondings(u: Linear<1, 32>) {
+		let end = 12;
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, end, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+
+		let subnet_node_account: T::AccountId = funded_account::<T>("subnet_node_account", 0);
+
+		// --- One unbonding per epoch
+		let mut unbondings: BTreeMap<u64, u128> = BTreeMap::new();
+		for epoch in 0..u {
+			unbondings.insert(epoch as u64, DEFAULT_STAKE_TO_BE_ADDED);
+		}
+		SubnetStakeUnbondingLedger::<T>::insert(subnet_node_account.clone(), subnet_id, unbondings);
+
+		let epoch_length = T::EpochLength::get();
+    let stake_cooldown_epochs = T::StakeCooldownEpochs::get();
+
+		frame_system::Pallet::<T>::set_block_number(
+			u64_to_block::<T>(epoch_length * (u as u64 + stake_cooldown_epochs + 1))
+		);
+
+		let balance = T::Currency::free_balance(&subnet_node_account.clone());
+
+		#[extrinsic_call]
+		claim_stake_unbondings(
+			RawOrigin::Signed(subnet_node_account.clone()), 
+			subnet_id, 
+		);
+
+		let after_claim_balance = T::Currency::free_balance(&subnet_node_account.clone());
+		let claimed_as_balance = u128_to_balance::<T>(DEFAULT_STAKE_TO_BE_ADDED * u as u128);
+    assert_eq!(after_claim_balance, balance + claimed_as_balance.unwrap());
+
+    let unbondings: BTreeMap<u64, u128> = SubnetStakeUnbondingLedger::<T>::get(subnet_node_account.clone(), subnet_id);
+    assert_eq!(unbondings.len(), 0);
+	}
+
 	#[benchmark]
 	fn increase_delegate_stake() {
 		let end = 12;
@@ -937,10 +1072,10 @@ mod benchmarks {
 
 		let proposal_bid_amount = ProposalBidAmount::<T>::get();
     let plaintiff_starting_balance = T::Currency::free_balance(&proposer.clone());
-		let data = Vec::new();
+		let data: BoundedVec<u8, DefaultProposalEvidenceLimit> = BoundedVec::new();
 
 		#[extrinsic_call]
-		propose(RawOrigin::Signed(proposer.clone()), subnet_id, peer(1), data.clone());
+		propose(RawOrigin::Signed(proposer.clone()), subnet_id, peer(1), ProposalReason::Offline, data.clone());
 
     let plaintiff_after_balance = T::Currency::free_balance(&proposer.clone());
     assert_eq!(plaintiff_starting_balance - u128_to_balance::<T>(proposal_bid_amount).unwrap(), plaintiff_after_balance);
@@ -959,6 +1094,36 @@ mod benchmarks {
     assert_eq!(proposal.complete, false);
 	}
 
+	#[benchmark]
+	fn attest_proposal() {
+		let end = 64;
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, end, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+
+		let proposer = funded_account::<T>("subnet_node_account", 0);
+		let data: BoundedVec<u8, DefaultProposalEvidenceLimit> = BoundedVec::new();
+
+		assert_ok!(
+			Network::<T>::propose(
+				RawOrigin::Signed(proposer.clone()).into(), 
+				subnet_id, 
+				peer(1), 
+				ProposalReason::Offline,
+				data.clone()
+			)
+		);
+
+		let attestor = funded_account::<T>("subnet_node_account", 2);
+
+		let current_block_number = get_current_block_as_u64::<T>();
+
+		#[extrinsic_call]
+		attest_proposal(RawOrigin::Signed(attestor.clone()), subnet_id, 0, data.clone());
+
+		let proposal = Proposals::<T>::get(subnet_id, 0);
+    assert_eq!(proposal.attests.get(&attestor.clone()), Some(&current_block_number));
+	}
+
 	#[benchmark]
 	fn cancel_proposal() {
 		let end = 64;
@@ -975,7 +1140,7 @@ mod benchmarks {
 
 		let proposal_bid_amount = ProposalBidAmount::<T>::get();
     let plaintiff_starting_balance = T::Currency::free_balance(&proposer.clone());
-		let data = Vec::new();
+		let data: BoundedVec<u8, DefaultProposalEvidenceLimit> = BoundedVec::new();
 
 		let accountant_nodes = SubnetNodesClasses::<T>::get(subnet_id, SubnetNodeClass::Accountant);
 
@@ -984,6 +1149,7 @@ mod benchmarks {
 				RawOrigin::Signed(proposer.clone()).into(), 
 				subnet_id, 
 				peer(1), 
+				ProposalReason::Offline,
 				data.clone()
 			)
 		);
@@ -1014,7 +1180,7 @@ mod benchmarks {
 
 		let proposal_bid_amount = ProposalBidAmount::<T>::get();
     let plaintiff_starting_balance = T::Currency::free_balance(&proposer.clone());
-		let data = Vec::new();
+		let data: BoundedVec<u8, DefaultProposalEvidenceLimit> = BoundedVec::new();
 
 		let accountant_nodes = SubnetNodesClasses::<T>::get(subnet_id, SubnetNodeClass::Accountant);
 
@@ -1023,6 +1189,7 @@ mod benchmarks {
 				RawOrigin::Signed(proposer.clone()).into(), 
 				subnet_id, 
 				peer(1), 
+				ProposalReason::Offline,
 				data.clone()
 			)
 		);
@@ -1030,7 +1197,7 @@ mod benchmarks {
 		let challenger_starting_balance = T::Currency::free_balance(&defendant.clone());
 
 		#[extrinsic_call]
-		challenge_proposal(RawOrigin::Signed(defendant.clone()), subnet_id, 0, Vec::new());
+		challenge_proposal(RawOrigin::Signed(defendant.clone()), subnet_id, 0, BoundedVec::new());
 
     let challenger_after_balance = T::Currency::free_balance(&defendant.clone());
     assert_eq!(challenger_starting_balance - u128_to_balance::<T>(proposal_bid_amount).unwrap(), challenger_after_balance);
@@ -1065,7 +1232,7 @@ mod benchmarks {
 
 		let proposal_bid_amount = ProposalBidAmount::<T>::get();
     let plaintiff_starting_balance = T::Currency::free_balance(&proposer.clone());
-		let data = Vec::new();
+		let data: BoundedVec<u8, DefaultProposalEvidenceLimit> = BoundedVec::new();
 
 		let accountant_nodes = SubnetNodesClasses::<T>::get(subnet_id, SubnetNodeClass::Accountant);
 
@@ -1074,6 +1241,7 @@ mod benchmarks {
 				RawOrigin::Signed(proposer.clone()).into(), 
 				subnet_id, 
 				peer(1), 
+				ProposalReason::Offline,
 				data.clone()
 			)
 		);
@@ -1098,128 +1266,467 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn finalize_proposal() {
-		let end = 64;
+	fn finalize_proposal(n: Linear<16, 254>) {
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, n, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+
+		build_voted_proposal::<T>(subnet_id, n);
+
+		// anone can call this
+		let finalizer = funded_account::<T>("subnet_node_account", n);
+
+		#[extrinsic_call]
+		finalize_proposal(RawOrigin::Signed(finalizer.clone()), subnet_id, 0);
+
+    let proposal = Proposals::<T>::get(subnet_id, 0);
+    assert_eq!(proposal.votes.yay.len(), (n-2) as usize);
+    assert_eq!(proposal.complete, true);
+    assert_eq!(proposal.outcome, Some(ProposalOutcome::Plaintiff));
+	}
+
+	#[benchmark]
+	fn appeal_proposal() {
+		let end = ProposalMinSubnetNodes::<T>::get();
 		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, end, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
 		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
-		let subnet = SubnetsData::<T>::get(subnet_id).unwrap();
-		let n_peers: u32 = TotalSubnetNodes::<T>::get(subnet_id);
 
-		let epoch_length = T::EpochLength::get();
-		let epoch = get_current_block_as_u64::<T>() / epoch_length as u64;
+		build_voted_proposal::<T>(subnet_id, end);
 
-		let proposer = funded_account::<T>("subnet_node_account", 0);
+		let finalizer = funded_account::<T>("subnet_node_account", 0);
+		assert_ok!(
+			Network::<T>::finalize_proposal(
+				RawOrigin::Signed(finalizer.clone()).into(), 
+				subnet_id, 
+				0
+			)
+		);
+
+		// --- Defendant lost and appeals
 		let defendant = funded_account::<T>("subnet_node_account", 1);
+		T::Currency::deposit_creating(&defendant, u128_to_balance::<T>(AppealBondAmount::<T>::get()).unwrap());
 
-		let proposal_bid_amount = ProposalBidAmount::<T>::get();
-    let plaintiff_starting_balance = T::Currency::free_balance(&proposer.clone());
-		let data = Vec::new();
+		#[extrinsic_call]
+		appeal_proposal(RawOrigin::Signed(defendant.clone()), subnet_id, 0);
 
-		let accountant_nodes = SubnetNodesClasses::<T>::get(subnet_id, SubnetNodeClass::Accountant);
+		assert!(ProposalAppeals::<T>::contains_key(subnet_id, 0));
+	}
 
+	#[benchmark]
+	fn enact_proposal(n: Linear<16, 254>) {
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, n, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+
+		build_voted_proposal::<T>(subnet_id, n);
+
+		let finalizer = funded_account::<T>("subnet_node_account", 0);
 		assert_ok!(
-			Network::<T>::propose(
-				RawOrigin::Signed(proposer.clone()).into(), 
+			Network::<T>::finalize_proposal(
+				RawOrigin::Signed(finalizer.clone()).into(), 
 				subnet_id, 
-				peer(1), 
-				data.clone()
+				0
 			)
 		);
 
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + 
+			u64_to_block::<T>(AppealPeriod::<T>::get())
+		);
+
+		let enactor = funded_account::<T>("subnet_node_account", n);
+
+		#[extrinsic_call]
+		enact_proposal(RawOrigin::Signed(enactor.clone()), subnet_id, 0);
+
+		let proposal = Proposals::<T>::get(subnet_id, 0);
+		assert_eq!(proposal.enacted, true);
+		let defendant = funded_account::<T>("subnet_node_account", 1);
+		assert!(!SubnetNodesData::<T>::contains_key(subnet_id, defendant));
+	}
+
+	#[benchmark]
+	fn remove_subnet() {
+		let end = 12;
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, end, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+
+		// --- Max penalties reached
+		SubnetPenaltyCount::<T>::insert(subnet_id, MaxSubnetPenaltyCount::<T>::get() + 1);
+
+		let remover = funded_account::<T>("remover", 0);
+
+		#[extrinsic_call]
+		remove_subnet(RawOrigin::Signed(remover.clone()), subnet_id);
+
+		assert_eq!(SubnetsData::<T>::contains_key(subnet_id), false);
+	}
+
+	#[benchmark]
+	fn clear_subnet(n: Linear<1, 256>) {
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, 0, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+
+		// --- More stakers than processed items
+		for s in 0..n {
+			let staker = funded_account::<T>("staker", s);
+			Network::<T>::increase_account_stake(&staker, subnet_id, DEFAULT_STAKE_TO_BE_ADDED);
+		}
+
+		assert_ok!(Network::<T>::deactivate_subnet(DEFAULT_SUBNET_PATH.into(), SubnetRemovalReason::MaxPenalties));
+		SubnetTeardownItemsPerCall::<T>::set(n);
+
+		let caller = funded_account::<T>("caller", 0);
+
+		#[extrinsic_call]
+		clear_subnet(RawOrigin::Signed(caller.clone()), subnet_id);
+
+		assert_eq!(SubnetTeardowns::<T>::get(subnet_id), Some(SubnetTeardownStep::NodeStake));
+		assert_eq!(SubnetStakeAccounts::<T>::iter_key_prefix(subnet_id).count() as u32, TotalSubnetNodes::<T>::get(subnet_id));
+	}
+
+	#[benchmark]
+	fn on_initialize() {
+		// --- Get to a block where no epoch processing is ran
+		set_next_epoch_block::<T>();
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + u64_to_block::<T>(1)
+		);
+		let block = frame_system::Pallet::<T>::block_number();
+
+		#[block]
+		{
+			Network::<T>::on_initialize(block);
+		}
+
+		assert_eq!(EpochProcessing::<T>::get(), None);
+	}
+
+	#[benchmark]
+	fn begin_epoch_rewards(s: Linear<1, 16>) {
+		let n_peers: u32 = get_min_subnet_nodes::<T>();
+		build_activated_subnets::<T>(s, n_peers);
+
+		let epoch = set_next_epoch_block::<T>();
+
+		// --- All subnets eligible for emissions
+		for subnet_id in 1..=s {
+			let validator = funded_account::<T>("subnet_node_account", (subnet_id - 1) * n_peers);
+			SubnetRewardsValidator::<T>::insert(subnet_id, epoch, validator);
+		}
+
+		#[block]
+		{
+			Network::<T>::begin_epoch_rewards(epoch);
+		}
+
+		for subnet_id in 1..=s {
+			assert!(EpochSubnetEmissions::<T>::contains_key(subnet_id));
+		}
+	}
+
+	#[benchmark]
+	fn reward_subnet(n: Linear<12, 254>) {
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, n, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+
+		let epoch = set_next_epoch_block::<T>();
+
+		Network::<T>::do_subnet_epoch_preliminaries(get_current_block_as_u64::<T>(), epoch, subnet_id);
+
+		let validator = SubnetRewardsValidator::<T>::get(subnet_id, epoch).unwrap();
+
+		// --- Every subnet node is in the consensus data and attests
 		assert_ok!(
-			Network::<T>::challenge_proposal(
-				RawOrigin::Signed(defendant.clone()).into(), 
+			Network::<T>::validate(
+				RawOrigin::Signed(validator.clone()).into(), 
 				subnet_id, 
-				0, 
-				data.clone()
+				subnet_node_data(0, n),
+				None,
 			)
 		);
 
-		for n in 0..n_peers {
-      if n == 0 || n == 1 {
-        continue
-      }
-			let voter = funded_account::<T>("subnet_node_account", n);
+		for i in 0..n {
+			let attester = funded_account::<T>("subnet_node_account", i);
+			if attester == validator {
+				continue
+			}
 			assert_ok!(
-				Network::<T>::vote(
-					RawOrigin::Signed(voter.clone()).into(), 
-					subnet_id, 
-					0, 
-					VoteType::Yay
+				Network::<T>::attest(
+					RawOrigin::Signed(attester).into(), 
+					subnet_id,
 				)
 			);
-    }
+		}
 
-		let voting_period = VotingPeriod::<T>::get();
-		frame_system::Pallet::<T>::set_block_number(
-			frame_system::Pallet::<T>::block_number() + 
-			u64_to_block::<T>(voting_period + 1)
-		);
+		set_next_epoch_block::<T>();
+		let block = get_current_block_as_u64::<T>();
 
-		// anone can call this
-		let finalizer = funded_account::<T>("subnet_node_account", n_peers);
+		Network::<T>::begin_epoch_rewards(epoch);
+
+		#[block]
+		{
+			Network::<T>::reward_subnet(block, epoch, subnet_id);
+		}
+
+		let receipt = SubnetRewardsReceipts::<T>::get(subnet_id, epoch).unwrap();
+		assert_eq!(receipt.node_rewards.len() as u32, n);
+	}
+
+	#[benchmark]
+	fn finish_epoch_rewards() {
+		// --- Burning writes the unpaid budget out of the stake vault
+		UnpaidEmissions::<T>::set(UnpaidEmissionsPolicy::Burn);
+		StakeVaultBalance::<T>::set(DEFAULT_SUBNET_INIT_COST);
+
+		let epoch = set_next_epoch_block::<T>();
+
+		#[block]
+		{
+			Network::<T>::finish_epoch_rewards(epoch, DEFAULT_SUBNET_INIT_COST, 0);
+		}
+
+		assert_eq!(StakeVaultBalance::<T>::get(), 0);
+	}
+
+	#[benchmark]
+	fn subnet_epoch_preliminaries(n: Linear<12, 254>) {
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, n, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+
+		let epoch = set_next_epoch_block::<T>();
+		let block = get_current_block_as_u64::<T>();
+
+		#[block]
+		{
+			Network::<T>::do_subnet_epoch_preliminaries(block, epoch, subnet_id);
+		}
+
+		assert!(SubnetRewardsValidator::<T>::contains_key(subnet_id, epoch));
+	}
+
+	#[benchmark]
+	fn remove_account_subnet_nodes(s: Linear<1, 16>) {
+		let n_peers: u32 = get_min_subnet_nodes::<T>();
+		build_activated_subnets::<T>(s, n_peers);
+
+		// --- One account with a subnet node on every subnet
+		let index: u32 = s * n_peers;
+		let penalized = funded_account::<T>("subnet_node_account", index);
+		T::Currency::deposit_creating(&penalized, (DEFAULT_SUBNET_NODE_STAKE * s as u128).try_into().ok().expect("REASON"));
+		for subnet_id in 1..=s {
+			assert_ok!(
+				Network::<T>::add_subnet_node(
+					RawOrigin::Signed(penalized.clone()).into(),
+					subnet_id,
+					peer(index + subnet_id),
+					DEFAULT_SUBNET_NODE_STAKE,
+					None,
+					None,
+					None,
+				)
+			);
+		}
+
+		AccountPenaltyCount::<T>::insert(penalized.clone(), MaxAccountPenaltyCount::<T>::get() + 1);
+
+		let caller = funded_account::<T>("caller", 0);
 
 		#[extrinsic_call]
-		finalize_proposal(RawOrigin::Signed(finalizer.clone()), subnet_id, 0);
+		remove_account_subnet_nodes(RawOrigin::Signed(caller.clone()), penalized.clone());
 
-		let plaintiff_after_balance = T::Currency::free_balance(&proposer.clone());
-    assert!(plaintiff_after_balance > plaintiff_starting_balance);
+		for subnet_id in 1..=s {
+			assert!(!SubnetNodesData::<T>::contains_key(subnet_id, penalized.clone()));
+		}
+		assert!(AccountBans::<T>::contains_key(penalized.clone()));
+	}
 
-    let proposal = Proposals::<T>::get(subnet_id, 0);
-    assert_eq!(proposal.votes.yay.len(), (n_peers-2) as usize);
-    assert_eq!(proposal.plaintiff_bond, 0);
-    assert_eq!(proposal.defendant_bond, 0);
-    assert_eq!(proposal.complete, true);
+	#[benchmark]
+	fn owner_update_subnet() {
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, 0, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+		let subnet = SubnetsData::<T>::get(subnet_id).unwrap();
+		let owner = SubnetOwner::<T>::get(subnet_id).unwrap();
+
+		let update = SubnetUpdateData {
+			memory_mb: None,
+			registration_blocks: None,
+			min_nodes: Some(subnet.min_nodes),
+			target_nodes: Some(subnet.target_nodes + 1),
+		};
+
+		#[extrinsic_call]
+		owner_update_subnet(RawOrigin::Signed(owner.clone()), subnet_id, update);
+
+		assert_eq!(SubnetsData::<T>::get(subnet_id).unwrap().target_nodes, subnet.target_nodes + 1);
 	}
 
-	// #[benchmark]
-	// fn on_initialize_do_choose_validator_and_accountants() {
-	// 	let max_subnets: u32 = Network::<T>::max_subnets();
-	// 	let n_peers: u32 = Network::<T>::max_subnet_nodes();
-
-	// 	for s in 0..max_subnets {
-	// 		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, n_peers, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
-	// 	}
-
-	// 	let epoch_length = T::EpochLength::get();
-
-	// 	let block_number = get_current_block_as_u64::<T>();
-	// 	let epoch = get_current_block_as_u64::<T>() / epoch_length as u64;
-
-	// 	#[block]
-	// 	{
-	// 		let block = get_current_block_as_u64::<T>();
-	// 		let epoch_length = T::EpochLength::get();
-	// 		let epoch = get_current_block_as_u64::<T>() / epoch_length as u64;
-	// 		Network::<T>::do_epoch_preliminaries(
-	// 			block, 
-	// 			epoch as u32, 
-	// 			epoch_length
-	// 		);
-	// 	}
-
-	// 	// ensure nodes were rewarded
-	// 	for s in 0..max_subnets {
-	// 		let subnet_id = s+1;
-
-	// 		let validator = SubnetRewardsValidator::<T>::get(subnet_id, epoch as u32);
-	// 		assert!(validator != None, "Validator is None");
-	// 	}
-	// }
-
-	// #[benchmark]
-	// fn on_initialize() {
-	// 	// get to a block where none of the functions will be ran
-	// 	frame_system::Pallet::<T>::set_block_number(
-	// 		frame_system::Pallet::<T>::block_number() + u64_to_block::<T>(1)
-	// 	);
-
-	// 	#[block]
-	// 	{
-	// 		let block = frame_system::Pallet::<T>::block_number();
-	// 		Network::<T>::on_initialize(block);
-	// 	}
-	// }
+	#[benchmark]
+	fn transfer_subnet_ownership() {
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, 0, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+		let owner = SubnetOwner::<T>::get(subnet_id).unwrap();
+		let new_owner = funded_account::<T>("new_owner", 0);
+
+		#[extrinsic_call]
+		transfer_subnet_ownership(RawOrigin::Signed(owner.clone()), subnet_id, new_owner.clone());
+
+		assert_eq!(SubnetOwner::<T>::get(subnet_id), Some(new_owner));
+	}
+
+	#[benchmark]
+	fn renounce_subnet_ownership() {
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, 0, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+		let owner = SubnetOwner::<T>::get(subnet_id).unwrap();
+
+		#[extrinsic_call]
+		renounce_subnet_ownership(RawOrigin::Signed(owner.clone()), subnet_id);
+
+		assert_eq!(SubnetOwner::<T>::get(subnet_id), None);
+	}
+
+	#[benchmark]
+	fn owner_set_subnet_metadata() {
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, 0, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+		let owner = SubnetOwner::<T>::get(subnet_id).unwrap();
+
+		// --- Every field at its limit
+		let metadata = SubnetMetadata {
+			name: vec![1u8; DefaultSubnetMetadataNameLimit::get() as usize].try_into().unwrap(),
+			model: vec![1u8; DefaultSubnetMetadataUriLimit::get() as usize].try_into().unwrap(),
+			content_hash: vec![1u8; DefaultSubnetMetadataHashLimit::get() as usize].try_into().unwrap(),
+			source_uri: vec![1u8; DefaultSubnetMetadataUriLimit::get() as usize].try_into().unwrap(),
+			hardware: SubnetHardwareHints::default(),
+			version: vec![1u8; DefaultSubnetMetadataVersionLimit::get() as usize].try_into().unwrap(),
+		};
+
+		#[extrinsic_call]
+		owner_set_subnet_metadata(RawOrigin::Signed(owner.clone()), subnet_id, metadata.clone());
+
+		assert_eq!(SubnetsMetadata::<T>::get(subnet_id), Some(metadata));
+		assert_eq!(SubnetMetadataRevision::<T>::get(subnet_id), 1);
+	}
+
+	#[benchmark]
+	fn update_subnet_node_params() {
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, 0, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+		let subnet_node_account = funded_account::<T>("subnet_node_account", 0);
+
+		// --- Both parameters at their limit
+		let param: BoundedVec<u8, DefaultSubnetNodeParamLimit> = vec![1u8; DefaultSubnetNodeParamLimit::get() as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		update_subnet_node_params(RawOrigin::Signed(subnet_node_account.clone()), subnet_id, Some(param.clone()), Some(param.clone()));
+
+		let subnet_node = SubnetNodesData::<T>::get(subnet_id, subnet_node_account.clone());
+		assert_eq!(subnet_node.b, param.into_inner());
+	}
+
+	#[benchmark]
+	fn owner_pause_subnet() {
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, 0, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+		let owner = SubnetOwner::<T>::get(subnet_id).unwrap();
+		T::Currency::deposit_creating(&owner, SubnetPauseBond::<T>::get().try_into().ok().expect("REASON"));
+
+		#[extrinsic_call]
+		owner_pause_subnet(RawOrigin::Signed(owner.clone()), subnet_id);
+
+		assert!(Network::<T>::is_subnet_paused(subnet_id));
+	}
+
+	#[benchmark]
+	fn owner_unpause_subnet() {
+		build_activated_subnet::<T>(DEFAULT_SUBNET_PATH.into(), 0, 0, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_SUBNET_NODE_STAKE);
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+		let owner = SubnetOwner::<T>::get(subnet_id).unwrap();
+		T::Currency::deposit_creating(&owner, SubnetPauseBond::<T>::get().try_into().ok().expect("REASON"));
+		assert_ok!(Network::<T>::owner_pause_subnet(RawOrigin::Signed(owner.clone()).into(), subnet_id));
+
+		// --- Paused for the min pause epochs
+		let unpause_block = get_current_block_as_u64::<T>() + T::EpochLength::get() * MinSubnetPauseEpochs::<T>::get();
+		frame_system::Pallet::<T>::set_block_number(u64_to_block::<T>(unpause_block));
+
+		#[extrinsic_call]
+		owner_unpause_subnet(RawOrigin::Signed(owner.clone()), subnet_id);
+
+		assert!(!Network::<T>::is_subnet_paused(subnet_id));
+		assert!(!SubnetPauseBonds::<T>::contains_key(subnet_id));
+	}
+
+	#[benchmark]
+	fn owner_set_registration_whitelist(n: Linear<1, { T::MaxSubnetNodes::get() }>) {
+		let (owner, subnet_id) = register_owned_subnet::<T>(DEFAULT_SUBNET_PATH.into());
+		MaxSubnetNodes::<T>::set(T::MaxSubnetNodes::get());
+
+		let whitelist: BoundedBTreeSet<T::AccountId, T::MaxSubnetNodes> = (0..n)
+			.map(|i| account::<T::AccountId>("whitelisted", i, SEED))
+			.collect::<BTreeSet<T::AccountId>>()
+			.try_into()
+			.unwrap();
+
+		#[extrinsic_call]
+		owner_set_registration_whitelist(RawOrigin::Signed(owner.clone()), subnet_id, whitelist.clone());
+
+		assert_eq!(SubnetRegistrationWhitelist::<T>::get(subnet_id), whitelist.into_inner());
+	}
+
+	#[benchmark]
+	fn owner_set_registration_peer_whitelist(n: Linear<1, { T::MaxSubnetNodes::get() }>) {
+		let (owner, subnet_id) = register_owned_subnet::<T>(DEFAULT_SUBNET_PATH.into());
+		MaxSubnetNodes::<T>::set(T::MaxSubnetNodes::get());
+
+		let whitelist: BoundedBTreeSet<PeerId, T::MaxSubnetNodes> = (0..n)
+			.map(|i| peer(i))
+			.collect::<BTreeSet<PeerId>>()
+			.try_into()
+			.unwrap();
+
+		#[extrinsic_call]
+		owner_set_registration_peer_whitelist(RawOrigin::Signed(owner.clone()), subnet_id, whitelist.clone());
+
+		assert_eq!(SubnetRegistrationPeerWhitelist::<T>::get(subnet_id), whitelist.into_inner());
+	}
+
+	#[benchmark]
+	fn register_subnet_with_whitelist(
+		n: Linear<1, { T::MaxSubnetNodes::get() }>,
+		p: Linear<1, { T::MaxSubnetNodes::get() }>,
+	) {
+		let funded_initializer = funded_initializer::<T>("funded_initializer", 0);
+		MaxSubnetNodes::<T>::set(T::MaxSubnetNodes::get());
+
+		let register_subnet_data = RegistrationSubnetData {
+			path: DEFAULT_SUBNET_PATH.as_bytes().to_vec().try_into().unwrap(),
+			memory_mb: DEFAULT_SUBNET_MEM_MB,
+			registration_blocks: DEFAULT_SUBNET_REGISTRATION_BLOCKS,
+		};
+
+		let whitelist: BoundedBTreeSet<T::AccountId, T::MaxSubnetNodes> = (0..n)
+			.map(|i| account::<T::AccountId>("whitelisted", i, SEED))
+			.collect::<BTreeSet<T::AccountId>>()
+			.try_into()
+			.unwrap();
+
+		let peer_whitelist: BoundedBTreeSet<PeerId, T::MaxSubnetNodes> = (0..p)
+			.map(|i| peer(i))
+			.collect::<BTreeSet<PeerId>>()
+			.try_into()
+			.unwrap();
+
+		#[extrinsic_call]
+		register_subnet_with_whitelist(
+			RawOrigin::Signed(funded_initializer.clone()), 
+			register_subnet_data, 
+			whitelist.clone(), 
+			peer_whitelist.clone(),
+		);
+
+		let subnet_id = SubnetPaths::<T>::get::<Vec<u8>>(DEFAULT_SUBNET_PATH.into()).unwrap();
+		assert_eq!(SubnetRegistrationWhitelist::<T>::get(subnet_id), whitelist.into_inner());
+		assert_eq!(SubnetRegistrationPeerWhitelist::<T>::get(subnet_id), peer_whitelist.into_inner());
+	}
 
 	impl_benchmark_test_suite!(Network, crate::mock::new_test_ext(), crate::mock::Test);
-}
+}
\ No newline at end of file
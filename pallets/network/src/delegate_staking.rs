@@ -84,6 +84,7 @@ impl<T: Config> Pallet<T> {
     // --- Mitigate inflation attack
     if total_subnet_delegated_stake_shares == 0 {
       // no need for saturation here
+      Self::snapshot_subnet_stake(subnet_id);
       TotalSubnetDelegateStakeShares::<T>::mutate(subnet_id, |mut n| *n += 1000);
       delegate_stake_to_be_added_as_shares = delegate_stake_to_be_added_as_shares.saturating_sub(1000);
     }
@@ -160,7 +161,12 @@ impl<T: Config> Pallet<T> {
     // let remaining_account_delegate_stake_shares: u128 = AccountSubnetDelegateStakeShares::<T>::get(&account_id, subnet_id);
     
     // --- We add the balancer to the account_id.  If the above fails we will not credit this account_id.
-    Self::add_balance_to_delegate_stake_unbonding_ledger(&account_id, subnet_id,  delegate_stake_to_be_removed, block).map_err(|e| e)?;
+    // Delegate stakers of a paused subnet can exit without the cooldown
+    if Self::is_subnet_paused(subnet_id) {
+      Self::add_balance_to_coldkey_account(&account_id, delegate_stake_to_be_added_as_currency.unwrap());
+    } else {
+      Self::add_balance_to_delegate_stake_unbonding_ledger(&account_id, subnet_id,  delegate_stake_to_be_removed, block).map_err(|e| e)?;
+    }
 
     // Set last block for rate limiting
     Self::set_last_tx_block(&account_id, block);
@@ -260,6 +266,7 @@ impl<T: Config> Pallet<T> {
     // --- Mitigate inflation attack
     if total_to_subnet_delegated_stake_shares == 0 {
       // no need for saturation here
+      Self::snapshot_subnet_stake(to_subnet_id);
       TotalSubnetDelegateStakeShares::<T>::mutate(to_subnet_id, |mut n| *n += 1000);
       delegate_stake_to_be_added_as_shares = delegate_stake_to_be_added_as_shares.saturating_sub(1000);
     }
@@ -323,6 +330,30 @@ impl<T: Config> Pallet<T> {
     Ok(())
   }
 
+  /// Infallible version of ``add_balance_to_delegate_stake_unbonding_ledger`` used when the stake is released by the network
+  // If the account already unbonded this epoch or the ledger is full, the balance is merged into the latest unbonding
+  pub fn force_add_balance_to_delegate_stake_unbonding_ledger(
+    account_id: &T::AccountId,
+    subnet_id: u32, 
+    balance: u128,
+    block: u64,
+  ) {
+    let epoch_length: u64 = T::EpochLength::get();
+    let epoch: u64 = block / epoch_length;
+
+    if DelegateStakeUnbondingLedger::<T>::get(account_id.clone(), subnet_id).len() as u32 >= T::MaxDelegateStakeUnlockings::get() {
+      Self::do_claim_delegate_stake_unbondings(&account_id, subnet_id);
+    }
+
+    DelegateStakeUnbondingLedger::<T>::mutate(account_id.clone(), subnet_id, |unbondings| {
+      let key: u64 = match unbondings.last_key_value() {
+        Some((last_epoch, _)) if unbondings.len() as u32 >= T::MaxDelegateStakeUnlockings::get() => *last_epoch,
+        _ => epoch,
+      };
+      unbondings.entry(key).or_insert(0).saturating_accrue(balance);
+    });
+  }
+
   // Infallible
   pub fn do_claim_delegate_stake_unbondings(account_id: &T::AccountId, subnet_id: u32) -> u32 {
     let block: u64 = Self::get_current_block_as_u64();
@@ -412,8 +443,12 @@ impl<T: Config> Pallet<T> {
     amount: u128,
     shares: u128,
   ) {
+    Self::snapshot_account_subnet_stake(account_id, subnet_id);
+    Self::snapshot_subnet_stake(subnet_id);
+
     // -- increase account subnet staking shares balance
     AccountSubnetDelegateStakeShares::<T>::mutate(account_id, subnet_id, |mut n| n.saturating_accrue(shares));
+    SubnetDelegateStakeAccounts::<T>::insert(subnet_id, account_id, ());
 
     // -- increase total subnet delegate stake balance
     TotalSubnetDelegateStakeBalance::<T>::mutate(subnet_id, |mut n| n.saturating_accrue(amount));
@@ -428,8 +463,14 @@ impl<T: Config> Pallet<T> {
     amount: u128,
     shares: u128,
   ) {
+    Self::snapshot_account_subnet_stake(account_id, subnet_id);
+    Self::snapshot_subnet_stake(subnet_id);
+
     // -- decrease account subnet staking shares balance
     AccountSubnetDelegateStakeShares::<T>::mutate(account_id, subnet_id, |mut n| n.saturating_reduce(shares));
+    if AccountSubnetDelegateStakeShares::<T>::get(account_id, subnet_id) == 0 {
+      SubnetDelegateStakeAccounts::<T>::remove(subnet_id, account_id);
+    }
 
     // -- decrease total subnet delegate stake balance
     TotalSubnetDelegateStakeBalance::<T>::mutate(subnet_id, |mut n| n.saturating_reduce(amount));
@@ -443,6 +484,8 @@ impl<T: Config> Pallet<T> {
     subnet_id: u32,
     amount: u128,
   ) {
+    Self::snapshot_subnet_stake(subnet_id);
+
     // -- increase total subnet delegate stake 
     TotalSubnetDelegateStakeBalance::<T>::mutate(subnet_id, |mut n| n.saturating_accrue(amount));
   }
@@ -59,19 +59,18 @@ impl<T: Config> Pallet<T> {
     0
   }
 
+  /// Find a subnet node by its unique ``a`` parameter
   pub fn get_subnet_node_by_params(
     subnet_id: u32,
-    a: BoundedVec<u8, DefaultSubnetNodeParamLimit>,
+    a: Vec<u8>,
   ) -> Option<SubnetNode<T::AccountId>> {
     if !SubnetsData::<T>::contains_key(subnet_id) {
       return None
     }
 
-    SubnetNodesData::<T>::iter_prefix_values(subnet_id)
-      .find(|x| {
-        // Find by ``a``, a unique parameter
-        x.a == *a
-      })
+    let peer_id = SubnetNodeParam::<T>::try_get(subnet_id, a).ok()?;
+    let account_id = SubnetNodeAccount::<T>::try_get(subnet_id, peer_id).ok()?;
+    SubnetNodesData::<T>::try_get(subnet_id, account_id).ok()
   }
 
   // id is consensus ID
@@ -101,6 +100,35 @@ impl<T: Config> Pallet<T> {
     Self::get_min_subnet_delegate_stake_balance(min_nodes)
   }
 
+  /// Each eligible subnets emission at the end of the current epoch
+  // Previewed from the current stake vault balance, subsidies accrued until the end of the epoch are not included
+  pub fn get_subnet_emissions_preview() -> Vec<(u32, u128)> {
+    let block: u64 = Self::get_current_block_as_u64();
+    let epoch_length: u64 = T::EpochLength::get();
+    let epoch: u64 = block / epoch_length;
+    let budget: u128 = Self::get_epoch_emission_budget();
+    Self::get_subnet_emission_shares(epoch as u32)
+      .into_iter()
+      .map(|(subnet_id, share)| (subnet_id, Self::get_subnet_emission(budget, share)))
+      .collect()
+  }
+
+  /// Rewards receipt of a subnet on an epoch, ``None`` if never recorded or pruned
+  pub fn get_subnet_rewards_receipt(subnet_id: u32, epoch: u32) -> Option<SubnetRewardsReceipt<T::AccountId>> {
+    SubnetRewardsReceipts::<T>::get(subnet_id, epoch)
+  }
+
+  /// Metadata of a subnet and its revision, ``None`` if never set
+  pub fn get_subnet_metadata(subnet_id: u32) -> Option<(u32, SubnetMetadata)> {
+    SubnetsMetadata::<T>::get(subnet_id).map(|metadata| (SubnetMetadataRevision::<T>::get(subnet_id), metadata))
+  }
+
+  /// Cost of registering a subnet with ``memory_mb`` on the current block
+  pub fn get_subnet_registration_cost_quote(memory_mb: u128) -> u128 {
+    let block: u64 = Self::get_current_block_as_u64();
+    Self::get_subnet_initialization_cost(block, memory_mb)
+  }
+
   pub fn get_subnet_node_stake_by_peer_id(subnet_id: u32, peer_id: PeerId) -> u128 {
     match SubnetNodeAccount::<T>::try_get(subnet_id, peer_id.clone()) {
       Ok(account_id) => {
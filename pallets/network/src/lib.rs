@@ -44,11 +44,12 @@ pub use pallet::*;
 use codec::{Decode, Encode};
 use frame_support::{
 	dispatch::{DispatchResult},
-	traits::{tokens::WithdrawReasons, Get, Currency, ReservableCurrency, ExistenceRequirement, Randomness},
+	traits::{tokens::WithdrawReasons, Get, Currency, ReservableCurrency, ExistenceRequirement, Randomness, StorageVersion},
 	PalletId,
 	ensure,
 	fail,
 	storage::bounded_vec::BoundedVec,
+	BoundedBTreeSet,
 };
 use frame_system::{self as system, ensure_signed};
 use scale_info::prelude::string::String;
@@ -91,6 +92,14 @@ mod rewards;
 mod info;
 mod proposal;
 mod admin;
+mod treasury;
+mod subnet_owner;
+mod subnet_pause;
+mod voting_power;
+pub mod migrations;
+
+/// The current storage version
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 // All pallet logic is defined in its own module and must be annotated by the `pallet` attribute.
 #[frame_support::pallet]
@@ -106,6 +115,7 @@ pub mod pallet {
 	// (`Call`s) in this pallet.
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// The pallet's configuration trait.
@@ -152,6 +162,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxStakeUnlockings: Get<u32>;
 
+		/// Upper bound of the ``MaxSubnetNodes`` storage value and of subnet registration whitelists
+		#[pallet::constant]
+		type MaxSubnetNodes: Get<u32>;
+
+		/// Maximum length of a subnet path, the unique key of a subnet in ``SubnetPaths``
+		#[pallet::constant]
+		type MaxPathLength: Get<u32>;
+
 		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
 		#[pallet::constant]
@@ -183,12 +201,33 @@ pub mod pallet {
 		SubnetRegistered { account_id: T::AccountId, path: Vec<u8>, subnet_id: u32 },
 		SubnetActivated { subnet_id: u32 },
 		SubnetDeactivated { subnet_id: u32, reason: SubnetRemovalReason },
+		SubnetTeardownProgress { subnet_id: u32, processed: u32 },
+		SubnetCleared { subnet_id: u32 },
+
+		// Subnet owner
+		SubnetOwnershipTransferred { subnet_id: u32, from: T::AccountId, to: T::AccountId },
+		SubnetOwnershipRenounced { subnet_id: u32, owner: T::AccountId },
+		SubnetRegistrationWhitelistUpdated { subnet_id: u32, whitelist: BTreeSet<T::AccountId> },
+		SubnetRegistrationPeerWhitelistUpdated { subnet_id: u32, whitelist: BTreeSet<PeerId> },
+		SubnetRegistrationWhitelistLifted { subnet_id: u32 },
+		SubnetPermissionedUpdated { subnet_id: u32, permissioned: bool },
+		SubnetOwnerDepositRefunded { subnet_id: u32, account_id: T::AccountId, amount: u128 },
+		SubnetPaused { subnet_id: u32, account_id: Option<T::AccountId> },
+		SubnetUnpaused { subnet_id: u32 },
+		SubnetPauseBondRefunded { subnet_id: u32, account_id: T::AccountId, amount: u128 },
+		SubnetPauseBondForfeited { subnet_id: u32, account_id: T::AccountId, amount: u128 },
+		SubnetMetadataUpdated { subnet_id: u32, revision: u32, metadata: SubnetMetadata },
+		SubnetUpdated { subnet_id: u32, subnet_data: SubnetData<T::MaxPathLength> },
 
 		// Subnet Nodes
 		SubnetNodeRegistered { subnet_id: u32, account_id: T::AccountId, peer_id: PeerId, block: u64 },
 		SubnetNodeActivated { subnet_id: u32, account_id: T::AccountId },
 		SubnetNodeDeactivated { subnet_id: u32, account_id: T::AccountId },
+		SubnetNodeReactivated { subnet_id: u32, account_id: T::AccountId },
+		AccountPenalized { account_id: T::AccountId, penalties: u32 },
+		AccountSubnetNodesRemoved { account_id: T::AccountId, removed: u32, banned_until_epoch: u64 },
 		SubnetNodeRemoved { subnet_id: u32, account_id: T::AccountId },
+		SubnetNodeParamsUpdated { subnet_id: u32, account_id: T::AccountId, b: Vec<u8>, c: Vec<u8> },
 
 		// Stake
 		StakeAdded(u32, T::AccountId, u128),
@@ -220,11 +259,34 @@ pub mod pallet {
 		SetSubnetPerNodeInitCost(u128),
 		SetSubnetConsensusUnconfirmedThreshold(u128),
 		SetRemoveSubnetNodeEpochPercentage(u128),
+		SetSubnetProposalReasons(u32, BTreeSet<ProposalReason>),
+		SetSubnetProposalReasonAttests(u32, ProposalReason, u32),
+		SetUnpaidEmissionsPolicy(UnpaidEmissionsPolicy),
+		SetRewardsReceiptRetentionEpochs(u32),
+		SetEpochProcessingNodesPerBlock(u32),
+		SetTreasurySplit(TreasurySplit),
+		SetMinSubnetRegistrationCost(u128),
+		SetSubnetRegistrationCostMultiplier(u128),
+		SetSubnetRegistrationCostDecayBlocks(u64),
+		SetSubnetTeardownItemsPerCall(u32),
+		SetSubnetOwnerDeposit(u128),
+		SetSubnetNodeParamsUpdateInterval(u64),
+		SetMaxSubnetNodeDeactivationEpochs(u64),
+		SetAccountPenaltyBanEpochs(u64),
+		SetSubnetPauseBond(u128),
+		SetMaxSubnetPauseEpochs(u64),
+		SetMinSubnetPauseEpochs(u64),
+		SetAppealPeriod(u64),
+		SetAppealBondAmount(u128),
 
 		// Proposals
-		Proposal { subnet_id: u32, proposal_id: u32, epoch: u32, plaintiff: T::AccountId, defendant: T::AccountId, plaintiff_data: Vec<u8> },
+		Proposal { subnet_id: u32, proposal_id: u32, epoch: u32, plaintiff: T::AccountId, defendant: T::AccountId, reason: ProposalReason, plaintiff_data: Vec<u8> },
 		ProposalChallenged { subnet_id: u32, proposal_id: u32, defendant: T::AccountId, defendant_data: Vec<u8> },
 		ProposalAttested { subnet_id: u32, proposal_id: u32, account_id: T::AccountId, attestor_data: Vec<u8> },
+		ProposalAttestsReached { subnet_id: u32, proposal_id: u32 },
+		ProposalAppealed { subnet_id: u32, proposal_id: u32, appellant: T::AccountId },
+		ProposalAppealResolved { subnet_id: u32, proposal_id: u32, upheld: bool },
+		ProposalEnacted { subnet_id: u32, proposal_id: u32, outcome: ProposalOutcome },
 		ProposalVote { subnet_id: u32, proposal_id: u32, account_id: T::AccountId, vote: VoteType },
 		ProposalFinalized { subnet_id: u32, proposal_id: u32 },
 		ProposalCanceled { subnet_id: u32, proposal_id: u32 },
@@ -234,6 +296,21 @@ pub mod pallet {
 		Attestation { subnet_id: u32, account_id: T::AccountId, epoch: u32},
 
 		Slashing { subnet_id: u32, account_id: T::AccountId, amount: u128},
+
+		// Treasury
+		TreasuryDeposited { amount: u128 },
+		TreasurySpent { beneficiary: T::AccountId, amount: u128 },
+
+		// Emissions
+		EpochEmissions { epoch: u32, budget: u128, emitted: u128, burned: u128 },
+		RewardsDistributed { 
+			subnet_id: u32, 
+			epoch: u32, 
+			node_rewards: u128, 
+			validator_reward: u128, 
+			delegate_stake_reward: u128, 
+			slashed: u128 
+		},
 	}
 
 	/// Errors that can be returned by this pallet.
@@ -268,6 +345,18 @@ pub mod pallet {
 		PeerIdNotExist,
 		/// Subnet peer doesn't exist
 		SubnetNodeNotExist,
+		/// Subnet node is already deactivated
+		SubnetNodeAlreadyDeactivated,
+		/// Subnet node is not deactivated
+		SubnetNodeNotDeactivated,
+		/// Subnet node has been deactivated longer than ``MaxSubnetNodeDeactivationEpochs``
+		SubnetNodeDeactivationExpired,
+		/// Subnet node is the validator of the current epoch
+		SubnetNodeIsValidator,
+		/// Max subnet node deactivation epochs must be greater than zero
+		InvalidMaxSubnetNodeDeactivationEpochs,
+		/// Subnet node parameters were updated too recently, see ``SubnetNodeParamsUpdateInterval``
+		SubnetNodeParamsRateLimitExceeded,
 		/// Subnet already exists
 		SubnetExist,
 		/// Max total subnet memory exceeded
@@ -276,6 +365,10 @@ pub mod pallet {
 		MaxSubnetMemory,
 		/// Invalid registration block
 		InvalidSubnetRegistrationBlocks,
+		/// Subnet path must not be empty
+		InvalidSubnetPath,
+		/// Subnet metadata must have a name
+		InvalidSubnetMetadata,
 		/// Subnet node must be unstaked to re-register to use the same balance
 		InvalidSubnetRegistrationCooldown,
 		/// Subnet minimum delegate stake balance is met
@@ -294,6 +387,8 @@ pub mod pallet {
 		AccountEligible,
 		/// Account is ineligible
 		AccountIneligible,
+		/// Account is banned from registering subnet nodes, see ``AccountBans``
+		AccountBanned,
 		// invalid submit consensus block
 		/// Cannot submit consensus during invalid blocks
 		InvalidSubmitEpochLength,
@@ -322,6 +417,44 @@ pub mod pallet {
 		InvalidMaxSubnetNodes,
 		/// Invalid minimum stake balance, must be greater than or equal to minimim required stake balance
 		InvalidMinStakeBalance,
+		/// Invalid max subnet rewards weight, must be greater than zero and not exceed 100%
+		InvalidMaxSubnetRewardsWeight,
+		/// Invalid stake reward weight, must not exceed 100%
+		InvalidStakeRewardWeight,
+		/// Invalid rewards receipt retention epochs, must be greater than zero
+		InvalidRewardsReceiptRetentionEpochs,
+		/// Invalid epoch processing nodes per block, must be at least ``MaxSubnetNodes``
+		InvalidEpochProcessingNodesPerBlock,
+		/// Invalid subnet teardown items per call, must be greater than zero
+		InvalidSubnetTeardownItemsPerCall,
+		/// Subnet has no teardown in progress
+		SubnetTeardownNotExist,
+		/// Caller is not the subnet owner
+		NotSubnetOwner,
+		/// Subnet owners cannot update the subnet memory, it remains under subnet democracy
+		SubnetOwnerUpdateNotAllowed,
+		/// Registration whitelist can't exceed ``MaxSubnetNodes``
+		InvalidSubnetRegistrationWhitelist,
+		/// Account or peer ID is not on the registration whitelist of the subnet
+		NotSubnetRegistrationWhitelisted,
+		/// Subnet must be activated to be paused
+		SubnetNotActivated,
+		/// Subnet is paused
+		SubnetIsPaused,
+		/// Subnet is not paused
+		SubnetNotPaused,
+		/// Subnet was paused by the council and can only be unpaused by the council
+		SubnetPausedByCouncil,
+		/// Max subnet pause epochs must be greater than zero and at least the min subnet pause epochs
+		InvalidMaxSubnetPauseEpochs,
+		/// Min subnet pause epochs must not exceed the max subnet pause epochs
+		InvalidMinSubnetPauseEpochs,
+		/// Subnet must be paused for ``MinSubnetPauseEpochs`` before its owner can unpause it
+		SubnetPauseTooShort,
+		/// Treasury split percentage above ``PERCENTAGE_FACTOR``
+		InvalidTreasurySplit,
+		/// Treasury balance too low for the spend
+		InsufficientTreasuryBalance,
 		/// Invalid percent number, must be in 1e4 format. Used for elements that only require correct format
 		InvalidPercent,
 		/// Invalid subnet peer consensus submit percent requirement
@@ -341,8 +474,12 @@ pub mod pallet {
 		InvalidNodeConsensusDishonestyEpochs,
 		/// Invalid max outlier delta percentage, must be in format convertible to f64
 		InvalidMaxOutlierDeltaPercent,
-		/// Invalid subnet per peer init cost, must be greater than 0 and less than 1000
+		/// Invalid subnet per peer init cost, must be greater than 0
 		InvalidSubnetPerNodeInitCost,
+		/// Invalid subnet registration cost multiplier, must be at least ``PERCENTAGE_FACTOR``
+		InvalidSubnetRegistrationCostMultiplier,
+		/// Invalid subnet registration cost decay blocks, must be greater than 0
+		InvalidSubnetRegistrationCostDecayBlocks,
 		/// Invalid subnet consensus uncunfirmed threshold, must be in 1e4 format
 		InvalidSubnetConsensusUnconfirmedThreshold,
 		/// Invalid remove subnet peer epoch percentage, must be in 1e4 format and greater than 20.00
@@ -467,6 +604,32 @@ pub mod pallet {
 		ProposalComplete,
 		/// Subnet node as defendant has proposal activated already
 		NodeHasActiveProposal,
+		/// Proposal reason not allowed by the subnet
+		ProposalReasonNotAllowed,
+		/// Proposal hasn't reached the required attestations to be challenged
+		ProposalNotAttested,
+		/// Plaintiff or defendant cannot attest to a proposal
+		PartiesCannotAttest,
+		/// Required attestations must be reachable by the minimum proposal subnet nodes
+		InvalidProposalReasonAttests,
+		/// Proposal has no outcome to appeal or enact
+		ProposalNotFinalized,
+		/// Proposal outcome already enacted
+		ProposalEnacted,
+		/// Only the losing party can appeal a proposal outcome
+		NotLosingParty,
+		/// Appeal period has passed
+		AppealPeriodPassed,
+		/// Appeal period not yet passed
+		AppealPeriodNotPassed,
+		/// Proposal has an appeal pending
+		ProposalAppealed,
+		/// Proposal has no appeal pending
+		AppealNotExist,
+		/// Not enough balance to withdraw bond for appeal
+		NotEnoughBalanceToAppeal,
+		/// Subnet update doesn't change any parameters
+		SubnetUpdateEmpty,
 	}
 	
 	/// Subnet node classification
@@ -504,6 +667,9 @@ pub mod pallet {
 		pub account_id: AccountId,
 		pub hotkey: AccountId,
 		pub peer_id: PeerId,
+		pub a: Vec<u8>,
+		pub b: Vec<u8>,
+		pub c: Vec<u8>,
 	}
 
 	#[derive(Encode, Decode, scale_info::TypeInfo, Clone, PartialEq, Eq)]
@@ -618,6 +784,7 @@ pub mod pallet {
 	// 	}
 	// }
 
+	/// Deactivated: Subnet node is temporarily deactivated by its operator, not included in consensus
 	/// Registered: Subnet node registered, not included in consensus
 	/// Idle: Subnet node is activated as idle, unless subnet is registering, and automatically updates on the first successful consensus epoch
 	/// Included: Subnet node automatically updates to Included from Idle on the first successful consensus epoch after being Idle
@@ -625,11 +792,13 @@ pub mod pallet {
 	/// Accountant:  Subnet node updates to Accountant after multiple successful validations
 	#[derive(Default, EnumIter, FromRepr, Copy, Encode, Decode, Clone, PartialOrd, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
   pub enum SubnetNodeClass {
-		#[default] Registered,
-    Idle,
-    Included,
-		Submittable,
-		Accountant
+		// Codec indexes are pinned to the order classes were added in so stored classifications keep decoding
+		#[codec(index = 5)] Deactivated,
+		#[default] #[codec(index = 0)] Registered,
+    #[codec(index = 1)] Idle,
+    #[codec(index = 2)] Included,
+		#[codec(index = 3)] Submittable,
+		#[codec(index = 4)] Accountant
   }
 
 	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
@@ -661,8 +830,64 @@ pub mod pallet {
 		MinSubnetDelegateStake,
 		Council,
 		EnactmentPeriod,
+		// Subnet democracy vote out reasons
+		SubnetEmissionsManipulation,
+		SubnetDown,
+		SubnetCloseSourced,
+		SubnetBroken,
+		SubnetMinimumNodes,
+		SubnetIllicit,
+		// Paused longer than ``MaxSubnetPauseEpochs`` without the minimum subnet nodes
+		MaxPauseLength,
   }
 
+	/// What happens to the share of an epochs emission budget that is not paid out
+	/// e.g. subnets that are broken or fail attestation
+	#[derive(Default, Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub enum UnpaidEmissionsPolicy {
+		/// Remains in the ``StakeVaultBalance`` for the next epochs budget
+		#[default]
+		Rollover,
+		/// Removed from the ``StakeVaultBalance``
+		Burn,
+	}
+
+	/// Portions sent to the treasury account, see ``treasury_account``
+	/// Percentages use ``PERCENTAGE_FACTOR``
+	#[derive(Default, Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct TreasurySplit {
+		/// Of the subnet registration fee, the rest goes to the ``StakeVaultBalance``
+		pub registration_fee: u128,
+		/// Of validator slashes, the rest is burned
+		pub slash: u128,
+		/// Of the dust left over from distributing a proposal bond, the rest goes to the winner
+		pub proposal_dust: u128,
+		/// Of the block subsidy, the rest goes to the block author and the ``StakeVaultBalance``
+		pub block_subsidy: u128,
+	}
+
+	/// Step of the epoch processing carried out over multiple blocks in ``on_initialize``
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub enum EpochProcessingStep {
+		/// Rewarding subnets for ``epoch``, scanning ``SubnetsData`` from the raw key ``cursor``
+		Rewards { epoch: u32, budget: u128, emitted: u128, cursor: Option<Vec<u8>> },
+		/// Choosing validators of subnets for ``epoch``, scanning ``SubnetsData`` from the raw key ``cursor``
+		Preliminaries { epoch: u32, cursor: Option<Vec<u8>> },
+	}
+
+	/// Step of the teardown of a deactivated subnet carried out over multiple blocks, see ``do_clear_subnet``
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub enum SubnetTeardownStep {
+		/// Moving the node stake of the accounts in ``SubnetStakeAccounts`` into unbonding
+		NodeStake,
+		/// Moving the delegate stake of the accounts in ``SubnetDelegateStakeAccounts`` into unbonding
+		DelegateStake,
+		/// Returning the bonds of unsettled proposals and appeals
+		ProposalBonds,
+		/// Removing the ``map``th subnet prefixed storage map from ``cursor``
+		Storage { map: u8, cursor: Option<Vec<u8>> },
+	}
+
 	/// Attests format for consensus
 	/// ``u64`` is the block number of the accounts attestation for subnets to utilize to measure attestation speed
 	/// The blockchain itself doesn't utilize this data
@@ -676,6 +901,24 @@ pub mod pallet {
 		pub args: Option<BoundedVec<u8, DefaultValidatorArgsLimit>>, // Optional arguements to pass for subnet to validate
 	}
 
+	/// Reward paid to a subnet node on an epoch based on its score
+	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct SubnetNodeReward<AccountId> {
+		pub account_id: AccountId,
+		pub reward: u128,
+	}
+
+	/// Record of the rewards and slashes of a subnet on an epoch
+	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct SubnetRewardsReceipt<AccountId> {
+		pub validator: AccountId, // Chosen validator of the epoch
+		pub validator_reward: u128, // Paid to the validator on top of its node reward
+		pub node_rewards: BoundedVec<SubnetNodeReward<AccountId>, DefaultSubnetRewardsReceiptLimit>,
+		pub unlisted_node_rewards: u128, // Node rewards paid past the ``node_rewards`` limit
+		pub delegate_stake_reward: u128, // Paid to the subnets delegate stake pool
+		pub validator_slash: u128, // Slashed from the validator
+	}
+
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
   pub struct MinNodesCurveParametersSet {
 		pub x_curve_start: u128, // The range of ``max-min`` to start descending the curve
@@ -690,14 +933,61 @@ pub mod pallet {
     Nay,
   }
 
+	/// Reason a plaintiff is bringing a proposal against a subnet node
+	/// Offline: Subnet node is not hosting the subnet or is unreachable
+	/// InvalidInference: Subnet node returned invalid or manipulated inference
+	/// CopiedWeights: Subnet node copied the weights of another subnet node
+	/// Other: Any other reason, described in the evidence
+	#[derive(Default, EnumIter, Copy, Encode, Decode, Clone, PartialOrd, Ord, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+  pub enum ProposalReason {
+		#[default] Other,
+    Offline,
+    InvalidInference,
+		CopiedWeights,
+  }
+
 	/// Subnet data used before activation
-	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
-	pub struct RegistrationSubnetData {
-		pub path: Vec<u8>,
+	#[derive(Encode, Decode, DefaultNoBound, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, scale_info::TypeInfo)]
+	#[scale_info(skip_type_params(MaxPathLength))]
+	pub struct RegistrationSubnetData<MaxPathLength: Get<u32>> {
+		pub path: BoundedVec<u8, MaxPathLength>,
 		pub memory_mb: u128,
 		pub registration_blocks: u64,
 	}
 	
+	/// Subnet parameters updated by subnet democracy
+	/// ``None`` keeps the subnets current value
+	/// ``registration_blocks`` can only be updated while the subnet is registering
+	/// ``min_nodes`` and ``target_nodes`` are derived from ``memory_mb`` unless overridden
+	#[derive(Default, Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct SubnetUpdateData {
+		pub memory_mb: Option<u128>,
+		pub registration_blocks: Option<u64>,
+		pub min_nodes: Option<u32>,
+		pub target_nodes: Option<u32>,
+	}
+
+	/// Minimum hardware a subnet node is expected to run the subnet on
+	/// These are hints for node operators and aren't enforced on chain
+	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct SubnetHardwareHints {
+		pub memory_mb: u128, // GPU memory
+		pub gpus: u32,
+		pub storage_gb: u64,
+		pub bandwidth_mbps: u64,
+	}
+
+	/// Description of the model a subnet runs so node operators can verify they run the right model
+	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct SubnetMetadata {
+		pub name: BoundedVec<u8, DefaultSubnetMetadataNameLimit>,
+		pub model: BoundedVec<u8, DefaultSubnetMetadataUriLimit>, // Model identifier, e.g. a model repository ID
+		pub content_hash: BoundedVec<u8, DefaultSubnetMetadataHashLimit>, // Hash of the model weights
+		pub source_uri: BoundedVec<u8, DefaultSubnetMetadataUriLimit>,
+		pub hardware: SubnetHardwareHints,
+		pub version: BoundedVec<u8, DefaultSubnetMetadataVersionLimit>,
+	}
+
 	/// Subnet data used before activation
 	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
 	pub struct RegisteredSubnetNodesData<AccountId> {
@@ -707,22 +997,26 @@ pub mod pallet {
 
 	/// Data for subnet held to be compared when adding a subnet to the network
 	// This is the data from the democracy voting pallet
-	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
-	pub struct SubnetDemocracySubnetData {
-		pub data: RegistrationSubnetData,
+	#[derive(Encode, Decode, DefaultNoBound, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, scale_info::TypeInfo)]
+	#[scale_info(skip_type_params(MaxPathLength))]
+	pub struct SubnetDemocracySubnetData<MaxPathLength: Get<u32>> {
+		pub data: RegistrationSubnetData<MaxPathLength>,
 		pub active: bool,
 	}
 
-	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
-	pub struct SubnetData {
+	#[derive(Encode, Decode, DefaultNoBound, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, scale_info::TypeInfo)]
+	#[scale_info(skip_type_params(MaxPathLength))]
+	pub struct SubnetData<MaxPathLength: Get<u32>> {
 		pub id: u32,
-		pub path: Vec<u8>,
+		pub path: BoundedVec<u8, MaxPathLength>,
 		pub min_nodes: u32,
 		pub target_nodes: u32,
 		pub memory_mb: u128,
 		pub initialized: u64,
 		pub registration_blocks: u64,
 		pub activated: u64,
+		/// Block the subnet was paused on, 0 if not paused
+		pub paused: u64,
 	}
 
 	// `data` is an arbitrary vec of data for subnets to use for validation
@@ -747,6 +1041,30 @@ pub mod pallet {
 		pub nay: BTreeSet<AccountId>,
 	}
 
+	/// Winning party of a finalized proposal
+	#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+  pub enum ProposalOutcome {
+    Plaintiff,
+    Defendant,
+  }
+
+	impl ProposalOutcome {
+		pub fn reversed(&self) -> Self {
+			match self {
+				ProposalOutcome::Plaintiff => ProposalOutcome::Defendant,
+				ProposalOutcome::Defendant => ProposalOutcome::Plaintiff,
+			}
+		}
+	}
+
+	/// Appeal of a proposal outcome to the council
+	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct AppealParams<AccountId> {
+		pub appellant: AccountId,
+		pub bond: u128,
+		pub block: u64,
+	}
+
 	#[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
 	pub struct ProposalParams<AccountId> {
 		pub subnet_id: u32,
@@ -756,11 +1074,18 @@ pub mod pallet {
 		pub defendant_bond: u128,
 		pub eligible_voters: BTreeSet<AccountId>, // Those eligible to vote at time of the proposal
 		pub votes: VoteParams<AccountId>,
+		pub reason: ProposalReason,
+		pub attests: Attests<AccountId>, // Attestations of the plaintiffs evidence by eligible voters
+		pub required_attests: u32, // Attestations required before the defendant must respond
+		pub attested_block: Option<u64>, // Block the required attestations were reached, starts the challenge period
 		pub start_block: u64,
 		pub challenge_block: u64,
-		pub plaintiff_data: Vec<u8>,
-		pub defendant_data: Vec<u8>,
+		pub plaintiff_data: BoundedVec<u8, DefaultProposalEvidenceLimit>, // Evidence hash or URI
+		pub defendant_data: BoundedVec<u8, DefaultProposalEvidenceLimit>, // Evidence hash or URI
 		pub complete: bool,
+		pub outcome: Option<ProposalOutcome>, // Winning party, enacted after the appeal period
+		pub finalized_block: u64,
+		pub enacted: bool,
 	}
 
 	#[pallet::type_value]
@@ -788,9 +1113,9 @@ pub mod pallet {
 		0
 	}
 	#[pallet::type_value]
-	pub fn DefaultSubnetDemocracySubnetData() -> SubnetDemocracySubnetData {
+	pub fn DefaultSubnetDemocracySubnetData<T: Config>() -> SubnetDemocracySubnetData<T::MaxPathLength> {
 		let pre_subnet_data = RegistrationSubnetData {
-			path: Vec::new(),
+			path: BoundedVec::new(),
 			memory_mb: 0,
 			registration_blocks: 0,
 		};
@@ -866,6 +1191,10 @@ pub mod pallet {
 		0
 	}
 	#[pallet::type_value]
+	pub fn DefaultAccountPenaltyBanEpochs() -> u64 {
+		100
+	}
+	#[pallet::type_value]
 	pub fn DefaultMinRequiredUnstakeEpochs() -> u64 {
 		12
 	}
@@ -938,6 +1267,16 @@ pub mod pallet {
 		1e+18 as u128
 	}
 	#[pallet::type_value]
+	pub fn DefaultStakeRewardWeight() -> u128 {
+		// 50%
+		500000000
+	}
+	#[pallet::type_value]
+	pub fn DefaultMaxSubnetRewardsWeight() -> u128 {
+		// 100%
+		1000000000
+	}
+	#[pallet::type_value]
 	pub fn DefaultBaseValidatorReward() -> u128 {
 		1e+18 as u128
 	}
@@ -978,6 +1317,24 @@ pub mod pallet {
 		333333333
 	}
 	#[pallet::type_value]
+	pub fn DefaultMinSubnetRegistrationCost<T: Config>() -> u128 {
+		T::SubnetInitializationCost::get()
+	}
+	#[pallet::type_value]
+	pub fn DefaultSubnetRegistrationCostMultiplier() -> u128 {
+		// 2.0
+		2000000000
+	}
+	#[pallet::type_value]
+	pub fn DefaultSubnetRegistrationCostDecayBlocks() -> u64 {
+		// 1 week of 6 second blocks
+		100_800
+	}
+	#[pallet::type_value]
+	pub fn DefaultSubnetPerNodeInitCost() -> u128 {
+		1e+18 as u128
+	}
+	#[pallet::type_value]
 	pub fn DefaultBaseSubnetNodeMemoryMB() -> u128 {
 		16_000
 	}
@@ -1045,13 +1402,74 @@ pub mod pallet {
 		1024_u32
 	}
 	#[pallet::type_value]
+	pub fn DefaultSubnetRewardsReceiptLimit() -> u32 {
+		// Upper bound of ``MaxSubnetNodes``
+		1000
+	}
+	#[pallet::type_value]
+	pub fn DefaultRewardsReceiptsPrunedPerEpoch() -> u32 {
+		64
+	}
+	#[pallet::type_value]
+	pub fn DefaultEpochProcessingNodesPerBlock() -> u32 {
+		1024
+	}
+	#[pallet::type_value]
+	pub fn DefaultSubnetTeardownItemsPerCall() -> u32 {
+		256
+	}
+	#[pallet::type_value]
+	pub fn DefaultRewardsReceiptRetentionEpochs() -> u32 {
+		30
+	}
+	#[pallet::type_value]
 	pub fn DefaultSubnetNodeParamLimit() -> u32 {
 		2024
 	}
 	#[pallet::type_value]
+	pub fn DefaultSubnetNodeParamsUpdateInterval<T: Config>() -> u64 {
+		T::EpochLength::get()
+	}
+	#[pallet::type_value]
+	pub fn DefaultMaxSubnetNodeDeactivationEpochs() -> u64 {
+		8
+	}
+	#[pallet::type_value]
+	pub fn DefaultSubnetPauseBond() -> u128 {
+		1000e+18 as u128
+	}
+	#[pallet::type_value]
+	pub fn DefaultMaxSubnetPauseEpochs() -> u64 {
+		24
+	}
+	#[pallet::type_value]
+	pub fn DefaultMinSubnetPauseEpochs() -> u64 {
+		4
+	}
+	#[pallet::type_value]
 	pub fn DefaultValidatorArgsLimit() -> u32 {
 		4096
 	}
+	#[pallet::type_value]
+	pub fn DefaultProposalEvidenceLimit() -> u32 {
+		512
+	}
+	#[pallet::type_value]
+	pub fn DefaultSubnetMetadataNameLimit() -> u32 {
+		64
+	}
+	#[pallet::type_value]
+	pub fn DefaultSubnetMetadataUriLimit() -> u32 {
+		256
+	}
+	#[pallet::type_value]
+	pub fn DefaultSubnetMetadataHashLimit() -> u32 {
+		64
+	}
+	#[pallet::type_value]
+	pub fn DefaultSubnetMetadataVersionLimit() -> u32 {
+		32
+	}
 
 	/// Count of subnets
 	#[pallet::storage]
@@ -1065,7 +1483,7 @@ pub mod pallet {
 	// Mapping of each subnet stored by ID, uniqued by `SubnetPaths`
 	// Stores subnet data by a unique id
 	#[pallet::storage] // subnet_id => data struct
-	pub type SubnetsData<T: Config> = StorageMap<_, Blake2_128Concat, u32, SubnetData>;
+	pub type SubnetsData<T: Config> = StorageMap<_, Blake2_128Concat, u32, SubnetData<T::MaxPathLength>>;
 
 	/// Maximum subnet memory per subnet
 	#[pallet::storage]
@@ -1175,7 +1593,8 @@ pub mod pallet {
 	// #[pallet::getter(fn pending_actions)]
 	// pub type SubnetNodePendingActionsLedger<T: Config> = StorageValue<_, SubnetNodePendingActions<T::AccountId>, ValueQuery>;
 		
-	#[pallet::storage]
+	/// Subnet nodes as they were before being deactivated, used to return them to their class on reactivation
+	#[pallet::storage] // subnet_id --> account_id --> subnet_node
 	pub type DeactivateSubnetNodeLedger<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
@@ -1187,6 +1606,11 @@ pub mod pallet {
 		DefaultSubnetNode<T>,
 	>;
 
+	/// Total epochs a subnet node can stay deactivated. If surpassed, they are removed on the first successful
+	/// consensus epoch
+	#[pallet::storage]
+	pub type MaxSubnetNodeDeactivationEpochs<T> = StorageValue<_, u64, ValueQuery, DefaultMaxSubnetNodeDeactivationEpochs>;
+
 	/// Total epochs a subnet node can stay in registration phase. If surpassed, they are removed on the first successful
 	/// consensus epoch
 	#[pallet::storage]
@@ -1227,7 +1651,7 @@ pub mod pallet {
 		DefaultAccountId<T>,
 	>;
 
-	// Used for unique peer_ids
+	// Used for unique ``a`` parameters
 	#[pallet::storage] // subnet_id --> param --> peer_id
 	pub type SubnetNodeParam<T: Config> = StorageDoubleMap<
 		_,
@@ -1239,6 +1663,21 @@ pub mod pallet {
 		ValueQuery,
 		DefaultPeerId,
 	>;
+
+	/// Minimum blocks between updates of a subnet nodes ``b`` and ``c`` parameters
+	#[pallet::storage]
+	pub type SubnetNodeParamsUpdateInterval<T> = StorageValue<_, u64, ValueQuery, DefaultSubnetNodeParamsUpdateInterval<T>>;
+
+	#[pallet::storage] // subnet_id --> account_id --> block
+	pub type SubnetNodeParamsLastUpdate<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		Blake2_128Concat,
+		T::AccountId,
+		u64,
+		OptionQuery,
+	>;
 	
 	#[pallet::storage] // subnet_id -> class_id -> BTreeMap(account_id, block)
 	pub type SubnetNodesClasses<T: Config> = StorageDoubleMap<
@@ -1265,12 +1704,11 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type MaxAccountPenaltyCount<T> = StorageValue<_, u32, ValueQuery, DefaultMaxAccountPenaltyCount>;
 
-	// Count of times a peer is against consensus
+	// Count of times an account is penalized across all subnets
 	// This includes:
-	// 1. being against other peers that conclude another peer is out of consensus
-	// 2. being against other peers that conclude another peer is in consensus
-	// 3. score delta is too high on consensus data submission
-	// 4. not submitting consensus data
+	// 1. being slashed as a validator
+	// 2. losing a proposal
+	// 3. being removed from a subnet for surpassing ``MaxSubnetNodePenalties``
 	#[pallet::storage] // account_id --> u32
 	#[pallet::getter(fn subnet_node_penalty_count)]
 	pub type AccountPenaltyCount<T: Config> = StorageMap<
@@ -1282,6 +1720,13 @@ pub mod pallet {
 		DefaultAccountPenaltyCount
 	>;
 
+	/// Epochs an account is banned from registering subnet nodes once removed for surpassing ``MaxAccountPenaltyCount``
+	#[pallet::storage]
+	pub type AccountPenaltyBanEpochs<T> = StorageValue<_, u64, ValueQuery, DefaultAccountPenaltyBanEpochs>;
+
+	#[pallet::storage] // account_id --> epoch the ban ends
+	pub type AccountBans<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u64, OptionQuery>;
+
 	/// Base subnet node memory used for calculating minimum and target nodes for a subnet
 	#[pallet::storage]
 	pub type BaseSubnetNodeMemoryMB<T> = StorageValue<_, u128, ValueQuery, DefaultBaseSubnetNodeMemoryMB>;
@@ -1324,6 +1769,25 @@ pub mod pallet {
 		RewardsData<T::AccountId>,
 	>;
 
+	/// Rewards and slashes of each subnet on each epoch
+	#[pallet::storage] // subnet ID => epoch  => receipt
+	pub type SubnetRewardsReceipts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		Identity,
+		u32,
+		SubnetRewardsReceipt<T::AccountId>,
+	>;
+
+	/// Number of epochs a ``SubnetRewardsReceipts`` entry is kept
+	#[pallet::storage]
+	pub type RewardsReceiptRetentionEpochs<T> = StorageValue<_, u32, ValueQuery, DefaultRewardsReceiptRetentionEpochs>;
+
+	/// Oldest epoch of each subnet that may still have a ``SubnetRewardsReceipts`` entry
+	#[pallet::storage] // subnet ID => epoch
+	pub type SubnetRewardsReceiptsPruneEpoch<T> = StorageMap<_, Identity, u32, u32, OptionQuery>;
+
 	#[pallet::storage]
 	pub type MinAttestationPercentage<T> = StorageValue<_, u128, ValueQuery, DefaultMinAttestationPercentage>;
 
@@ -1338,9 +1802,71 @@ pub mod pallet {
 	pub type BaseValidatorReward<T> = StorageValue<_, u128, ValueQuery, DefaultBaseValidatorReward>;
 
 	/// Base reward per MB per epoch based on 4,380 MB per year
+	// Not used in ``reward_subnets``, subnet emissions are a weighted share of the ``StakeVaultBalance``
 	#[pallet::storage]
 	pub type BaseRewardPerMB<T> = StorageValue<_, u128, ValueQuery, DefaultBaseRewardPerMB>;
 
+	/// Whether the unpaid portion of an epochs emission budget rolls over or is burned
+	#[pallet::storage]
+	pub type UnpaidEmissions<T> = StorageValue<_, UnpaidEmissionsPolicy, ValueQuery>;
+
+	/// Portions of network fees and the block subsidy sent to the treasury
+	#[pallet::storage]
+	pub type TreasurySplits<T> = StorageValue<_, TreasurySplit, ValueQuery>;
+
+	/// Floor the subnet registration cost decays back to
+	#[pallet::storage]
+	pub type MinSubnetRegistrationCost<T> = StorageValue<_, u128, ValueQuery, DefaultMinSubnetRegistrationCost<T>>;
+
+	/// Multiplier applied to the registration cost on each subnet registration
+	#[pallet::storage]
+	pub type SubnetRegistrationCostMultiplier<T> = StorageValue<_, u128, ValueQuery, DefaultSubnetRegistrationCostMultiplier>;
+
+	/// Blocks for the registration cost to linearly decay from ``LastSubnetRegistrationCost`` to
+	/// ``MinSubnetRegistrationCost``
+	#[pallet::storage]
+	pub type SubnetRegistrationCostDecayBlocks<T> = StorageValue<_, u64, ValueQuery, DefaultSubnetRegistrationCostDecayBlocks>;
+
+	/// Registration cost per minimum subnet node required by a subnets ``memory_mb``
+	#[pallet::storage]
+	pub type SubnetPerNodeInitCost<T> = StorageValue<_, u128, ValueQuery, DefaultSubnetPerNodeInitCost>;
+
+	/// Registration cost, excluding the memory cost, set on the last subnet registration
+	#[pallet::storage]
+	pub type LastSubnetRegistrationCost<T> = StorageValue<_, u128, ValueQuery>;
+
+	#[pallet::storage]
+	pub type LastSubnetRegistrationBlock<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// Weight of a subnets delegate stake versus its memory in its share of the emission budget
+	#[pallet::storage]
+	pub type StakeRewardWeight<T> = StorageValue<_, u128, ValueQuery, DefaultStakeRewardWeight>;
+
+	/// Maximum share of the emission budget a single subnet can receive, the remainder is unpaid
+	#[pallet::storage]
+	pub type MaxSubnetRewardsWeight<T> = StorageValue<_, u128, ValueQuery, DefaultMaxSubnetRewardsWeight>;
+
+	/// Emission of each eligible subnet on the epoch being rewarded, taken once the subnet is rewarded
+	#[pallet::storage] // subnet ID => emission
+	pub type EpochSubnetEmissions<T> = StorageMap<_, Identity, u32, u128, ValueQuery>;
+
+	/// Cursor of the epoch processing in progress, ``None`` once complete
+	#[pallet::storage]
+	pub type EpochProcessing<T> = StorageValue<_, EpochProcessingStep, OptionQuery>;
+
+	/// Number of subnet nodes processed per block by the epoch processing
+	// Each subnet counts as its ``TotalSubnetNodes``, subnets are never split across blocks
+	#[pallet::storage]
+	pub type EpochProcessingNodesPerBlock<T> = StorageValue<_, u32, ValueQuery, DefaultEpochProcessingNodesPerBlock>;
+
+	/// Teardowns of deactivated subnets in progress, removed once the subnet is cleared
+	#[pallet::storage] // subnet ID => step
+	pub type SubnetTeardowns<T> = StorageMap<_, Identity, u32, SubnetTeardownStep, OptionQuery>;
+
+	/// Number of storage items processed per ``clear_subnet`` call, or per block in ``on_idle``
+	#[pallet::storage]
+	pub type SubnetTeardownItemsPerCall<T> = StorageValue<_, u32, ValueQuery, DefaultSubnetTeardownItemsPerCall>;
+
 	/// Assumed cost per MB for each epoch
 	// TODO: (not included in logic yet)
 	// This will help determine inflation for each epoch on the cost to run a subnet node
@@ -1443,6 +1969,18 @@ pub mod pallet {
 		DefaultAccountTake,
 	>;
 
+	/// Accounts with a balance in ``AccountSubnetStake``, keyed by subnet first
+	#[pallet::storage] // subnet_id --> account --> ()
+	pub type SubnetStakeAccounts<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u32,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	pub type SubnetStakeUnbondingLedger<T: Config> = StorageDoubleMap<
 		_,
@@ -1543,6 +2081,18 @@ pub mod pallet {
 		DefaultAccountTake,
 	>;
 
+	/// Accounts with shares in ``AccountSubnetDelegateStakeShares``, keyed by subnet first
+	#[pallet::storage] // subnet_id --> account --> ()
+	pub type SubnetDelegateStakeAccounts<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u32,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::storage] // account --> subnet_id --> u64
 	pub type DelegateStakeCooldown<T: Config> = StorageDoubleMap<
 		_,
@@ -1638,11 +2188,18 @@ pub mod pallet {
 				yay: BTreeSet::new(),
 				nay: BTreeSet::new(),
 			},
+			reason: ProposalReason::Other,
+			attests: BTreeMap::new(),
+			required_attests: 0,
+			attested_block: None,
 			start_block: 0,
 			challenge_block: 0,
-			plaintiff_data: Vec::new(),
-			defendant_data: Vec::new(),
+			plaintiff_data: BoundedVec::new(),
+			defendant_data: BoundedVec::new(),
 			complete: false,
+			outcome: None,
+			finalized_block: 0,
+			enacted: false,
 		};
 	}
 
@@ -1724,6 +2281,165 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ProposalConsensusThreshold<T> = StorageValue<_, u128, ValueQuery, DefaultProposalConsensusThreshold>;
 
+	#[pallet::type_value]
+	pub fn DefaultAppealPeriod() -> u64 {
+		// 7 days in blocks
+		100800
+	}
+
+	#[pallet::storage] // Period in blocks after a proposal is finalized for the losing party to appeal
+	pub type AppealPeriod<T> = StorageValue<_, u64, ValueQuery, DefaultAppealPeriod>;
+
+	#[pallet::type_value]
+	pub fn DefaultAppealBondAmount() -> u128 {
+		1e+18 as u128
+	}
+
+	// Amount required to put up to appeal a proposal outcome to the council
+	#[pallet::storage] 
+	pub type AppealBondAmount<T> = StorageValue<_, u128, ValueQuery, DefaultAppealBondAmount>;
+
+	#[pallet::storage] // subnet => proposal_id => appeal
+	pub type ProposalAppeals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		Identity,
+		u32,
+		AppealParams<T::AccountId>,
+		OptionQuery,
+	>;
+
+	#[pallet::type_value]
+	pub fn DefaultSubnetProposalReasons() -> BTreeSet<ProposalReason> {
+		ProposalReason::iter().collect()
+	}
+
+	/// Reasons a subnet allows proposals to be brought under, all reasons allowed by default
+	#[pallet::storage] // subnet_id => reasons
+	pub type SubnetProposalReasons<T> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		BTreeSet<ProposalReason>,
+		ValueQuery,
+		DefaultSubnetProposalReasons,
+	>;
+
+	/// Attestations required on a proposal of a reason before the defendant must respond
+	// If zero, the challenge period begins at the time of the proposal
+	#[pallet::storage] // subnet_id => reason => attestations
+	pub type SubnetProposalReasonAttests<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		Identity,
+		ProposalReason,
+		u32,
+		ValueQuery,
+		DefaultZeroU32,
+	>;
+
+	/// Owner of each subnet, the account that registered it unless transferred or renounced
+	#[pallet::storage] // subnet_id => account_id
+	pub type SubnetOwner<T: Config> = StorageMap<_, Blake2_128Concat, u32, T::AccountId, OptionQuery>;
+
+	/// Deposit reserved from the owner on subnet registration, refunded when the subnet is removed
+	// Disabled by default
+	#[pallet::storage]
+	pub type SubnetOwnerDeposit<T> = StorageValue<_, u128, ValueQuery>;
+
+	/// Registration deposit of each subnet and the account it's reserved from
+	#[pallet::storage] // subnet_id => (depositor, deposit)
+	pub type SubnetOwnerDeposits<T: Config> = StorageMap<_, Blake2_128Concat, u32, (T::AccountId, u128), OptionQuery>;
+
+	/// Accounts allowed to register subnet nodes while the subnet is registering, anyone if empty
+	/// along with ``SubnetRegistrationPeerWhitelist``
+	#[pallet::storage] // subnet_id => accounts
+	pub type SubnetRegistrationWhitelist<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		BTreeSet<T::AccountId>,
+		ValueQuery,
+	>;
+
+	/// Peer IDs allowed to register subnet nodes while the subnet is registering, anyone if empty
+	/// along with ``SubnetRegistrationWhitelist``
+	#[pallet::storage] // subnet_id => peer_ids
+	pub type SubnetRegistrationPeerWhitelist<T> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		BTreeSet<PeerId>,
+		ValueQuery,
+	>;
+
+	/// Subnets approved by governance to keep their registration whitelists after activation
+	#[pallet::storage] // subnet_id => permissioned
+	pub type SubnetPermissioned<T> = StorageMap<_, Blake2_128Concat, u32, bool, ValueQuery>;
+
+	/// Metadata of each subnet, set by the subnet owner or governance
+	#[pallet::storage] // subnet_id => metadata
+	pub type SubnetsMetadata<T> = StorageMap<_, Blake2_128Concat, u32, SubnetMetadata, OptionQuery>;
+
+	/// Number of times the metadata of each subnet has been set
+	#[pallet::storage] // subnet_id => revision
+	pub type SubnetMetadataRevision<T> = StorageMap<_, Blake2_128Concat, u32, u32, ValueQuery>;
+
+	/// Bond reserved from a subnet owner to pause their subnet
+	#[pallet::storage]
+	pub type SubnetPauseBond<T> = StorageValue<_, u128, ValueQuery, DefaultSubnetPauseBond>;
+
+	/// Pause bond of each subnet paused by its owner and the account it's reserved from
+	// Subnets paused by the council have no bond
+	#[pallet::storage] // subnet_id => (owner, bond)
+	pub type SubnetPauseBonds<T: Config> = StorageMap<_, Blake2_128Concat, u32, (T::AccountId, u128), OptionQuery>;
+
+	/// Total epochs a subnet can stay paused. Once surpassed, the subnet is unpaused if it has the minimum
+	/// subnet nodes, otherwise removed
+	#[pallet::storage]
+	pub type MaxSubnetPauseEpochs<T> = StorageValue<_, u64, ValueQuery, DefaultMaxSubnetPauseEpochs>;
+
+	/// Epochs a subnet paused by its owner stays paused before the owner can unpause it, so delegate stakers
+	/// exiting without the cooldown can't be followed by an immediate unpause
+	#[pallet::storage]
+	pub type MinSubnetPauseEpochs<T> = StorageValue<_, u64, ValueQuery, DefaultMinSubnetPauseEpochs>;
+
+	/// Blocks stake changes are tracked from for subnet democracy voting power and the number of proposals using each
+	#[pallet::storage] // block => proposals
+	pub type VotingPowerSnapshotBlocks<T> = StorageValue<_, BTreeMap<u64, u32>, ValueQuery>;
+
+	/// Snapshot blocks no longer used by any proposal, cleared in ``on_idle``
+	#[pallet::storage]
+	pub type VotingPowerSnapshotTeardowns<T> = StorageValue<_, BTreeSet<u64>, ValueQuery>;
+
+	/// Stake and delegate stake shares of an account in a subnet as of the start of a snapshot block,
+	/// recorded ahead of their first change since
+	#[pallet::storage] // block => (account_id, subnet_id) => (stake, delegate_stake_shares)
+	pub type AccountSubnetStakeSnapshots<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		u64,
+		Blake2_128Concat,
+		(T::AccountId, u32),
+		(u128, u128),
+		OptionQuery,
+	>;
+
+	/// Total stake, delegate stake balance and delegate stake shares of a subnet as of the start of a
+	/// snapshot block, recorded ahead of their first change since
+	#[pallet::storage] // block => subnet_id => (stake, delegate_stake_balance, delegate_stake_shares)
+	pub type SubnetStakeSnapshots<T> = StorageDoubleMap<
+		_,
+		Identity,
+		u64,
+		Identity,
+		u32,
+		(u128, u128, u128),
+		OptionQuery,
+	>;
+
 	/// The pallet's dispatchable functions ([`Call`]s).
 	///
 	/// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -1739,10 +2455,10 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::register_subnet())]
 		pub fn register_subnet(
 			origin: OriginFor<T>, 
-			subnet_data: RegistrationSubnetData,
+			subnet_data: RegistrationSubnetData<T::MaxPathLength>,
 		) -> DispatchResult {
 			let account_id: T::AccountId = ensure_signed(origin)?;
 	
@@ -1753,7 +2469,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::activate_subnet())]
 		pub fn activate_subnet(
 			origin: OriginFor<T>, 
 			subnet_id: u32,
@@ -1768,7 +2484,7 @@ pub mod pallet {
 		///   and
 		/// - The delegate stake balance is below the minimum required threshold
 		#[pallet::call_index(2)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::remove_subnet())]
 		pub fn remove_subnet(
 			origin: OriginFor<T>, 
 			subnet_id: u32,
@@ -1802,15 +2518,17 @@ pub mod pallet {
 			if penalties > MaxSubnetPenaltyCount::<T>::get() {
 				// --- If the subnet has reached max penalty, remove it
         Self::deactivate_subnet(
-          subnet.path,
+          subnet.path.into(),
           SubnetRemovalReason::MaxPenalties,
         ).map_err(|e| e)?;
+				return Ok(())
 			} else if subnet_delegate_stake_balance < min_subnet_delegate_stake_balance {
 				// --- If the delegate stake balance is below minimum threshold, remove it
         Self::deactivate_subnet(
-          subnet.path,
+          subnet.path.into(),
           SubnetRemovalReason::MinSubnetDelegateStake,
         ).map_err(|e| e)?;
+				return Ok(())
 			}
 
 			// --- If we make it to here, fail the extrinsic
@@ -1827,8 +2545,7 @@ pub mod pallet {
 		// Once you claim the peer_id, no one else can claim it.
 		// After RequiredSubnetNodeEpochs pass and the peer is in consensus, rewards will be emitted to the account
 		#[pallet::call_index(3)]
-		// #[pallet::weight(T::WeightInfo::add_subnet_node())]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::add_subnet_node())]
 		pub fn add_subnet_node(
 			origin: OriginFor<T>, 
 			subnet_id: u32, 
@@ -1855,7 +2572,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(4)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::register_subnet_node())]
 		pub fn register_subnet_node(
 			origin: OriginFor<T>, 
 			subnet_id: u32, 
@@ -1877,7 +2594,7 @@ pub mod pallet {
 		}
 		
 		#[pallet::call_index(5)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::activate_subnet_node())]
 		pub fn activate_subnet_node(
 			origin: OriginFor<T>, 
 			subnet_id: u32, 
@@ -1888,26 +2605,28 @@ pub mod pallet {
 			)
 		}	
 
+		/// Temporarily deactivate your subnet node, i.e. for maintenance
+		/// Deactivated subnet nodes are not chosen as validators or penalized for being absent from consensus data
+		// The subnet node is removed if it stays deactivated longer than ``MaxSubnetNodeDeactivationEpochs``
 		#[pallet::call_index(6)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::deactivate_subnet_node())]
 		pub fn deactivate_subnet_node(
 			origin: OriginFor<T>, 
 			subnet_id: u32, 
 		) -> DispatchResult {
-			// --- TODO: feature not finished yet
-			// Self::do_deactivate_subnet_node(
-			// 	origin,
-			// 	subnet_id,
-			// )
-			Ok(())
+			let account_id: T::AccountId = ensure_signed(origin)?;
+
+			Self::do_deactivate_subnet_node(
+				account_id,
+				subnet_id,
+			)
 		}
 		
 		/// Remove your subnet peer
 		/// Unstaking must be done seperately
 		/// Infallible
 		#[pallet::call_index(7)]
-		// #[pallet::weight(T::WeightInfo::remove_subnet_node())]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::remove_subnet_node())]
 		pub fn remove_subnet_node(
 			origin: OriginFor<T>, 
 			subnet_id: u32, 
@@ -1919,8 +2638,11 @@ pub mod pallet {
 
 		/// Remove a subnet peer that has surpassed the max penalties allowed
 		// This is redundant 
+		/// Remove the subnet nodes of an account past the ``MaxAccountPenaltyCount`` from all subnets
+		/// The account is banned from registering subnet nodes for ``AccountPenaltyBanEpochs``
+		// Anyone can call this
 		#[pallet::call_index(8)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::remove_account_subnet_nodes(MaxSubnets::<T>::get()))]
 		pub fn remove_account_subnet_nodes(
 			origin: OriginFor<T>, 
 			account_id: T::AccountId, 
@@ -1938,7 +2660,7 @@ pub mod pallet {
 				Error::<T>::AccountEligible
 			);
 
-			// Self::do_remove_account_subnet_nodes(block, account_id);
+			Self::do_remove_account_subnet_nodes(block, account_id);
 
 			Ok(())
 		}
@@ -1946,8 +2668,7 @@ pub mod pallet {
 		
 		/// Increase stake towards the specified subnet ID
 		#[pallet::call_index(9)]
-		// #[pallet::weight(T::WeightInfo::add_to_stake())]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::add_to_stake())]
 		pub fn add_to_stake(
 			origin: OriginFor<T>, 
 			subnet_id: u32,
@@ -1984,7 +2705,7 @@ pub mod pallet {
 		// to-do: if removed through consensus, add removed_block to storage and require time 
 		//				to pass until they can remove their stake
 		#[pallet::call_index(10)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::remove_stake())]
 		pub fn remove_stake(
 			origin: OriginFor<T>, 
 			subnet_id: u32, 
@@ -2013,7 +2734,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(11)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::claim_stake_unbondings(T::MaxStakeUnlockings::get()))]
 		pub fn claim_stake_unbondings(
 			origin: OriginFor<T>, 
 			subnet_id: u32, 
@@ -2029,8 +2750,7 @@ pub mod pallet {
 
 		/// Increase stake towards subnet ID
 		#[pallet::call_index(12)]
-		// #[pallet::weight(T::WeightInfo::add_to_delegate_stake())]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::add_to_delegate_stake())]
 		pub fn add_to_delegate_stake(
 			origin: OriginFor<T>, 
 			subnet_id: u32,
@@ -2053,7 +2773,7 @@ pub mod pallet {
 		
 		/// Swaps the balance of the ``from_subnet_id`` shares to ``to_subnet_id``
 		#[pallet::call_index(13)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::transfer_delegate_stake())]
 		pub fn transfer_delegate_stake(
 			origin: OriginFor<T>, 
 			from_subnet_id: u32, 
@@ -2082,8 +2802,7 @@ pub mod pallet {
 		/// Remove delegate stake and add to delegate stake unboding ledger
 		/// Enter shares and will convert to balance automatically
 		#[pallet::call_index(14)]
-		// #[pallet::weight(T::WeightInfo::remove_delegate_stake())]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::remove_delegate_stake())]
 		pub fn remove_delegate_stake(
 			origin: OriginFor<T>, 
 			subnet_id: u32, 
@@ -2097,8 +2816,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(15)]
-		// #[pallet::weight(T::WeightInfo::claim_delegate_stake_unbondings())]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::claim_delegate_stake_unbondings(T::MaxDelegateStakeUnlockings::get()))]
 		pub fn claim_delegate_stake_unbondings(
 			origin: OriginFor<T>, 
 			subnet_id: u32, 
@@ -2114,7 +2832,7 @@ pub mod pallet {
 		
 		/// Allows anyone to increase a subnets delegate stake pool
 		#[pallet::call_index(16)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::increase_delegate_stake())]
 		pub fn increase_delegate_stake(
 			origin: OriginFor<T>, 
 			subnet_id: u32,
@@ -2157,7 +2875,7 @@ pub mod pallet {
 
 		/// Delete proposals that are no longer live
 		#[pallet::call_index(17)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::validate())]
 		pub fn validate(
 			origin: OriginFor<T>, 
 			subnet_id: u32,
@@ -2182,7 +2900,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(18)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::attest())]
 		pub fn attest(
 			origin: OriginFor<T>, 
 			subnet_id: u32,
@@ -2203,12 +2921,13 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(19)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::propose())]
 		pub fn propose(
 			origin: OriginFor<T>, 
 			subnet_id: u32,
 			peer_id: PeerId,
-			data: Vec<u8>,
+			reason: ProposalReason,
+			data: BoundedVec<u8, DefaultProposalEvidenceLimit>,
 	) -> DispatchResult {
 			let account_id: T::AccountId = ensure_signed(origin)?;
 	
@@ -2216,30 +2935,31 @@ pub mod pallet {
 				account_id,
 				subnet_id,
 				peer_id,
+				reason,
 				data
 			)
 		}
 
 		#[pallet::call_index(20)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::attest_proposal())]
 		pub fn attest_proposal(
 			origin: OriginFor<T>, 
 			subnet_id: u32,
-			peer_id: PeerId,
-			data: Vec<u8>,
+			proposal_id: u32,
+			data: BoundedVec<u8, DefaultProposalEvidenceLimit>,
 	) -> DispatchResult {
 			let account_id: T::AccountId = ensure_signed(origin)?;
 	
-			Self::do_propose(
+			Self::do_attest_proposal(
 				account_id,
 				subnet_id,
-				peer_id,
+				proposal_id,
 				data
 			)
 		}
 
 		#[pallet::call_index(21)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::cancel_proposal())]
 		pub fn cancel_proposal(
 			origin: OriginFor<T>, 
 			subnet_id: u32,
@@ -2255,12 +2975,12 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(22)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::challenge_proposal())]
 		pub fn challenge_proposal(
 			origin: OriginFor<T>, 
 			subnet_id: u32,
 			proposal_id: u32,
-			data: Vec<u8>,
+			data: BoundedVec<u8, DefaultProposalEvidenceLimit>,
 	) -> DispatchResult {
 			let account_id: T::AccountId = ensure_signed(origin)?;
 	
@@ -2273,7 +2993,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(23)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::vote())]
 		pub fn vote(
 			origin: OriginFor<T>, 
 			subnet_id: u32,
@@ -2291,7 +3011,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(24)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::finalize_proposal(TotalSubnetNodes::<T>::get(subnet_id)))]
 		pub fn finalize_proposal(
 			origin: OriginFor<T>, 
 			subnet_id: u32,
@@ -2306,60 +3026,266 @@ pub mod pallet {
 			)
 		}
 
+		/// Continue the teardown of a deactivated subnet
+		// Anyone can call this to release the stake of a deactivated subnet sooner than ``on_idle`` would
 		#[pallet::call_index(25)]
-		#[pallet::weight({0})]
+		#[pallet::weight(T::WeightInfo::clear_subnet(SubnetTeardownItemsPerCall::<T>::get()))]
 		pub fn clear_subnet(
 			origin: OriginFor<T>, 
 			subnet_id: u32,
 	) -> DispatchResult {
 			ensure_signed(origin)?;
+
+			ensure!(
+				SubnetTeardowns::<T>::contains_key(subnet_id),
+				Error::<T>::SubnetTeardownNotExist
+			);
+
+			Self::do_clear_subnet(subnet_id, SubnetTeardownItemsPerCall::<T>::get());
+
 			Ok(())
 		}
 
+		/// Appeal a finalized proposal outcome to the council as the losing party
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::appeal_proposal())]
+		pub fn appeal_proposal(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			proposal_id: u32,
+	) -> DispatchResult {
+			let account_id: T::AccountId = ensure_signed(origin)?;
+	
+			Self::do_appeal_proposal(
+				account_id,
+				subnet_id,
+				proposal_id,
+			)
+		}
+
+		/// Enact a finalized proposal outcome once the appeal period has passed without an appeal
+		/// Weighed by the proposal's eligible voters, each of which may receive part of the losing bond
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::enact_proposal(
+			Proposals::<T>::get(subnet_id, proposal_id).eligible_voters.len() as u32
+		))]
+		pub fn enact_proposal(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			proposal_id: u32,
+	) -> DispatchResult {
+			ensure_signed(origin)?;
+	
+			Self::do_enact_proposal(
+				subnet_id,
+				proposal_id,
+			)
+		}
+
+		/// Update the subnet parameters as the subnet owner
+		/// The memory of the subnet can only be updated through subnet democracy
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::owner_update_subnet())]
+		pub fn owner_update_subnet(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			update: SubnetUpdateData,
+	) -> DispatchResult {
+			let account_id: T::AccountId = ensure_signed(origin)?;
+	
+			Self::do_owner_update_subnet(
+				account_id,
+				subnet_id,
+				update,
+			)
+		}
+
+		/// Set the accounts allowed to register subnet nodes while the subnet is registering
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::owner_set_registration_whitelist(whitelist.len() as u32))]
+		pub fn owner_set_registration_whitelist(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			whitelist: BoundedBTreeSet<T::AccountId, T::MaxSubnetNodes>,
+	) -> DispatchResult {
+			let account_id: T::AccountId = ensure_signed(origin)?;
+	
+			Self::do_set_subnet_registration_whitelist(
+				account_id,
+				subnet_id,
+				whitelist.into_inner(),
+			)
+		}
+
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::transfer_subnet_ownership())]
+		pub fn transfer_subnet_ownership(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			new_owner: T::AccountId,
+	) -> DispatchResult {
+			let account_id: T::AccountId = ensure_signed(origin)?;
+	
+			Self::do_transfer_subnet_ownership(
+				account_id,
+				subnet_id,
+				new_owner,
+			)
+		}
+
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::renounce_subnet_ownership())]
+		pub fn renounce_subnet_ownership(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+	) -> DispatchResult {
+			let account_id: T::AccountId = ensure_signed(origin)?;
+	
+			Self::do_renounce_subnet_ownership(
+				account_id,
+				subnet_id,
+			)
+		}
+
+		/// Set the subnet metadata as the subnet owner
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::owner_set_subnet_metadata())]
+		pub fn owner_set_subnet_metadata(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			metadata: SubnetMetadata,
+	) -> DispatchResult {
+			let account_id: T::AccountId = ensure_signed(origin)?;
+	
+			Self::do_owner_set_subnet_metadata(
+				account_id,
+				subnet_id,
+				metadata,
+			)
+		}
+
+		/// Update the ``b`` and ``c`` parameters of your subnet node
+		/// ``None`` leaves the parameter unchanged
+		// ``a`` is unique within the subnet and can only be set at registration
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::update_subnet_node_params())]
+		pub fn update_subnet_node_params(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			b: Option<BoundedVec<u8, DefaultSubnetNodeParamLimit>>,
+			c: Option<BoundedVec<u8, DefaultSubnetNodeParamLimit>>,
+	) -> DispatchResult {
+			let account_id: T::AccountId = ensure_signed(origin)?;
+	
+			Self::do_update_subnet_node_params(
+				account_id,
+				subnet_id,
+				b,
+				c,
+			)
+		}
+
+		/// Reactivate your deactivated subnet node
+		/// The subnet node returns to its class before deactivation after one epoch in consensus without penalties
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::reactivate_subnet_node())]
+		pub fn reactivate_subnet_node(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+	) -> DispatchResult {
+			let account_id: T::AccountId = ensure_signed(origin)?;
+	
+			Self::do_reactivate_subnet_node(
+				account_id,
+				subnet_id,
+			)
+		}
+
+		/// Set the peer IDs allowed to register subnet nodes while the subnet is registering
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::owner_set_registration_peer_whitelist(whitelist.len() as u32))]
+		pub fn owner_set_registration_peer_whitelist(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+			whitelist: BoundedBTreeSet<PeerId, T::MaxSubnetNodes>,
+	) -> DispatchResult {
+			let account_id: T::AccountId = ensure_signed(origin)?;
+	
+			Self::do_set_subnet_registration_peer_whitelist(
+				account_id,
+				subnet_id,
+				whitelist.into_inner(),
+			)
+		}
+
+		/// Register a subnet with the accounts and peer IDs allowed to register subnet nodes until it's activated
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::register_subnet_with_whitelist(whitelist.len() as u32, peer_whitelist.len() as u32))]
+		pub fn register_subnet_with_whitelist(
+			origin: OriginFor<T>, 
+			subnet_data: RegistrationSubnetData<T::MaxPathLength>,
+			whitelist: BoundedBTreeSet<T::AccountId, T::MaxSubnetNodes>,
+			peer_whitelist: BoundedBTreeSet<PeerId, T::MaxSubnetNodes>,
+		) -> DispatchResult {
+			let account_id: T::AccountId = ensure_signed(origin)?;
+	
+			Self::do_register_subnet_with_whitelist(
+				account_id,
+				subnet_data,
+				whitelist.into_inner(),
+				peer_whitelist.into_inner(),
+			)
+		}
+
+		/// Pause a subnet in an emergency, reserving the ``SubnetPauseBond`` from the owner
+		// While paused no validator is chosen, no emissions are paid, subnet node classes and penalties are frozen
+		// and delegate stakers can exit without the cooldown
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::owner_pause_subnet())]
+		pub fn owner_pause_subnet(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+		) -> DispatchResult {
+			let account_id: T::AccountId = ensure_signed(origin)?;
+	
+			Self::do_owner_pause_subnet(account_id, subnet_id)
+		}
+
+		/// Unpause a subnet paused by its owner at least ``MinSubnetPauseEpochs`` ago and return the pause bond
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::owner_unpause_subnet())]
+		pub fn owner_unpause_subnet(
+			origin: OriginFor<T>, 
+			subnet_id: u32,
+		) -> DispatchResult {
+			let account_id: T::AccountId = ensure_signed(origin)?;
+	
+			Self::do_owner_unpause_subnet(account_id, subnet_id)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
-		/// Activate subnet - called by subnet democracy logic
+		/// Register a subnet at the current initialization cost
 		pub fn do_register_subnet(
 			activator: T::AccountId,
-			subnet_data: RegistrationSubnetData,
+			subnet_data: RegistrationSubnetData<T::MaxPathLength>,
 		) -> DispatchResult {
-			// Ensure path is unique
-			ensure!(
-				!SubnetPaths::<T>::contains_key(subnet_data.clone().path),
-				Error::<T>::SubnetExist
-			);
-
-			// --- Ensure total network memory isn't exceeded
-			ensure!(
-				TotalSubnetMemoryMB::<T>::get() + subnet_data.memory_mb <= MaxTotalSubnetMemoryMB::<T>::get(),
-				Error::<T>::MaxTotalSubnetMemory
-			);
-
-			// Ensure max subnets not reached
-			// Get total live subnets
-			let total_subnets: u32 = (SubnetsData::<T>::iter().count()).try_into().unwrap();
-			let max_subnets: u32 = MaxSubnets::<T>::get();
-			ensure!(
-				total_subnets < max_subnets,
-				Error::<T>::MaxSubnets
-			);
+			let block: u64 = Self::get_current_block_as_u64();
+			let subnet_cost: u128 = Self::get_subnet_initialization_cost(block, subnet_data.memory_mb);
 
-			// --- Ensure registration time period is allowed
-			ensure!(
-				subnet_data.registration_blocks >= MinSubnetRegistrationBlocks::<T>::get() && 
-				subnet_data.registration_blocks <= MaxSubnetRegistrationBlocks::<T>::get(),
-				Error::<T>::InvalidSubnetRegistrationBlocks
-			);
+			Self::do_register_subnet_at_cost(activator, subnet_data, subnet_cost)
+		}
 
-			// --- Ensure memory under max
-			ensure!(
-				subnet_data.memory_mb <= MaxSubnetMemoryMB::<T>::get(),
-				Error::<T>::MaxSubnetMemory
-			);
+		/// Register a subnet at an initialization cost quoted beforehand
+		// Subnet democracy reserves the cost when the activation is proposed, so it registers at that cost
+		pub fn do_register_subnet_at_cost(
+			activator: T::AccountId,
+			subnet_data: RegistrationSubnetData<T::MaxPathLength>,
+			subnet_cost: u128,
+		) -> DispatchResult {
+			Self::ensure_subnet_registrable(&subnet_data)?;
 
 			let block: u64 = Self::get_current_block_as_u64();
-			let subnet_cost: u128 = Self::get_subnet_initialization_cost(block);
 
 			if subnet_cost > 0 {
 				// unreserve from activator
@@ -2375,14 +3301,19 @@ pub mod pallet {
 					Error::<T>::BalanceWithdrawalError
 				);
 
-				// TODO
-				// Send portion to stake rewards vault
-				// Send portion to treasury
+				// --- Send portion to treasury
+				let treasury_share: u128 = Self::deposit_treasury_share(
+					subnet_cost,
+					TreasurySplits::<T>::get().registration_fee
+				);
 
-				// increase stake balance with subnet initialization cost
-				StakeVaultBalance::<T>::mutate(|n: &mut u128| *n += subnet_cost);
+				// increase stake balance with the remaining subnet initialization cost
+				StakeVaultBalance::<T>::mutate(|n: &mut u128| *n += subnet_cost - treasury_share);
 			}
 
+			// --- Raise the cost of the next registration
+			Self::increase_subnet_registration_cost(block);
+
 			// Get total subnets ever
 			let subnet_len: u32 = TotalSubnets::<T>::get();
 			// Start the subnet_ids at 1
@@ -2402,12 +3333,17 @@ pub mod pallet {
 				registration_blocks: subnet_data.clone().registration_blocks,
 				initialized: block,
 				activated: 0,
+				paused: 0,
 			};
 
+			// --- Reserve the refundable owner deposit
+			Self::reserve_subnet_owner_deposit(&activator, subnet_id)?;
+			SubnetOwner::<T>::insert(subnet_id, activator.clone());
+
 			// Increase total subnet memory
 			TotalSubnetMemoryMB::<T>::mutate(|n: &mut u128| *n += subnet_data.memory_mb);
 			// Store unique path
-			SubnetPaths::<T>::insert(subnet_data.clone().path, subnet_id);
+			SubnetPaths::<T>::insert(subnet_data.path.to_vec(), subnet_id);
 			// Store subnet data
 			SubnetsData::<T>::insert(subnet_id, subnet_data.clone());
 			// Increase total subnets. This is used for unique Subnet IDs
@@ -2415,13 +3351,185 @@ pub mod pallet {
 
 			Self::deposit_event(Event::SubnetRegistered { 
 				account_id: activator, 
-				path: subnet_data.path, 
+				path: subnet_data.path.into(), 
 				subnet_id: subnet_id 
 			});
 
 			Ok(())
 		}
 
+		/// Ensure a subnet can be registered with the given data
+		/// This doesn't account for the initialization cost
+		pub fn ensure_subnet_registrable(subnet_data: &RegistrationSubnetData<T::MaxPathLength>) -> DispatchResult {
+			// Ensure path is set, it's used as a unique key
+			ensure!(
+				!subnet_data.path.is_empty(),
+				Error::<T>::InvalidSubnetPath
+			);
+
+			// Ensure path is unique
+			ensure!(
+				!SubnetPaths::<T>::contains_key(subnet_data.path.to_vec()),
+				Error::<T>::SubnetExist
+			);
+
+			// --- Ensure total network memory isn't exceeded
+			ensure!(
+				TotalSubnetMemoryMB::<T>::get() + subnet_data.memory_mb <= MaxTotalSubnetMemoryMB::<T>::get(),
+				Error::<T>::MaxTotalSubnetMemory
+			);
+
+			// Ensure max subnets not reached
+			// Get total live subnets
+			let total_subnets: u32 = (SubnetsData::<T>::iter().count()).try_into().unwrap();
+			let max_subnets: u32 = MaxSubnets::<T>::get();
+			ensure!(
+				total_subnets < max_subnets,
+				Error::<T>::MaxSubnets
+			);
+
+			// --- Ensure registration time period is allowed
+			ensure!(
+				subnet_data.registration_blocks >= MinSubnetRegistrationBlocks::<T>::get() && 
+				subnet_data.registration_blocks <= MaxSubnetRegistrationBlocks::<T>::get(),
+				Error::<T>::InvalidSubnetRegistrationBlocks
+			);
+
+			// --- Ensure memory under max
+			ensure!(
+				subnet_data.memory_mb <= MaxSubnetMemoryMB::<T>::get(),
+				Error::<T>::MaxSubnetMemory
+			);
+
+			Ok(())
+		}
+
+		/// Get the subnet data with the update applied
+		/// Ensures the updated subnet is within the network memory and subnet node bounds
+		pub fn ensure_subnet_updatable(subnet_id: u32, update: &SubnetUpdateData) -> Result<SubnetData<T::MaxPathLength>, DispatchError> {
+			let subnet = match SubnetsData::<T>::try_get(subnet_id) {
+				Ok(subnet) => subnet,
+				Err(()) => return Err(Error::<T>::SubnetNotExist.into()),
+			};
+
+			ensure!(
+				*update != SubnetUpdateData::default(),
+				Error::<T>::SubnetUpdateEmpty
+			);
+
+			let memory_mb: u128 = update.memory_mb.unwrap_or(subnet.memory_mb);
+
+			// --- Ensure memory under max
+			ensure!(
+				memory_mb <= MaxSubnetMemoryMB::<T>::get(),
+				Error::<T>::MaxSubnetMemory
+			);
+
+			// --- Ensure total network memory isn't exceeded, excluding the subnets current memory
+			ensure!(
+				TotalSubnetMemoryMB::<T>::get().saturating_sub(subnet.memory_mb).saturating_add(memory_mb) <= MaxTotalSubnetMemoryMB::<T>::get(),
+				Error::<T>::MaxTotalSubnetMemory
+			);
+
+			// --- Registration period can only be updated while registering
+			let registration_blocks: u64 = match update.registration_blocks {
+				Some(registration_blocks) => {
+					ensure!(
+						subnet.activated == 0,
+						Error::<T>::SubnetActivatedAlready
+					);
+					ensure!(
+						registration_blocks >= MinSubnetRegistrationBlocks::<T>::get() && 
+						registration_blocks <= MaxSubnetRegistrationBlocks::<T>::get(),
+						Error::<T>::InvalidSubnetRegistrationBlocks
+					);
+					registration_blocks
+				},
+				None => subnet.registration_blocks,
+			};
+
+			// --- Derive node requirements from memory if it changes and isn't overridden
+			let (mut min_nodes, mut target_nodes) = (subnet.min_nodes, subnet.target_nodes);
+			if update.memory_mb.is_some() {
+				min_nodes = Self::get_min_subnet_nodes(BaseSubnetNodeMemoryMB::<T>::get(), memory_mb);
+				target_nodes = Self::get_target_subnet_nodes(min_nodes);
+			}
+			let min_nodes: u32 = update.min_nodes.unwrap_or(min_nodes);
+			let target_nodes: u32 = update.target_nodes.unwrap_or(target_nodes);
+
+			ensure!(
+				min_nodes >= MinSubnetNodes::<T>::get(),
+				Error::<T>::InvalidMinSubnetNodes
+			);
+
+			ensure!(
+				target_nodes >= min_nodes && target_nodes <= MaxSubnetNodes::<T>::get(),
+				Error::<T>::InvalidMaxSubnetNodes
+			);
+
+			Ok(SubnetData {
+				id: subnet.id,
+				path: subnet.path,
+				min_nodes: min_nodes,
+				target_nodes: target_nodes,
+				memory_mb: memory_mb,
+				initialized: subnet.initialized,
+				registration_blocks: registration_blocks,
+				activated: subnet.activated,
+				paused: subnet.paused,
+			})
+		}
+
+		/// Update a subnets parameters and the total network memory
+		pub fn do_update_subnet(subnet_id: u32, update: SubnetUpdateData) -> DispatchResult {
+			let prev_memory_mb: u128 = match SubnetsData::<T>::try_get(subnet_id) {
+				Ok(subnet) => subnet.memory_mb,
+				Err(()) => return Err(Error::<T>::SubnetNotExist.into()),
+			};
+
+			let subnet_data: SubnetData<T::MaxPathLength> = Self::ensure_subnet_updatable(subnet_id, &update)?;
+
+			TotalSubnetMemoryMB::<T>::mutate(|n: &mut u128| {
+				*n = n.saturating_sub(prev_memory_mb).saturating_add(subnet_data.memory_mb)
+			});
+
+			SubnetsData::<T>::insert(subnet_id, subnet_data.clone());
+
+			Self::deposit_event(Event::SubnetUpdated { 
+				subnet_id: subnet_id, 
+				subnet_data: subnet_data 
+			});
+
+			Ok(())
+		}
+
+		/// Set the subnet metadata, increasing its revision
+		pub fn do_set_subnet_metadata(subnet_id: u32, metadata: SubnetMetadata) -> DispatchResult {
+			ensure!(
+				SubnetsData::<T>::contains_key(subnet_id),
+				Error::<T>::SubnetNotExist
+			);
+
+			ensure!(
+				!metadata.name.is_empty(),
+				Error::<T>::InvalidSubnetMetadata
+			);
+
+			SubnetsMetadata::<T>::insert(subnet_id, metadata.clone());
+			let revision: u32 = SubnetMetadataRevision::<T>::mutate(subnet_id, |n: &mut u32| {
+				n.saturating_inc();
+				*n
+			});
+
+			Self::deposit_event(Event::SubnetMetadataUpdated { 
+				subnet_id: subnet_id, 
+				revision: revision,
+				metadata: metadata 
+			});
+
+			Ok(())
+		}
+
 		/// Activate subnet or remove registering subnet if doesn't meet requirements
 		pub fn do_activate_subnet(subnet_id: u32) -> DispatchResult {
 			let subnet = match SubnetsData::<T>::try_get(subnet_id) {
@@ -2446,7 +3554,7 @@ pub mod pallet {
 			// --- If subnet not activated yet and is outside the enactment period, remove subnet
 			if block > subnet.initialized + subnet.registration_blocks + SubnetActivationEnactmentPeriod::<T>::get() {
 				return Self::deactivate_subnet(
-					subnet.path,
+					subnet.path.into(),
 					SubnetRemovalReason::EnactmentPeriod,
 				)
 			}
@@ -2459,7 +3567,7 @@ pub mod pallet {
 
 			if subnet_nodes_count < subnet.min_nodes {
 				return Self::deactivate_subnet(
-					subnet.path,
+					subnet.path.into(),
 					SubnetRemovalReason::MinSubnetNodes,
 				)
 			}
@@ -2471,7 +3579,7 @@ pub mod pallet {
 			// --- Ensure delegate stake balance is below minimum threshold required
 			if subnet_delegate_stake_balance < min_subnet_delegate_stake_balance {
 				return Self::deactivate_subnet(
-					subnet.path,
+					subnet.path.into(),
 					SubnetRemovalReason::MinSubnetDelegateStake,
 				)
 			}
@@ -2488,6 +3596,11 @@ pub mod pallet {
 				}
 			)?;
 
+			// --- Open subnet node registration unless governance approved a permissioned subnet
+			if !SubnetPermissioned::<T>::get(subnet_id) {
+				Self::lift_subnet_registration_whitelist(subnet_id);
+			}
+
       Self::deposit_event(Event::SubnetActivated { subnet_id: subnet_id });
 	
 			Ok(())
@@ -2513,27 +3626,26 @@ pub mod pallet {
 			SubnetPaths::<T>::remove(path.clone());
 			// Remove subnet data
 			SubnetsData::<T>::remove(subnet_id);
+			// Removed subnets are no longer rewarded, an emission stored for the epoch being rewarded stays unpaid
+			EpochSubnetEmissions::<T>::remove(subnet_id);
 			// Decrease total subnet memory
 			TotalSubnetMemoryMB::<T>::mutate(|n: &mut u128| n.saturating_reduce(subnet.memory_mb));
 
 			// We don't subtract TotalSubnets since it's used for ids
 
-			// Remove all peers data
-			let _ = SubnetNodesData::<T>::clear_prefix(subnet_id, u32::MAX, None);
-			let _ = TotalSubnetNodes::<T>::remove(subnet_id);
-			let _ = SubnetNodeAccount::<T>::clear_prefix(subnet_id, u32::MAX, None);
-
-			// Remove all subnet consensus data
-			let _ = SubnetPenaltyCount::<T>::remove(subnet_id);
-
-			// Remove consensus data
-			let _ = SubnetRewardsSubmission::<T>::clear_prefix(subnet_id, u32::MAX, None);
-
-			// Remove accounting data
-			let _ = AccountantData::<T>::clear_prefix(subnet_id, u32::MAX, None);
-
-			// Remove proposals
-			let _ = Proposals::<T>::clear_prefix(subnet_id, u32::MAX, None);
+			// --- Return the owners registration deposit
+			Self::refund_subnet_owner_deposit(subnet_id);
+			SubnetOwner::<T>::remove(subnet_id);
+			SubnetRegistrationWhitelist::<T>::remove(subnet_id);
+			SubnetRegistrationPeerWhitelist::<T>::remove(subnet_id);
+			SubnetPermissioned::<T>::remove(subnet_id);
+			Self::refund_subnet_pause_bond(subnet_id);
+			SubnetsMetadata::<T>::remove(subnet_id);
+			SubnetMetadataRevision::<T>::remove(subnet_id);
+
+			// --- Stake and the remaining subnet storage are released over multiple blocks
+			//     by ``on_idle`` and ``clear_subnet``
+			SubnetTeardowns::<T>::insert(subnet_id, SubnetTeardownStep::NodeStake);
 	
 			Self::deposit_event(Event::SubnetDeactivated { subnet_id: subnet_id, reason: reason });
 
@@ -2555,6 +3667,48 @@ pub mod pallet {
 			Ok(())
 		}
 
+		pub fn do_update_subnet_node_params(
+			account_id: T::AccountId,
+			subnet_id: u32,
+			b: Option<BoundedVec<u8, DefaultSubnetNodeParamLimit>>,
+			c: Option<BoundedVec<u8, DefaultSubnetNodeParamLimit>>,
+		) -> DispatchResult {
+			let mut subnet_node = match SubnetNodesData::<T>::try_get(subnet_id, account_id.clone()) {
+				Ok(subnet_node) => subnet_node,
+				Err(()) => return Err(Error::<T>::SubnetNodeNotExist.into()),
+			};
+
+			let block: u64 = Self::get_current_block_as_u64();
+			if let Some(last_update) = SubnetNodeParamsLastUpdate::<T>::get(subnet_id, account_id.clone()) {
+				ensure!(
+					block >= last_update.saturating_add(SubnetNodeParamsUpdateInterval::<T>::get()),
+					Error::<T>::SubnetNodeParamsRateLimitExceeded
+				);
+			}
+
+			if let Some(b) = b {
+				subnet_node.b = b.into_inner();
+			}
+
+			if let Some(c) = c {
+				subnet_node.c = c.into_inner();
+			}
+
+			SubnetNodesData::<T>::insert(subnet_id, account_id.clone(), subnet_node.clone());
+			SubnetNodeParamsLastUpdate::<T>::insert(subnet_id, account_id.clone(), block);
+
+			Self::deposit_event(
+				Event::SubnetNodeParamsUpdated { 
+					subnet_id: subnet_id, 
+					account_id: account_id, 
+					b: subnet_node.b,
+					c: subnet_node.c,
+				}
+			);
+
+			Ok(())
+		}
+
 		pub fn do_register_subnet_node(
 			origin: OriginFor<T>, 
 			subnet_id: u32, 
@@ -2589,6 +3743,25 @@ pub mod pallet {
 				Error::<T>::SubnetMustBeRegisteringOrActivated
 			);
 
+			// --- Ensure the account isn't banned or past the max penalties
+			if let Some(banned_until_epoch) = AccountBans::<T>::get(account_id.clone()) {
+				ensure!(
+					block / T::EpochLength::get() >= banned_until_epoch,
+					Error::<T>::AccountBanned
+				);
+			}
+
+			ensure!(
+				Self::is_account_eligible(account_id.clone()),
+				Error::<T>::AccountIneligible
+			);
+
+			// --- Ensure the account is whitelisted by the subnet owner while registering
+			ensure!(
+				Self::is_subnet_registration_whitelisted(&subnet, &account_id, &peer_id),
+				Error::<T>::NotSubnetRegistrationWhitelisted
+			);
+
 			// Ensure max peers isn't surpassed
 			let total_subnet_nodes: u32 = TotalSubnetNodes::<T>::get(subnet_id);
 			let max_subnet_nodes: u32 = MaxSubnetNodes::<T>::get();
@@ -2605,10 +3778,9 @@ pub mod pallet {
 			);
 
 			// Unique ``a``
-			// [here]
-			if a.is_some() {
+			if let Some(a) = &a {
 				ensure!(
-					!SubnetNodeParam::<T>::contains_key(subnet_id, a.unwrap()),
+					!SubnetNodeParam::<T>::contains_key(subnet_id, a.to_vec()),
 					Error::<T>::SubnetNodeExist
 				);	
 			}
@@ -2663,10 +3835,16 @@ pub mod pallet {
 				peer_id: peer_id.clone(),
 				initialized: 0,
 				classification: classification,
-				a: Vec::new(),
-				b: Vec::new(),
-				c: Vec::new(),
+				a: a.map_or(Vec::new(), |a| a.into_inner()),
+				b: b.map_or(Vec::new(), |b| b.into_inner()),
+				c: c.map_or(Vec::new(), |c| c.into_inner()),
 			};
+
+			// Index ``a`` to keep it unique within the subnet
+			if !subnet_node.a.is_empty() {
+				SubnetNodeParam::<T>::insert(subnet_id, subnet_node.a.clone(), peer_id.clone());
+			}
+
 			// Insert SubnetNodesData with account_id as key
 			SubnetNodesData::<T>::insert(subnet_id, account_id.clone(), subnet_node);
 
@@ -2775,29 +3953,45 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// This should be called by a user facing extrinsic
 		pub fn do_deactivate_subnet_node(
-			origin: OriginFor<T>, 
+			account_id: T::AccountId,
 			subnet_id: u32, 
 		) -> DispatchResult {
-			let account_id: T::AccountId = ensure_signed(origin)?;
-
 			let epoch_length: u64 = T::EpochLength::get();
 			let block: u64 = Self::get_current_block_as_u64();
 			let epoch: u64 = block / epoch_length;
 
+			ensure!(
+				SubnetsData::<T>::contains_key(subnet_id),
+				Error::<T>::SubnetNotExist
+			);
+
+			// --- The validator must see the epoch through
+			ensure!(
+				SubnetRewardsValidator::<T>::get(subnet_id, epoch as u32).as_ref() != Some(&account_id),
+				Error::<T>::SubnetNodeIsValidator
+			);
+
 			SubnetNodesData::<T>::try_mutate_exists(
 				subnet_id,
 				account_id.clone(),
 				|maybe_params| -> DispatchResult {
-					let params = maybe_params.as_mut().ok_or(Error::<T>::SubnetNodeExist)?;	
+					let params = maybe_params.as_mut().ok_or(Error::<T>::SubnetNodeNotExist)?;	
 					ensure!(
 						params.initialized != 0,
             Error::<T>::SubnetNodeNotActivated
 					);
-					params.initialized = 0;
+					ensure!(
+						params.classification.class != SubnetNodeClass::Deactivated,
+            Error::<T>::SubnetNodeAlreadyDeactivated
+					);
+
+					// --- Keep the subnet node as is to return it to its class on reactivation
+					DeactivateSubnetNodeLedger::<T>::insert(subnet_id, account_id.clone(), params.clone());
+
+					// --- The deactivation epoch is kept as the start epoch
 					params.classification = SubnetNodeClassification {
-						class: SubnetNodeClass::Registered,
+						class: SubnetNodeClass::Deactivated,
 						start_epoch: epoch,
 					};
 					Ok(())
@@ -2806,20 +4000,65 @@ pub mod pallet {
 
 			TotalActiveSubnetNodes::<T>::mutate(subnet_id, |n: &mut u32| n.saturating_dec());
 
-			// --- Add deactivation action to ledger
-			// This will deactivate the node on the following epoch
-			let mut pending_actions = PendingActionsStorage::<T>::get().unwrap_or_else(|| PendingActions {
-				actions: BTreeMap::new(),
-			});
+			Self::deposit_event(
+				Event::SubnetNodeDeactivated { 
+					subnet_id: subnet_id, 
+					account_id: account_id, 
+				}
+			);
 
-			// Add the new action
-			pending_actions.add_action(account_id.clone(), ActionType::Deactivate, 10);
+			Ok(())
+		}
 
-			// Store the updated actions
-			PendingActionsStorage::<T>::put(Some(pending_actions));
+		pub fn do_reactivate_subnet_node(
+			account_id: T::AccountId,
+			subnet_id: u32, 
+		) -> DispatchResult {
+			let epoch_length: u64 = T::EpochLength::get();
+			let block: u64 = Self::get_current_block_as_u64();
+			let epoch: u64 = block / epoch_length;
+
+			let max_deactivation_epochs: u64 = MaxSubnetNodeDeactivationEpochs::<T>::get();
+
+			SubnetNodesData::<T>::try_mutate_exists(
+				subnet_id,
+				account_id.clone(),
+				|maybe_params| -> DispatchResult {
+					let params = maybe_params.as_mut().ok_or(Error::<T>::SubnetNodeNotExist)?;	
+					ensure!(
+						params.classification.class == SubnetNodeClass::Deactivated,
+            Error::<T>::SubnetNodeNotDeactivated
+					);
+					ensure!(
+						epoch <= params.classification.start_epoch.saturating_add(max_deactivation_epochs),
+            Error::<T>::SubnetNodeDeactivationExpired
+					);
+
+					let previous_class: SubnetNodeClass = DeactivateSubnetNodeLedger::<T>::get(subnet_id, account_id.clone())
+						.classification
+						.class;
+
+					// --- Subnet nodes above ``Included`` must first be in consensus for one epoch as ``Included``,
+					//     their class is returned on rewards, see ``reward_subnet``
+					let mut class = SubnetNodeClass::Included;
+					if previous_class <= SubnetNodeClass::Included {
+						class = previous_class;
+						DeactivateSubnetNodeLedger::<T>::remove(subnet_id, account_id.clone());
+					}
+
+					// --- Start on a fresh epoch
+					params.classification = SubnetNodeClassification {
+						class: class,
+						start_epoch: epoch + 1,
+					};
+					Ok(())
+				}
+			)?;
+
+			TotalActiveSubnetNodes::<T>::mutate(subnet_id, |n: &mut u32| *n += 1);
 
 			Self::deposit_event(
-				Event::SubnetNodeDeactivated { 
+				Event::SubnetNodeReactivated { 
 					subnet_id: subnet_id, 
 					account_id: account_id, 
 				}
@@ -2878,61 +4117,59 @@ pub mod pallet {
 			//			- If subnet is under any of the following conditions they are removed:
 			//						- Passed max penalties
 			//						- Under minimum delegate stake threshold
+			//
+			// Both steps are processed ``EpochProcessingNodesPerBlock`` subnet nodes at a time starting
+			// on the first block of the epoch, see ``do_epoch_processing``
 			let block: u64 = Self::convert_block_as_u64(block_number);
 			let epoch_length: u64 = T::EpochLength::get();
 
+			let mut weight: Weight = T::WeightInfo::on_initialize();
+
 			// Reward validators and attestors... Shift node classes
 			if block >= epoch_length && block % epoch_length == 0 {
 				let epoch: u64 = block / epoch_length;
 
+				// --- Processing left from the previous epoch only happens if ``EpochProcessingNodesPerBlock``
+				//     is too low for the network, see ``skip_ended_epoch_processing``
+				weight = weight.saturating_add(Self::skip_ended_epoch_processing());
+
 				// Reward subnets for the previous epoch
 				// Reward before shifting
-				Self::reward_subnets(block, (epoch - 1) as u32);
-
-				// return T::WeightInfo::on_initialize_reward_subnets();
-				return Weight::from_parts(207_283_478_000, 22166406)
-					.saturating_add(T::DbWeight::get().reads(18250_u64))
-					.saturating_add(T::DbWeight::get().writes(12002_u64));
-
-			} else if (block - 1) >= epoch_length && (block - 1) % epoch_length == 0 {
-				// We save some weight by waiting one more block to choose validators
-				// Run the block succeeding form consensus
-				let epoch: u64 = block / epoch_length;
-
-				// Choose validators and accountants for the current epoch
-				Self::do_epoch_preliminaries(block, epoch as u32, epoch_length);
-
-				// return T::WeightInfo::on_initialize_do_choose_validator_and_accountants();
-				return Weight::from_parts(207_283_478_000, 22166406)
-					.saturating_add(T::DbWeight::get().reads(18250_u64))
-					.saturating_add(T::DbWeight::get().writes(12002_u64));
+				if !EpochProcessing::<T>::exists() {
+					weight = weight.saturating_add(Self::begin_epoch_processing((epoch - 1) as u32));
+				}
 			}
 
-			// return T::WeightInfo::on_initialize()
-			return Weight::from_parts(207_283_478_000, 22166406)
-				.saturating_add(T::DbWeight::get().reads(18250_u64))
-				.saturating_add(T::DbWeight::get().writes(12002_u64))
+			weight.saturating_add(Self::do_epoch_processing(block, EpochProcessingNodesPerBlock::<T>::get()))
 		}
 
-		fn on_idle(block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
-			let block: u64 = Self::convert_block_as_u64(block_number);
-
-			if remaining_weight.any_lt(T::DbWeight::get().reads(2)) {
-				return Weight::from_parts(0, 0)
-			}
-
-			return Weight::from_parts(0, 0)
-
-			// Self::do_on_idle(remaining_weight)
+		fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::do_on_idle(remaining_weight)
 		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		pub(crate) fn do_on_idle(remaining_weight: Weight) -> Weight {
-			// any weight that is unaccounted for
-			let mut unaccounted_weight = Weight::from_parts(0, 0);
+			// --- Continue the teardown of one deactivated subnet per block
+			let limit: u32 = SubnetTeardownItemsPerCall::<T>::get();
+			let weight: Weight = T::WeightInfo::clear_subnet(limit);
 
-			unaccounted_weight
+			if remaining_weight.any_lt(weight) {
+				return Weight::from_parts(0, 0)
+			}
+
+			match SubnetTeardowns::<T>::iter_keys().next() {
+				Some(subnet_id) => {
+					Self::do_clear_subnet(subnet_id, limit);
+					weight
+				},
+				// --- Otherwise clear the snapshots of voting power no longer used by subnet democracy
+				None if !VotingPowerSnapshotTeardowns::<T>::get().is_empty() => {
+					Self::clear_voting_power_snapshots(limit);
+					weight
+				},
+				None => T::DbWeight::get().reads(3),
+			}
 		}
 	}
 
@@ -2964,13 +4201,14 @@ pub mod pallet {
 
 			let subnet_data = SubnetData {
 				id: subnet_id,
-				path: self.subnet_path.clone(),
+				path: self.subnet_path.clone().try_into().expect("subnet path exceeds MaxPathLength"),
 				min_nodes: min_subnet_nodes,
 				target_nodes: target_subnet_nodes,
 				memory_mb: self.memory_mb.clone(),
 				registration_blocks: MinSubnetRegistrationBlocks::<T>::get(),
 				initialized: 1,
 				activated: 0,
+				paused: 0,
 			};
 
 			// Increase total subnet memory
@@ -3093,36 +4331,79 @@ pub trait IncreaseStakeVault {
 	fn increase_stake_vault(amount: u128) -> DispatchResult;
 }
 
-impl<T: Config> SubnetVote<OriginFor<T>, T::AccountId> for Pallet<T> {
-	fn vote_subnet_in(vote_subnet_data: SubnetDemocracySubnetData) -> DispatchResult {
-		Ok(())
+// Treasury share of the block subsidy from rewards pallet
+impl<T: Config> TreasuryInterface for Pallet<T> {
+	fn deposit_block_subsidy_share(subsidy: u128) -> u128 {
+		Self::deposit_treasury_share(subsidy, TreasurySplits::<T>::get().block_subsidy)
 	}
-	fn vote_subnet_out(vote_subnet_data: SubnetDemocracySubnetData) -> DispatchResult {
-		Ok(())
+}
+pub trait TreasuryInterface {
+	/// Deposit the treasury share of ``subsidy``, returns the amount deposited
+	fn deposit_block_subsidy_share(subsidy: u128) -> u128;
+}
+
+impl<T: Config> SubnetVote<OriginFor<T>, T::AccountId, T::MaxPathLength> for Pallet<T> {
+	fn vote_subnet_in(vote_subnet_data: SubnetDemocracySubnetData<T::MaxPathLength>) -> DispatchResult {
+		Self::ensure_subnet_registrable(&vote_subnet_data.data)
 	}
-	fn vote_activated(activator: T::AccountId, path: Vec<u8>, proposer: T::AccountId, vote_subnet_data: SubnetDemocracySubnetData) -> DispatchResult {
+	fn vote_subnet_out(vote_subnet_data: SubnetDemocracySubnetData<T::MaxPathLength>) -> DispatchResult {
 		Ok(())
 	}
-	fn vote_deactivated(deactivator: T::AccountId, path: Vec<u8>, proposer: T::AccountId, vote_subnet_data: SubnetDemocracySubnetData) -> DispatchResult {
+	fn vote_activated(activator: T::AccountId, path: Vec<u8>, proposer: T::AccountId, vote_subnet_data: SubnetDemocracySubnetData<T::MaxPathLength>, cost: u128) -> DispatchResult {
+		// --- The proposer pays the initialization fee quoted and reserved by the democracy pallet
+		Self::do_register_subnet_at_cost(
+			proposer,
+			vote_subnet_data.data,
+			cost,
+		)
+	}
+	fn vote_deactivated(deactivator: T::AccountId, path: Vec<u8>, proposer: T::AccountId, vote_subnet_data: SubnetDemocracySubnetData<T::MaxPathLength>, reason: SubnetRemovalReason) -> DispatchResult {
 		Self::deactivate_subnet(
-			vote_subnet_data.clone().data.path,
-			SubnetRemovalReason::SubnetDemocracy
+			vote_subnet_data.data.path.into(),
+			reason
 		)
 	}
+	fn vote_subnet_update(path: Vec<u8>, update: SubnetUpdateData) -> DispatchResult {
+		let subnet_id = match SubnetPaths::<T>::try_get(path) {
+			Ok(subnet_id) => subnet_id,
+			Err(()) => return Err(Error::<T>::SubnetNotExist.into()),
+		};
+		Self::ensure_subnet_updatable(subnet_id, &update).map(|_| ())
+	}
+	fn vote_updated(path: Vec<u8>, update: SubnetUpdateData) -> DispatchResult {
+		let subnet_id = match SubnetPaths::<T>::try_get(path) {
+			Ok(subnet_id) => subnet_id,
+			Err(()) => return Err(Error::<T>::SubnetNotExist.into()),
+		};
+		Self::do_update_subnet(subnet_id, update)
+	}
 	fn vote_add_subnet_node(
 		origin: OriginFor<T>, 
 		subnet_id: u32, 
 		peer_id: PeerId, 
 		stake_to_be_added: u128,
 	) -> DispatchResult {
-		Ok(())
+		Self::do_register_subnet_node(
+			origin.clone(),
+			subnet_id,
+			peer_id,
+			stake_to_be_added,
+			None,
+			None,
+			None,
+		).map_err(|e| e)?;
+
+		Self::do_activate_subnet_node(
+			origin,
+			subnet_id,
+		)
 	}
 	fn get_total_subnets() -> u32 {
 		TotalSubnets::<T>::get()
 	}
-	fn get_subnet_initialization_cost() -> u128 {
+	fn get_subnet_initialization_cost(memory_mb: u128) -> u128 {
 		let block: u64 = Self::get_current_block_as_u64();
-		Self::get_subnet_initialization_cost(block)
+		Self::get_subnet_initialization_cost(block, memory_mb)
 	}
 	fn get_subnet_path_exist(path: Vec<u8>) -> bool {
 		if SubnetPaths::<T>::contains_key(path) {
@@ -3146,7 +4427,7 @@ impl<T: Config> SubnetVote<OriginFor<T>, T::AccountId> for Pallet<T> {
 		}
 	}
 	// Should never be called unless contains_key is confirmed
-	fn get_subnet_data(id: u32) -> SubnetData {
+	fn get_subnet_data(id: u32) -> SubnetData<T::MaxPathLength> {
 		SubnetsData::<T>::get(id).unwrap()
 	}
 	// fn get_min_subnet_nodes() -> u32 {
@@ -3159,7 +4440,7 @@ impl<T: Config> SubnetVote<OriginFor<T>, T::AccountId> for Pallet<T> {
 		MinStakeBalance::<T>::get()
 	}
 	fn is_submittable_subnet_node_account(account_id: T::AccountId) -> bool {
-		true
+		Self::is_submittable_subnet_node_account(account_id)
 	}
 	fn is_subnet_initialized(id: u32) -> bool {
 		let subnet_data = SubnetsData::<T>::get(id).unwrap();
@@ -3185,29 +4466,30 @@ impl<T: Config> SubnetVote<OriginFor<T>, T::AccountId> for Pallet<T> {
 	fn get_target_subnet_nodes(min_subnet_nodes: u32) -> u32 {
 		Self::get_target_subnet_nodes(min_subnet_nodes)
 	}
-	fn get_stake_balance(account_id: T::AccountId) -> u128 {
-		Self::get_account_total_stake_balance(account_id)
+	fn get_stake_balance(account_id: T::AccountId, block: u64) -> u128 {
+		Self::get_account_total_stake_balance(account_id, block)
 	}
-	fn get_delegate_stake_balance(account_id: T::AccountId) -> u128 {
-		let mut total_delegate_stake_balance = 0;
-		for (subnet_id, _) in SubnetsData::<T>::iter() {
-			total_delegate_stake_balance += Self::convert_account_shares_to_balance(
-				&account_id,
-				subnet_id
-			);
-		}
-		total_delegate_stake_balance
+	fn get_delegate_stake_balance(account_id: T::AccountId, block: u64) -> u128 {
+		Self::get_account_delegate_stake_balance_at(&account_id, block)
+	}
+	fn get_voting_power(block: u64) -> u128 {
+		Self::get_total_voting_power(block)
 	}
-	fn get_voting_power() -> u128 {
-		Self::get_total_voting_power()
+	fn add_voting_power_snapshot(block: u64) {
+		Self::do_add_voting_power_snapshot(block)
+	}
+	fn remove_voting_power_snapshot(block: u64) {
+		Self::do_remove_voting_power_snapshot(block)
 	}
 }
 
-pub trait SubnetVote<OriginFor, AccountId> {
-	fn vote_subnet_in(vote_subnet_data: SubnetDemocracySubnetData) -> DispatchResult;
-	fn vote_subnet_out(vote_subnet_data: SubnetDemocracySubnetData) -> DispatchResult;
-	fn vote_activated(activator: AccountId, path: Vec<u8>, proposer: AccountId, vote_subnet_data: SubnetDemocracySubnetData) -> DispatchResult;
-	fn vote_deactivated(deactivator: AccountId, path: Vec<u8>, proposer: AccountId, vote_subnet_data: SubnetDemocracySubnetData) -> DispatchResult;
+pub trait SubnetVote<OriginFor, AccountId, MaxPathLength: Get<u32>> {
+	fn vote_subnet_in(vote_subnet_data: SubnetDemocracySubnetData<MaxPathLength>) -> DispatchResult;
+	fn vote_subnet_out(vote_subnet_data: SubnetDemocracySubnetData<MaxPathLength>) -> DispatchResult;
+	fn vote_activated(activator: AccountId, path: Vec<u8>, proposer: AccountId, vote_subnet_data: SubnetDemocracySubnetData<MaxPathLength>, cost: u128) -> DispatchResult;
+	fn vote_deactivated(deactivator: AccountId, path: Vec<u8>, proposer: AccountId, vote_subnet_data: SubnetDemocracySubnetData<MaxPathLength>, reason: SubnetRemovalReason) -> DispatchResult;
+	fn vote_subnet_update(path: Vec<u8>, update: SubnetUpdateData) -> DispatchResult;
+	fn vote_updated(path: Vec<u8>, update: SubnetUpdateData) -> DispatchResult;
 	fn vote_add_subnet_node(
 		origin: OriginFor, 
 		subnet_id: u32, 
@@ -3215,11 +4497,11 @@ pub trait SubnetVote<OriginFor, AccountId> {
 		stake_to_be_added: u128,
 	) -> DispatchResult;
 	fn get_total_subnets() -> u32;
-	fn get_subnet_initialization_cost() -> u128;
+	fn get_subnet_initialization_cost(memory_mb: u128) -> u128;
 	fn get_subnet_path_exist(path: Vec<u8>) -> bool;
 	fn get_subnet_id_by_path(path: Vec<u8>) -> u32;
 	fn get_subnet_id_exist(id: u32) -> bool;
-	fn get_subnet_data(id: u32) -> SubnetData;
+	fn get_subnet_data(id: u32) -> SubnetData<MaxPathLength>;
 	fn get_max_subnet_nodes() -> u32;
 	fn get_min_stake_balance() -> u128;
 	fn is_submittable_subnet_node_account(account_id: AccountId) -> bool;
@@ -3227,9 +4509,11 @@ pub trait SubnetVote<OriginFor, AccountId> {
 	fn get_total_subnet_errors(id: u32) -> u32;
 	fn get_min_subnet_nodes(memory_mb: u128) -> u32;
 	fn get_target_subnet_nodes(min_subnet_nodes: u32) -> u32;
-	fn get_stake_balance(account_id: AccountId) -> u128;
-	fn get_delegate_stake_balance(account_id: AccountId) -> u128;
-	fn get_voting_power() -> u128;
+	fn get_stake_balance(account_id: AccountId, block: u64) -> u128;
+	fn get_delegate_stake_balance(account_id: AccountId, block: u64) -> u128;
+	fn get_voting_power(block: u64) -> u128;
+	fn add_voting_power_snapshot(block: u64);
+	fn remove_voting_power_snapshot(block: u64);
 }
 
 // impl<T: Config> Tester<OriginFor<T>, T::AccountId> for Pallet<T> {
@@ -3327,6 +4611,78 @@ impl<T: Config> AdminInterface<T::AccountId> for Pallet<T> {
 	fn set_min_nodes_slope_parameters(params: MinNodesCurveParametersSet) -> DispatchResult {
 		Self::set_min_nodes_slope_parameters(params)
 	}
+	fn set_subnet_proposal_reasons(subnet_id: u32, reasons: BTreeSet<ProposalReason>) -> DispatchResult {
+		Self::set_subnet_proposal_reasons(subnet_id, reasons)
+	}
+	fn set_subnet_proposal_reason_attests(subnet_id: u32, reason: ProposalReason, value: u32) -> DispatchResult {
+		Self::set_subnet_proposal_reason_attests(subnet_id, reason, value)
+	}
+	fn council_resolve_proposal_appeal(subnet_id: u32, proposal_id: u32, uphold: bool) -> DispatchResult {
+		Self::do_resolve_proposal_appeal(subnet_id, proposal_id, uphold)
+	}
+	fn set_unpaid_emissions_policy(value: UnpaidEmissionsPolicy) -> DispatchResult {
+		Self::set_unpaid_emissions_policy(value)
+	}
+	fn set_rewards_receipt_retention_epochs(value: u32) -> DispatchResult {
+		Self::set_rewards_receipt_retention_epochs(value)
+	}
+	fn set_epoch_processing_nodes_per_block(value: u32) -> DispatchResult {
+		Self::set_epoch_processing_nodes_per_block(value)
+	}
+	fn set_treasury_split(value: TreasurySplit) -> DispatchResult {
+		Self::set_treasury_split(value)
+	}
+	fn set_min_subnet_registration_cost(value: u128) -> DispatchResult {
+		Self::set_min_subnet_registration_cost(value)
+	}
+	fn set_subnet_registration_cost_multiplier(value: u128) -> DispatchResult {
+		Self::set_subnet_registration_cost_multiplier(value)
+	}
+	fn set_subnet_registration_cost_decay_blocks(value: u64) -> DispatchResult {
+		Self::set_subnet_registration_cost_decay_blocks(value)
+	}
+	fn set_subnet_teardown_items_per_call(value: u32) -> DispatchResult {
+		Self::set_subnet_teardown_items_per_call(value)
+	}
+	fn set_subnet_owner_deposit(value: u128) -> DispatchResult {
+		Self::set_subnet_owner_deposit(value)
+	}
+	fn set_subnet_node_params_update_interval(value: u64) -> DispatchResult {
+		Self::set_subnet_node_params_update_interval(value)
+	}
+	fn set_max_subnet_node_deactivation_epochs(value: u64) -> DispatchResult {
+		Self::set_max_subnet_node_deactivation_epochs(value)
+	}
+	fn set_account_penalty_ban_epochs(value: u64) -> DispatchResult {
+		Self::set_account_penalty_ban_epochs(value)
+	}
+	fn council_treasury_spend(beneficiary: T::AccountId, amount: u128) -> DispatchResult {
+		Self::do_treasury_spend(beneficiary, amount)
+	}
+	fn council_set_subnet_metadata(subnet_id: u32, metadata: SubnetMetadata) -> DispatchResult {
+		Self::do_set_subnet_metadata(subnet_id, metadata)
+	}
+	fn council_set_subnet_permissioned(subnet_id: u32, permissioned: bool) -> DispatchResult {
+		Self::do_set_subnet_permissioned(subnet_id, permissioned)
+	}
+	fn set_subnet_pause_bond(value: u128) -> DispatchResult {
+		Self::set_subnet_pause_bond(value)
+	}
+	fn set_max_subnet_pause_epochs(value: u64) -> DispatchResult {
+		Self::set_max_subnet_pause_epochs(value)
+	}
+	fn set_min_subnet_pause_epochs(value: u64) -> DispatchResult {
+		Self::set_min_subnet_pause_epochs(value)
+	}
+	fn council_set_subnet_paused(subnet_id: u32, paused: bool) -> DispatchResult {
+		Self::do_council_set_subnet_paused(subnet_id, paused)
+	}
+	fn set_appeal_period(value: u64) -> DispatchResult {
+		Self::set_appeal_period(value)
+	}
+	fn set_appeal_bond_amount(value: u128) -> DispatchResult {
+		Self::set_appeal_bond_amount(value)
+	}
 }
 
 pub trait AdminInterface<AccountId> {
@@ -3354,4 +4710,28 @@ pub trait AdminInterface<AccountId> {
 	fn council_remove_subnet(path: Vec<u8>) -> DispatchResult;
 	fn council_remove_subnet_node(account_id: AccountId, subnet_id: u32) -> DispatchResult;
 	fn set_min_nodes_slope_parameters(params: MinNodesCurveParametersSet) -> DispatchResult;
+	fn set_subnet_proposal_reasons(subnet_id: u32, reasons: BTreeSet<ProposalReason>) -> DispatchResult;
+	fn set_subnet_proposal_reason_attests(subnet_id: u32, reason: ProposalReason, value: u32) -> DispatchResult;
+	fn council_resolve_proposal_appeal(subnet_id: u32, proposal_id: u32, uphold: bool) -> DispatchResult;
+	fn set_unpaid_emissions_policy(value: UnpaidEmissionsPolicy) -> DispatchResult;
+	fn set_rewards_receipt_retention_epochs(value: u32) -> DispatchResult;
+	fn set_epoch_processing_nodes_per_block(value: u32) -> DispatchResult;
+	fn set_treasury_split(value: TreasurySplit) -> DispatchResult;
+	fn set_min_subnet_registration_cost(value: u128) -> DispatchResult;
+	fn set_subnet_registration_cost_multiplier(value: u128) -> DispatchResult;
+	fn set_subnet_registration_cost_decay_blocks(value: u64) -> DispatchResult;
+	fn set_subnet_teardown_items_per_call(value: u32) -> DispatchResult;
+	fn set_subnet_owner_deposit(value: u128) -> DispatchResult;
+	fn set_subnet_node_params_update_interval(value: u64) -> DispatchResult;
+	fn set_max_subnet_node_deactivation_epochs(value: u64) -> DispatchResult;
+	fn set_account_penalty_ban_epochs(value: u64) -> DispatchResult;
+	fn council_treasury_spend(beneficiary: AccountId, amount: u128) -> DispatchResult;
+	fn council_set_subnet_metadata(subnet_id: u32, metadata: SubnetMetadata) -> DispatchResult;
+	fn council_set_subnet_permissioned(subnet_id: u32, permissioned: bool) -> DispatchResult;
+	fn set_subnet_pause_bond(value: u128) -> DispatchResult;
+	fn set_max_subnet_pause_epochs(value: u64) -> DispatchResult;
+	fn set_min_subnet_pause_epochs(value: u64) -> DispatchResult;
+	fn council_set_subnet_paused(subnet_id: u32, paused: bool) -> DispatchResult;
+	fn set_appeal_period(value: u64) -> DispatchResult;
+	fn set_appeal_bond_amount(value: u128) -> DispatchResult;
 }
\ No newline at end of file
@@ -0,0 +1,320 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{traits::UncheckedOnRuntimeUpgrade, weights::Weight};
+
+/// Indexes existing stakers by subnet into ``SubnetStakeAccounts`` and ``SubnetDelegateStakeAccounts``
+pub mod v1 {
+  use super::*;
+
+  /// Inserts every account with a balance in ``AccountSubnetStake`` or ``AccountSubnetDelegateStakeShares`` into
+  /// the subnet keyed index used by the subnet teardown
+  pub struct UncheckedMigrateToV1<T>(core::marker::PhantomData<T>);
+
+  impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+    fn on_runtime_upgrade() -> Weight {
+      let mut reads: u64 = 0;
+      let mut writes: u64 = 0;
+
+      for (account_id, subnet_id, stake) in AccountSubnetStake::<T>::iter() {
+        reads += 1;
+        if stake > 0 {
+          SubnetStakeAccounts::<T>::insert(subnet_id, account_id, ());
+          writes += 1;
+        }
+      }
+
+      for (account_id, subnet_id, shares) in AccountSubnetDelegateStakeShares::<T>::iter() {
+        reads += 1;
+        if shares > 0 {
+          SubnetDelegateStakeAccounts::<T>::insert(subnet_id, account_id, ());
+          writes += 1;
+        }
+      }
+
+      log::info!(
+        target: "runtime::network",
+        "indexed {} subnet stakers",
+        writes
+      );
+
+      T::DbWeight::get().reads_writes(reads, writes)
+    }
+  }
+
+  /// [`UncheckedMigrateToV1`] wrapped in a [`VersionedMigration`](frame_support::migrations::VersionedMigration)
+  /// so it only runs when the on-chain storage version is ``0``
+  pub type MigrateToV1<T> = frame_support::migrations::VersionedMigration<
+    0,
+    1,
+    UncheckedMigrateToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+  >;
+}
+
+/// Bounds stored subnet paths by ``MaxPathLength``
+pub mod v2 {
+  use super::*;
+  use frame_support::storage::unhashed;
+
+  /// Truncates any ``SubnetsData`` path longer than ``MaxPathLength`` and re-keys its ``SubnetPaths`` entry.
+  ///
+  /// Only the ``(id, path)`` prefix of each stored value is rewritten, the remaining bytes are copied as is.
+  /// A path that truncates onto one already in use is suffixed with ``-<n>`` within ``MaxPathLength``.
+  /// If no suffixed path is free, the subnet is left unchanged.
+  pub struct UncheckedMigrateToV2<T>(core::marker::PhantomData<T>);
+
+  /// ``path`` truncated to leave room for a ``-<n>`` suffix within ``max_path_length``
+  pub fn suffixed_path(path: &[u8], max_path_length: usize, n: u32) -> Vec<u8> {
+    let mut digits: Vec<u8> = Vec::new();
+    let mut rest = n;
+    loop {
+      digits.push(b'0' + (rest % 10) as u8);
+      rest /= 10;
+      if rest == 0 {
+        break
+      }
+    }
+    digits.reverse();
+
+    let suffix_len = digits.len() + 1;
+    let mut suffixed: Vec<u8> = path[..path.len().min(max_path_length.saturating_sub(suffix_len))].to_vec();
+    suffixed.push(b'-');
+    suffixed.extend_from_slice(&digits);
+    suffixed
+  }
+
+  impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV2<T> {
+    fn on_runtime_upgrade() -> Weight {
+      let max_path_length = T::MaxPathLength::get() as usize;
+      let mut reads: u64 = 0;
+      let mut writes: u64 = 0;
+      let mut bounded: u32 = 0;
+
+      let subnet_ids: Vec<u32> = SubnetsData::<T>::iter_keys().collect();
+      let max_suffix = subnet_ids.len() as u32;
+      for subnet_id in subnet_ids.iter().copied() {
+        reads += 1;
+        let key = SubnetsData::<T>::hashed_key_for(subnet_id);
+        let Some(raw) = unhashed::get_raw(&key) else {
+          continue
+        };
+
+        let mut input = &raw[..];
+        let Ok((id, path)) = <(u32, Vec<u8>)>::decode(&mut input) else {
+          log::warn!(
+            target: "runtime::network",
+            "subnet {} data could not be decoded",
+            subnet_id
+          );
+          continue
+        };
+
+        if path.len() <= max_path_length {
+          continue
+        }
+
+        let mut bounded_path = path.clone();
+        bounded_path.truncate(max_path_length);
+
+        // --- Keep ``SubnetPaths`` unique, a truncated path can match one already registered
+        reads += 1;
+        let mut suffix: u32 = 0;
+        while SubnetPaths::<T>::contains_key(&bounded_path) && suffix < max_suffix {
+          suffix += 1;
+          bounded_path = suffixed_path(&path, max_path_length, suffix);
+          reads += 1;
+        }
+
+        if bounded_path.len() > max_path_length || SubnetPaths::<T>::contains_key(&bounded_path) {
+          log::warn!(
+            target: "runtime::network",
+            "no free truncated path for subnet {}, keeping its path",
+            subnet_id
+          );
+          continue
+        }
+
+        let mut value = (id, &bounded_path).encode();
+        value.extend_from_slice(input);
+        unhashed::put_raw(&key, &value);
+        writes += 1;
+        bounded += 1;
+
+        SubnetPaths::<T>::remove(&path);
+        SubnetPaths::<T>::insert(&bounded_path, subnet_id);
+        writes += 2;
+      }
+
+      log::info!(
+        target: "runtime::network",
+        "bounded {} subnet paths",
+        bounded
+      );
+
+      T::DbWeight::get().reads_writes(reads, writes)
+    }
+  }
+
+  /// [`UncheckedMigrateToV2`] wrapped in a [`VersionedMigration`](frame_support::migrations::VersionedMigration)
+  /// so it only runs when the on-chain storage version is ``1``
+  pub type MigrateToV2<T> = frame_support::migrations::VersionedMigration<
+    1,
+    2,
+    UncheckedMigrateToV2<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+  >;
+}
+
+/// Adds the ``paused`` field to ``SubnetsData``
+pub mod v3 {
+  use super::*;
+
+  /// ``SubnetData`` before subnets could be paused
+  #[derive(Decode)]
+  pub struct OldSubnetData<MaxPathLength: Get<u32>> {
+    pub id: u32,
+    pub path: BoundedVec<u8, MaxPathLength>,
+    pub min_nodes: u32,
+    pub target_nodes: u32,
+    pub memory_mb: u128,
+    pub initialized: u64,
+    pub registration_blocks: u64,
+    pub activated: u64,
+  }
+
+  /// Translates every ``SubnetsData`` value to the current ``SubnetData``, no subnet starts paused
+  pub struct UncheckedMigrateToV3<T>(core::marker::PhantomData<T>);
+
+  impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV3<T> {
+    fn on_runtime_upgrade() -> Weight {
+      let mut translated: u64 = 0;
+
+      SubnetsData::<T>::translate::<OldSubnetData<T::MaxPathLength>, _>(|_, old| {
+        translated += 1;
+        Some(SubnetData {
+          id: old.id,
+          path: old.path,
+          min_nodes: old.min_nodes,
+          target_nodes: old.target_nodes,
+          memory_mb: old.memory_mb,
+          initialized: old.initialized,
+          registration_blocks: old.registration_blocks,
+          activated: old.activated,
+          paused: 0,
+        })
+      });
+
+      log::info!(
+        target: "runtime::network",
+        "translated {} subnets",
+        translated
+      );
+
+      T::DbWeight::get().reads_writes(translated, translated)
+    }
+  }
+
+  /// [`UncheckedMigrateToV3`] wrapped in a [`VersionedMigration`](frame_support::migrations::VersionedMigration)
+  /// so it only runs when the on-chain storage version is ``2``
+  pub type MigrateToV3<T> = frame_support::migrations::VersionedMigration<
+    2,
+    3,
+    UncheckedMigrateToV3<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+  >;
+}
+
+/// Adds attestations, reasons and outcomes to ``Proposals`` and bounds their evidence
+pub mod v4 {
+  use super::*;
+
+  /// ``ProposalParams`` before attestations and appeals
+  #[derive(Encode, Decode)]
+  pub struct OldProposalParams<AccountId> {
+    pub subnet_id: u32,
+    pub plaintiff: AccountId,
+    pub defendant: AccountId,
+    pub plaintiff_bond: u128,
+    pub defendant_bond: u128,
+    pub eligible_voters: BTreeSet<AccountId>,
+    pub votes: VoteParams<AccountId>,
+    pub start_block: u64,
+    pub challenge_block: u64,
+    pub plaintiff_data: Vec<u8>,
+    pub defendant_data: Vec<u8>,
+    pub complete: bool,
+  }
+
+  /// Translates every ``Proposals`` value to the current ``ProposalParams``.
+  ///
+  /// Open proposals need no attestations and keep their challenge period from their ``start_block``, as before.
+  /// Completed proposals were settled when they completed and are marked enacted so they can't be enacted again.
+  /// Evidence over ``DefaultProposalEvidenceLimit`` is truncated.
+  pub struct UncheckedMigrateToV4<T>(core::marker::PhantomData<T>);
+
+  impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV4<T> {
+    fn on_runtime_upgrade() -> Weight {
+      let mut translated: u64 = 0;
+
+      Proposals::<T>::translate::<OldProposalParams<T::AccountId>, _>(|_, _, old| {
+        translated += 1;
+        Some(ProposalParams {
+          subnet_id: old.subnet_id,
+          plaintiff: old.plaintiff,
+          defendant: old.defendant,
+          plaintiff_bond: old.plaintiff_bond,
+          defendant_bond: old.defendant_bond,
+          eligible_voters: old.eligible_voters,
+          votes: old.votes,
+          reason: ProposalReason::Other,
+          attests: BTreeMap::new(),
+          required_attests: 0,
+          attested_block: Some(old.start_block),
+          start_block: old.start_block,
+          challenge_block: old.challenge_block,
+          plaintiff_data: BoundedVec::truncate_from(old.plaintiff_data),
+          defendant_data: BoundedVec::truncate_from(old.defendant_data),
+          complete: old.complete,
+          outcome: None,
+          finalized_block: 0,
+          enacted: old.complete,
+        })
+      });
+
+      log::info!(
+        target: "runtime::network",
+        "translated {} proposals",
+        translated
+      );
+
+      T::DbWeight::get().reads_writes(translated, translated)
+    }
+  }
+
+  /// [`UncheckedMigrateToV4`] wrapped in a [`VersionedMigration`](frame_support::migrations::VersionedMigration)
+  /// so it only runs when the on-chain storage version is ``3``
+  pub type MigrateToV4<T> = frame_support::migrations::VersionedMigration<
+    3,
+    4,
+    UncheckedMigrateToV4<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+  >;
+}
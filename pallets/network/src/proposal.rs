@@ -17,12 +17,12 @@ use super::*;
 use sp_runtime::traits::TrailingZeroInput;
 
 impl<T: Config> Pallet<T> {
-  // TODO: Max vector string limit
   pub fn do_propose(
     account_id: T::AccountId, 
     subnet_id: u32,
     peer_id: PeerId,
-    data: Vec<u8>,
+    reason: ProposalReason,
+    data: BoundedVec<u8, DefaultProposalEvidenceLimit>,
   ) -> DispatchResult {
     // --- Ensure subnet exists
     let subnet = match SubnetsData::<T>::try_get(subnet_id) {
@@ -30,6 +30,12 @@ impl<T: Config> Pallet<T> {
       Err(()) => return Err(Error::<T>::SubnetNotExist.into()),
     };
 
+    // --- Ensure reason is allowed by the subnet
+    ensure!(
+      SubnetProposalReasons::<T>::get(subnet_id).contains(&reason),
+      Error::<T>::ProposalReasonNotAllowed
+    );
+
     let block: u64 = Self::get_current_block_as_u64();
     let epoch: u64 = block / T::EpochLength::get();
 
@@ -117,6 +123,15 @@ impl<T: Config> Pallet<T> {
 
     let proposal_id = ProposalsCount::<T>::get();
 
+    // --- Get attestations required for the reason
+    // If none are required, the challenge period begins immediately
+    let required_attests: u32 = SubnetProposalReasonAttests::<T>::get(subnet_id, reason);
+    let attested_block: Option<u64> = if required_attests == 0 {
+      Some(block)
+    } else {
+      None
+    };
+
     // TODO: Test adding quorum and consensus into the Proposal storage
     //       by using the amount of nodes in the subnet
     //       It's possible the quorum or consensus for smaller subnets may not be divisible
@@ -134,10 +149,14 @@ impl<T: Config> Pallet<T> {
           yay: BTreeSet::new(),
           nay: BTreeSet::new(),
         },
+        reason: reason,
+        attests: BTreeMap::new(),
+        required_attests: required_attests,
+        attested_block: attested_block,
         start_block: block,
         challenge_block: 0, // No challenge block initially
         plaintiff_data: data.clone(),
-        defendant_data: Vec::new(),
+        defendant_data: BoundedVec::new(),
         complete: false,
       }
     );
@@ -151,18 +170,21 @@ impl<T: Config> Pallet<T> {
         epoch: epoch as u32,
         plaintiff: account_id, 
         defendant: defendant_account_id,
-        plaintiff_data: data
+        reason: reason,
+        plaintiff_data: data.into_inner()
       }
     );
 
     Ok(())
   }
 
+  /// Attest to the plaintiffs evidence of a proposal
+  /// Once the required attestations of the proposals reason are reached, the challenge period begins
   pub fn do_attest_proposal(
     account_id: T::AccountId, 
     subnet_id: u32,
     proposal_id: u32,
-    data: Vec<u8>,
+    data: BoundedVec<u8, DefaultProposalEvidenceLimit>,
   ) -> DispatchResult {
     let proposal = match Proposals::<T>::try_get(subnet_id, proposal_id) {
       Ok(proposal) => proposal,
@@ -170,15 +192,83 @@ impl<T: Config> Pallet<T> {
         return Err(Error::<T>::ProposalInvalid.into()),
     };
 
+    // --- Ensure not plaintiff or defendant
+    ensure!(
+      account_id != proposal.plaintiff && account_id != proposal.defendant,
+      Error::<T>::PartiesCannotAttest
+    );
+
+    // --- Ensure account has peer
+    ensure!(
+      SubnetNodesData::<T>::contains_key(subnet_id, account_id.clone()),
+      Error::<T>::SubnetNodeNotExist
+    );
+
+    // --- Ensure incomplete
+    ensure!(
+      !proposal.complete,
+      Error::<T>::ProposalComplete
+    );
+
+    // --- Ensure unchallenged
+    ensure!(
+      proposal.challenge_block == 0,
+      Error::<T>::ProposalChallenged
+    );
+
+    let challenge_period = ChallengePeriod::<T>::get();
+    let block: u64 = Self::get_current_block_as_u64();
+
+    // --- Ensure proposal hasn't expired awaiting attestations or a challenge
+    ensure!(
+      block < proposal.attested_block.unwrap_or(proposal.start_block) + challenge_period,
+      Error::<T>::ProposalChallengePeriodPassed
+    );
+
+    // --- Ensure is eligible to attest
+    ensure!(
+      proposal.eligible_voters.get(&account_id).is_some(),
+      Error::<T>::NotEligible
+    );
+
+    // --- Ensure hasn't already attested
+    ensure!(
+      proposal.attests.get(&account_id) == None,
+      Error::<T>::AlreadyAttested
+    );
+
+    let mut attests_reached: bool = false;
+
+    Proposals::<T>::mutate(
+      subnet_id,
+      proposal_id,
+      |params: &mut ProposalParams<T::AccountId>| {
+        params.attests.insert(account_id.clone(), block);
+        if params.attested_block.is_none() && params.attests.len() as u32 >= params.required_attests {
+          params.attested_block = Some(block);
+          attests_reached = true;
+        }
+      }
+    );
+
     Self::deposit_event(
       Event::ProposalAttested{ 
         subnet_id: subnet_id, 
         proposal_id: proposal_id, 
         account_id: account_id,
-        attestor_data: data
+        attestor_data: data.into_inner()
       }
     );
 
+    if attests_reached {
+      Self::deposit_event(
+        Event::ProposalAttestsReached{ 
+          subnet_id: subnet_id, 
+          proposal_id: proposal_id, 
+        }
+      );
+    }
+
     Ok(())
   }
 
@@ -186,7 +276,7 @@ impl<T: Config> Pallet<T> {
     account_id: T::AccountId, 
     subnet_id: u32,
     proposal_id: u32,
-    data: Vec<u8>,
+    data: BoundedVec<u8, DefaultProposalEvidenceLimit>,
   ) -> DispatchResult {
     let proposal = match Proposals::<T>::try_get(subnet_id, proposal_id) {
       Ok(proposal) => proposal,
@@ -206,12 +296,19 @@ impl<T: Config> Pallet<T> {
       Error::<T>::ProposalComplete
     );
     
+    // --- Ensure required attestations reached
+    // The challenge period begins once the proposal is attested
+    let attested_block: u64 = match proposal.attested_block {
+      Some(attested_block) => attested_block,
+      None => return Err(Error::<T>::ProposalNotAttested.into()),
+    };
+
     let challenge_period = ChallengePeriod::<T>::get();
     let block: u64 = Self::get_current_block_as_u64();
 
     // --- Ensure challenge period is active
     ensure!(
-      block < proposal.start_block + challenge_period,
+      block < attested_block + challenge_period,
       Error::<T>::ProposalChallengePeriodPassed
     );
 
@@ -261,7 +358,7 @@ impl<T: Config> Pallet<T> {
         subnet_id: subnet_id, 
         proposal_id: proposal_id,
         defendant: account_id, 
-        defendant_data: data,
+        defendant_data: data.into_inner(),
       }
     );
 
@@ -439,8 +536,6 @@ impl<T: Config> Pallet<T> {
       Error::<T>::VotingPeriodInvalid
     );
 
-    // TODO: include enactment period for executing proposals
-
     // --- Ensure quorum reached
     let yays_len: u128 = proposal.votes.yay.len() as u128;
     let nays_len: u128 = proposal.votes.nay.len() as u128;
@@ -456,23 +551,23 @@ impl<T: Config> Pallet<T> {
     let quorum_reached: bool = voting_percentage >= ProposalQuorum::<T>::get();
     let consensus_threshold: u128 = ProposalConsensusThreshold::<T>::get();
 
-    // --- Mark as complete
-    Proposals::<T>::mutate(
-      subnet_id,
-      proposal_id,
-      |params: &mut ProposalParams<T::AccountId>| {
-        params.complete = true;
-        params.plaintiff_bond = 0;
-        params.defendant_bond = 0;
-      }
-    );
-
     // --- If quorum not reached and both voting options didn't succeed consensus then complete
     if !quorum_reached || 
       (yays_percentage < consensus_threshold && 
       nays_percentage < consensus_threshold && 
       quorum_reached)
     {
+      // --- Mark as complete
+      Proposals::<T>::mutate(
+        subnet_id,
+        proposal_id,
+        |params: &mut ProposalParams<T::AccountId>| {
+          params.complete = true;
+          params.plaintiff_bond = 0;
+          params.defendant_bond = 0;
+        }
+      );
+
       // Give plaintiff and defendant bonds back
       T::Currency::deposit_creating(&proposal.plaintiff, plaintiff_bond_as_balance.unwrap());
       T::Currency::deposit_creating(&proposal.defendant, defendant_bond_as_balance.unwrap());
@@ -480,28 +575,24 @@ impl<T: Config> Pallet<T> {
     }
 
     // --- At this point we know that one of the voting options are in consensus
-    if yays_len > nays_len {
-      // --- Plaintiff wins
-      // --- Remove defendant
-      Self::perform_remove_subnet_node(block, subnet_id, proposal.defendant);
-      // --- Return bond
-      T::Currency::deposit_creating(&proposal.plaintiff, plaintiff_bond_as_balance.unwrap());
-      // --- Distribute bond to voters in consensus
-      Self::distribute_bond(
-        proposal.defendant_bond, 
-        proposal.votes.yay,
-        &proposal.plaintiff
-      );
+    let outcome: ProposalOutcome = if yays_len > nays_len {
+      ProposalOutcome::Plaintiff
     } else {
-      // --- Defendant wins
-      T::Currency::deposit_creating(&proposal.defendant, defendant_bond_as_balance.unwrap());
-      // --- Distribute bond to voters in consensus
-      Self::distribute_bond(
-        proposal.plaintiff_bond, 
-        proposal.votes.nay,
-        &proposal.defendant
-      );
-    }
+      ProposalOutcome::Defendant
+    };
+
+    // --- Mark as complete
+    // Removal and bond distribution are suspended until the appeal period passes
+    // or the council resolves an appeal
+    Proposals::<T>::mutate(
+      subnet_id,
+      proposal_id,
+      |params: &mut ProposalParams<T::AccountId>| {
+        params.complete = true;
+        params.outcome = Some(outcome);
+        params.finalized_block = block;
+      }
+    );
 
     Self::deposit_event(
       Event::ProposalFinalized{ 
@@ -513,6 +604,256 @@ impl<T: Config> Pallet<T> {
     Ok(())
   }
 
+  /// Appeal the outcome of a finalized proposal to the council
+  /// Only the losing party can appeal and must bond ``AppealBondAmount`` within the appeal period
+  pub fn do_appeal_proposal(
+    account_id: T::AccountId, 
+    subnet_id: u32,
+    proposal_id: u32,
+  ) -> DispatchResult {
+    let proposal = match Proposals::<T>::try_get(subnet_id, proposal_id) {
+      Ok(proposal) => proposal,
+      Err(()) =>
+        return Err(Error::<T>::ProposalInvalid.into()),
+    };
+
+    let outcome: ProposalOutcome = match proposal.outcome {
+      Some(outcome) => outcome,
+      None => return Err(Error::<T>::ProposalNotFinalized.into()),
+    };
+
+    // --- Ensure not enacted
+    ensure!(
+      !proposal.enacted,
+      Error::<T>::ProposalEnacted
+    );
+
+    // --- Ensure losing party
+    let losing_party: T::AccountId = match outcome {
+      ProposalOutcome::Plaintiff => proposal.defendant,
+      ProposalOutcome::Defendant => proposal.plaintiff,
+    };
+
+    ensure!(
+      account_id == losing_party,
+      Error::<T>::NotLosingParty
+    );
+
+    // --- Ensure not already appealed
+    ensure!(
+      !ProposalAppeals::<T>::contains_key(subnet_id, proposal_id),
+      Error::<T>::ProposalAppealed
+    );
+
+    let block: u64 = Self::get_current_block_as_u64();
+
+    // --- Ensure appeal period is active
+    ensure!(
+      block < proposal.finalized_block + AppealPeriod::<T>::get(),
+      Error::<T>::AppealPeriodPassed
+    );
+
+    let appeal_bond: u128 = AppealBondAmount::<T>::get();
+    let appeal_bond_as_balance = Self::u128_to_balance(appeal_bond);
+
+    let can_withdraw: bool = Self::can_remove_balance_from_coldkey_account(
+      &account_id,
+      appeal_bond_as_balance.unwrap(),
+    );
+
+    // --- Ensure can bond
+    ensure!(
+      can_withdraw,
+      Error::<T>::NotEnoughBalanceToAppeal
+    );
+
+    // --- Withdraw appeal bond from appellant
+    let _ = T::Currency::withdraw(
+      &account_id,
+      appeal_bond_as_balance.unwrap(),
+      WithdrawReasons::except(WithdrawReasons::TRANSFER),
+      ExistenceRequirement::KeepAlive,
+    );
+
+    ProposalAppeals::<T>::insert(
+      subnet_id,
+      proposal_id,
+      AppealParams {
+        appellant: account_id.clone(),
+        bond: appeal_bond,
+        block: block,
+      }
+    );
+
+    Self::deposit_event(
+      Event::ProposalAppealed { 
+        subnet_id: subnet_id, 
+        proposal_id: proposal_id,
+        appellant: account_id, 
+      }
+    );
+
+    Ok(())
+  }
+
+  /// Enact the outcome of a finalized proposal that wasn't appealed within the appeal period
+  pub fn do_enact_proposal(
+    subnet_id: u32,
+    proposal_id: u32,
+  ) -> DispatchResult {
+    let proposal = match Proposals::<T>::try_get(subnet_id, proposal_id) {
+      Ok(proposal) => proposal,
+      Err(()) =>
+        return Err(Error::<T>::ProposalInvalid.into()),
+    };
+
+    let outcome: ProposalOutcome = match proposal.outcome {
+      Some(outcome) => outcome,
+      None => return Err(Error::<T>::ProposalNotFinalized.into()),
+    };
+
+    // --- Ensure not enacted
+    ensure!(
+      !proposal.enacted,
+      Error::<T>::ProposalEnacted
+    );
+
+    // --- Ensure no appeal pending
+    // Appealed proposals are enacted by the council
+    ensure!(
+      !ProposalAppeals::<T>::contains_key(subnet_id, proposal_id),
+      Error::<T>::ProposalAppealed
+    );
+
+    let block: u64 = Self::get_current_block_as_u64();
+
+    // --- Ensure appeal period passed
+    ensure!(
+      block >= proposal.finalized_block + AppealPeriod::<T>::get(),
+      Error::<T>::AppealPeriodNotPassed
+    );
+
+    Self::enact_proposal_outcome(block, subnet_id, proposal_id, proposal, outcome, 0);
+
+    Ok(())
+  }
+
+  /// Council resolution of an appealed proposal
+  /// Upholding enacts the original outcome and distributes the appeal bond with the losing bond
+  /// Reversing refunds the appeal bond and enacts the opposite outcome
+  pub fn do_resolve_proposal_appeal(
+    subnet_id: u32,
+    proposal_id: u32,
+    uphold: bool,
+  ) -> DispatchResult {
+    let proposal = match Proposals::<T>::try_get(subnet_id, proposal_id) {
+      Ok(proposal) => proposal,
+      Err(()) =>
+        return Err(Error::<T>::ProposalInvalid.into()),
+    };
+
+    let outcome: ProposalOutcome = match proposal.outcome {
+      Some(outcome) => outcome,
+      None => return Err(Error::<T>::ProposalNotFinalized.into()),
+    };
+
+    // --- Ensure not enacted
+    ensure!(
+      !proposal.enacted,
+      Error::<T>::ProposalEnacted
+    );
+
+    let appeal = match ProposalAppeals::<T>::take(subnet_id, proposal_id) {
+      Some(appeal) => appeal,
+      None => return Err(Error::<T>::AppealNotExist.into()),
+    };
+
+    let block: u64 = Self::get_current_block_as_u64();
+
+    if uphold {
+      Self::enact_proposal_outcome(block, subnet_id, proposal_id, proposal, outcome, appeal.bond);
+    } else {
+      // --- Give appellant bond back
+      let appeal_bond_as_balance = Self::u128_to_balance(appeal.bond);
+      if appeal_bond_as_balance.is_some() {
+        T::Currency::deposit_creating(&appeal.appellant, appeal_bond_as_balance.unwrap());
+      }
+      Self::enact_proposal_outcome(block, subnet_id, proposal_id, proposal, outcome.reversed(), 0);
+    }
+
+    Self::deposit_event(
+      Event::ProposalAppealResolved { 
+        subnet_id: subnet_id, 
+        proposal_id: proposal_id,
+        upheld: uphold, 
+      }
+    );
+
+    Ok(())
+  }
+
+  /// Remove the defendant if the plaintiff wins, return the winners bond, and distribute
+  /// the losers bond, and any forfeited appeal bond, to the voters in consensus
+  fn enact_proposal_outcome(
+    block: u64,
+    subnet_id: u32,
+    proposal_id: u32,
+    proposal: ProposalParams<T::AccountId>,
+    outcome: ProposalOutcome,
+    forfeited_bond: u128,
+  ) {
+    // --- Mark as enacted
+    Proposals::<T>::mutate(
+      subnet_id,
+      proposal_id,
+      |params: &mut ProposalParams<T::AccountId>| {
+        params.outcome = Some(outcome);
+        params.enacted = true;
+        params.plaintiff_bond = 0;
+        params.defendant_bond = 0;
+      }
+    );
+
+    let plaintiff_bond_as_balance = Self::u128_to_balance(proposal.plaintiff_bond);
+    let defendant_bond_as_balance = Self::u128_to_balance(proposal.defendant_bond);
+
+    match outcome {
+      ProposalOutcome::Plaintiff => {
+        // --- Remove and penalize defendant
+        Self::increase_account_penalty_count(&proposal.defendant);
+        Self::perform_remove_subnet_node(block, subnet_id, proposal.defendant.clone());
+        // --- Return bond
+        T::Currency::deposit_creating(&proposal.plaintiff, plaintiff_bond_as_balance.unwrap());
+        // --- Distribute bond to voters in consensus
+        Self::distribute_bond(
+          proposal.defendant_bond.saturating_add(forfeited_bond), 
+          proposal.votes.yay,
+          &proposal.plaintiff
+        );
+      },
+      ProposalOutcome::Defendant => {
+        // --- Penalize plaintiff
+        Self::increase_account_penalty_count(&proposal.plaintiff);
+        // --- Return bond
+        T::Currency::deposit_creating(&proposal.defendant, defendant_bond_as_balance.unwrap());
+        // --- Distribute bond to voters in consensus
+        Self::distribute_bond(
+          proposal.plaintiff_bond.saturating_add(forfeited_bond), 
+          proposal.votes.nay,
+          &proposal.defendant
+        );
+      },
+    }
+
+    Self::deposit_event(
+      Event::ProposalEnacted{ 
+        subnet_id: subnet_id, 
+        proposal_id: proposal_id, 
+        outcome: outcome,
+      }
+    );
+  }
+
   pub fn distribute_bond(
     bond: u128, 
     mut distributees: BTreeSet<T::AccountId>,
@@ -536,9 +877,10 @@ impl<T: Config> Pallet<T> {
       T::Currency::deposit_creating(&account, distribution_amount_as_balance.unwrap());
     }
 
-    // --- Take care of dust and send to winner
+    // --- Take care of dust and send to treasury and winner
     if total_distributed < bond {
-      let remaining_bond = bond - total_distributed;
+      let mut remaining_bond = bond - total_distributed;
+      remaining_bond -= Self::deposit_treasury_share(remaining_bond, TreasurySplits::<T>::get().proposal_dust);
       let remaining_bid_as_balance = Self::u128_to_balance(remaining_bond);
       if remaining_bid_as_balance.is_some() {
         T::Currency::deposit_creating(&winner.clone(), remaining_bid_as_balance.unwrap());
@@ -563,11 +905,11 @@ impl<T: Config> Pallet<T> {
       }
 
       // At this point we have a proposal that matches the plaintiff
-      let proposal_block: u64 = proposal.start_block;
+      let proposal_block: u64 = proposal.attested_block.unwrap_or(proposal.start_block);
       let challenge_block: u64 = proposal.challenge_block;
       if challenge_block == 0 {
-        // If time remaining for challenge
-        if block < proposal.start_block + challenge_period {
+        // If time remaining for attestations or challenge
+        if block < proposal_block + challenge_period {
           active_proposal = true;
           break;
         }
@@ -585,6 +927,8 @@ impl<T: Config> Pallet<T> {
 
   /// Does a subnet node have a proposal against them under the following conditions
   /// Proposal must not be completed to qualify or awaiting challenge
+  /// A challenged proposal awaiting finalization, or a finalized outcome awaiting
+  /// the appeal period, a council resolution or enactment, is still active
   fn account_has_active_proposal_as_defendant(
     subnet_id: u32, 
     account_id: T::AccountId, 
@@ -607,11 +951,20 @@ impl<T: Config> Pallet<T> {
       }
 
       // At this point we have a proposal that matches the defendant
-      let proposal_block: u64 = proposal.start_block;
+      let proposal_block: u64 = proposal.attested_block.unwrap_or(proposal.start_block);
       let challenge_block: u64 = proposal.challenge_block;
+
+      // --- Outcome not yet settled
+      if (challenge_block != 0 && !proposal.complete) || 
+        (proposal.outcome.is_some() && !proposal.enacted)
+      {
+        active_proposal = true;
+        break;
+      }
+
       if challenge_block == 0 {
-        // If time remaining for challenge
-        if block < proposal.start_block + challenge_period {
+        // If time remaining for attestations or challenge
+        if block < proposal_block + challenge_period {
           active_proposal = true;
           break;
         }
@@ -17,11 +17,76 @@ use super::*;
 use sp_runtime::Saturating;
 use frame_support::pallet_prelude::DispatchResultWithPostInfo;
 use frame_support::pallet_prelude::Pays;
+use sp_runtime::{helpers_128bit::multiply_by_rational_with_rounding, Rounding};
 
 impl<T: Config> Pallet<T> {
+  /// Reward all subnets for the epoch at once
+  //
+  // ``on_initialize`` carries out the same steps over multiple blocks, see ``do_epoch_processing``
   pub fn reward_subnets(block: u64, epoch: u32) -> DispatchResultWithPostInfo {
-    // --- Get base rewards based on subnet memory requirements
-    let base_reward_per_mb: u128 = BaseRewardPerMB::<T>::get();
+    let budget: u128 = Self::begin_epoch_rewards(epoch);
+
+    let mut emitted: u128 = 0;
+    let subnet_ids: Vec<u32> = SubnetsData::<T>::iter_keys().collect();
+    for subnet_id in subnet_ids {
+      emitted = emitted.saturating_add(Self::reward_subnet(block, epoch, subnet_id));
+    }
+
+    Self::finish_epoch_rewards(epoch, budget, emitted);
+
+    Ok(None.into())
+  }
+
+  /// Get the epochs emission budget and store each eligible subnets emission, returns the budget
+  pub fn begin_epoch_rewards(epoch: u32) -> u128 {
+    // --- Get the epochs emission budget, everything accrued in the stake vault
+    let budget: u128 = Self::get_epoch_emission_budget();
+
+    // --- Get the emission shares of subnets eligible for emissions on this epoch
+    for (subnet_id, share) in Self::get_subnet_emission_shares(epoch) {
+      EpochSubnetEmissions::<T>::insert(subnet_id, Self::get_subnet_emission(budget, share));
+    }
+
+    budget
+  }
+
+  /// Debit the stake vault for what was paid, the unpaid budget rolls over or is burned
+  pub fn finish_epoch_rewards(epoch: u32, budget: u128, emitted: u128) {
+    let unpaid: u128 = budget.saturating_sub(emitted);
+    let burned: u128 = match UnpaidEmissions::<T>::get() {
+      UnpaidEmissionsPolicy::Rollover => 0,
+      UnpaidEmissionsPolicy::Burn => unpaid,
+    };
+    StakeVaultBalance::<T>::mutate(|n: &mut u128| *n = n.saturating_sub(emitted.saturating_add(burned)));
+
+    Self::deposit_event(
+      Event::EpochEmissions { 
+        epoch: epoch, 
+        budget: budget, 
+        emitted: emitted,
+        burned: burned,
+      }
+    );
+  }
+
+  /// Reward a subnet for the epoch out of its emission stored in ``begin_epoch_rewards``,
+  /// returns the amount paid to its subnet nodes and delegate stakers
+  pub fn reward_subnet(block: u64, epoch: u32, subnet_id: u32) -> u128 {
+    // --- Get subnet rewards, the subnets weighted share of the budget
+    let overall_subnet_reward: u128 = EpochSubnetEmissions::<T>::take(subnet_id);
+
+    let data = match SubnetsData::<T>::try_get(subnet_id) {
+      Ok(data) => data,
+      Err(()) => return 0,
+    };
+
+    // --- Subnet node classes and penalties are frozen while the subnet is paused
+    if data.paused != 0 {
+      return 0
+    }
+
+    // --- Paid to subnet nodes and delegate stakers
+    let mut emitted: u128 = 0;
     // --- Get required attestation percentage
     let min_attestation_percentage = MinAttestationPercentage::<T>::get();
     let min_vast_majority_attestation_percentage = MinVastMajorityAttestationPercentage::<T>::get();
@@ -39,281 +104,452 @@ impl<T: Config> Pallet<T> {
 
     let subnet_node_registration_epochs = SubnetNodeRegistrationEpochs::<T>::get();
 
-    for (subnet_id, data) in SubnetsData::<T>::iter() {
-      // --- We don't check for minimum nodes because nodes cannot validate or attest if they are not met
-      //     as they the validator will not be chosen in ``do_epoch_preliminaries`` if the 
-      //     min nodes are not met on that epoch.
-      if let Ok(mut submission) = SubnetRewardsSubmission::<T>::try_get(subnet_id, epoch) {
-        // --- Get memory of the subnet
-        let memory_mb = data.memory_mb;
-
-        // --- Get subnet rewards
-        let overall_subnet_reward: u128 = Self::percent_mul(base_reward_per_mb, memory_mb);
-
-        // --- Get delegators rewards
-        // We get the delegators rewards in case of rounding issues in favor of subnet nodes over delegators
-        let delegate_stake_reward: u128 = Self::percent_mul(overall_subnet_reward, delegate_stake_rewards_percentage);
-
-        // --- Get subnet nodes rewards
-        let subnet_reward: u128 = overall_subnet_reward.saturating_sub(delegate_stake_reward);
-
-        let min_nodes = data.min_nodes;
-
-        // --- Get subnet nodes count to check against attestation count
-        // ``reward_subnuts`` is called before ``shift_node_classes`` so we can know how many nodes are submittable
-        // while in this function that should have in the epoch the rewards are destined for
-        let subnet_node: Vec<T::AccountId> = Self::get_classified_accounts(subnet_id, &SubnetNodeClass::Submittable, epoch as u64);
-        let subnet_node_count = subnet_node.len() as u128;
-
-        let attestations: u128 = submission.attests.len() as u128;
-        let mut attestation_percentage: u128 = Self::percent_div(attestations, subnet_node_count);
-
-        // Redundant
-        // When subnet nodes exit, the consensus data is updated to remove them from it
-        if attestation_percentage > Self::PERCENTAGE_FACTOR {
-          attestation_percentage = Self::PERCENTAGE_FACTOR;
-        }
-        let validator: T::AccountId = submission.validator;
+    let max_subnet_node_deactivation_epochs = MaxSubnetNodeDeactivationEpochs::<T>::get();
 
-        let data_len = submission.data.len();
+    // --- We don't check for minimum nodes because nodes cannot validate or attest if they are not met
+    //     as they the validator will not be chosen in ``do_epoch_preliminaries`` if the 
+    //     min nodes are not met on that epoch.
+    if let Ok(mut submission) = SubnetRewardsSubmission::<T>::try_get(subnet_id, epoch) {
+      let min_nodes = data.min_nodes;
 
-        // --- If validator submitted no data, or less than the minimum required subnet nodes 
-        //     we assume the subnet is broken
-        // There is no slashing if subnet is broken, only risk of subnet being removed
-        // The subnet is deemed broken is there is no consensus or not enough nodes
-        //
-        // The subnet has up to the MaxSubnetPenaltyCount to solve the issue before the subnet and all subnet nodes are removed
-        if (data_len as u32) < min_nodes  {
-          // --- Increase the penalty count for the subnet because its deemed in a broken state
-          SubnetPenaltyCount::<T>::mutate(subnet_id, |n: &mut u32| *n += 1);
-
-          // If the subnet is broken, the validator can avoid slashing by submitting consensus with null data
-
-          // --- If subnet nodes aren't in consensus this is true
-          // Since we can assume the subnet is in a broken state, we don't slash the validator
-          // even if others do not attest to this state???
-
-          // --- If the subnet nodes are not in agreement with the validator that the model is broken, we
-          //     increase the penalty score for the validator
-          //     and slash
-          // --- We only do this if the vast majority of nodes are not in agreement with the validator
-          //     Otherwise we assume the issue just started or is being resolved.
-          //     i.e. if a validator sends in no data but by the time some other nodes check, it's resolved,
-          //          the validator only gets slashed if the vast majority of nodes disagree at ~87.5%
-          //     Or vice versa if validator submits data in a healthy state and the subnet breaks
-          //
-          // This is an unlikely scenario because all nodes should be checking the subnets state within a few seconds
-          // of each other.
-          if attestation_percentage < min_vast_majority_attestation_percentage {
-            // --- Slash validator and increase penalty score
-            Self::slash_validator(subnet_id, validator, attestation_percentage, block);
-          }
+      // --- Get subnet nodes count to check against attestation count
+      // ``reward_subnuts`` is called before ``shift_node_classes`` so we can know how many nodes are submittable
+      // while in this function that should have in the epoch the rewards are destined for
+      let subnet_node: Vec<T::AccountId> = Self::get_classified_accounts(subnet_id, &SubnetNodeClass::Submittable, epoch as u64);
+      let subnet_node_count = subnet_node.len() as u128;
 
-          // --- If the subnet was deemed in a broken stake by the validator, rewards are bypassed
-          continue;
-        }
+      let attestations: u128 = submission.attests.len() as u128;
+      let mut attestation_percentage: u128 = Self::percent_div(attestations, subnet_node_count);
 
-        // --- If the minimum required attestation not reached, assume validator is dishonest, slash, and continue
-        // We don't increase subnet penalty count here because this is likely the validators fault
-        if attestation_percentage < min_attestation_percentage {
+      // Redundant
+      // When subnet nodes exit, the consensus data is updated to remove them from it
+      if attestation_percentage > Self::PERCENTAGE_FACTOR {
+        attestation_percentage = Self::PERCENTAGE_FACTOR;
+      }
+      let validator: T::AccountId = submission.validator;
+
+      // --- Get validator reward, paid out of the subnets rewards
+      let validator_reward: u128 = Self::get_validator_reward(attestation_percentage).min(overall_subnet_reward);
+
+      // --- Get delegators rewards
+      // We get the delegators rewards in case of rounding issues in favor of subnet nodes over delegators
+      let delegate_stake_reward: u128 = Self::percent_mul(
+        overall_subnet_reward.saturating_sub(validator_reward), 
+        delegate_stake_rewards_percentage
+      );
+
+      // --- Get subnet nodes rewards
+      let subnet_reward: u128 = overall_subnet_reward
+        .saturating_sub(validator_reward)
+        .saturating_sub(delegate_stake_reward);
+
+      // --- Receipt of the subnets rewards and slashes on this epoch
+      let mut receipt: SubnetRewardsReceipt<T::AccountId> = SubnetRewardsReceipt {
+        validator: validator.clone(),
+        validator_reward: 0,
+        node_rewards: BoundedVec::new(),
+        unlisted_node_rewards: 0,
+        delegate_stake_reward: 0,
+        validator_slash: 0,
+      };
+
+      let data_len = submission.data.len();
+
+      // --- If validator submitted no data, or less than the minimum required subnet nodes 
+      //     we assume the subnet is broken
+      // There is no slashing if subnet is broken, only risk of subnet being removed
+      // The subnet is deemed broken is there is no consensus or not enough nodes
+      //
+      // The subnet has up to the MaxSubnetPenaltyCount to solve the issue before the subnet and all subnet nodes are removed
+      if (data_len as u32) < min_nodes  {
+        // --- Increase the penalty count for the subnet because its deemed in a broken state
+        SubnetPenaltyCount::<T>::mutate(subnet_id, |n: &mut u32| *n += 1);
+
+        // If the subnet is broken, the validator can avoid slashing by submitting consensus with null data
+
+        // --- If subnet nodes aren't in consensus this is true
+        // Since we can assume the subnet is in a broken state, we don't slash the validator
+        // even if others do not attest to this state???
+
+        // --- If the subnet nodes are not in agreement with the validator that the model is broken, we
+        //     increase the penalty score for the validator
+        //     and slash
+        // --- We only do this if the vast majority of nodes are not in agreement with the validator
+        //     Otherwise we assume the issue just started or is being resolved.
+        //     i.e. if a validator sends in no data but by the time some other nodes check, it's resolved,
+        //          the validator only gets slashed if the vast majority of nodes disagree at ~87.5%
+        //     Or vice versa if validator submits data in a healthy state and the subnet breaks
+        //
+        // This is an unlikely scenario because all nodes should be checking the subnets state within a few seconds
+        // of each other.
+        if attestation_percentage < min_vast_majority_attestation_percentage {
           // --- Slash validator and increase penalty score
-          Self::slash_validator(subnet_id, validator, attestation_percentage, block);
-          
-          // --- Attestation not successful, move on to next subnet
-          continue
+          receipt.validator_slash = Self::slash_validator(subnet_id, validator, attestation_percentage, block);
         }
 
-        // --- Get sum of subnet total scores for use of divvying rewards
-        let sum = submission.data.iter().fold(0, |acc, x| acc + x.score);
-    
-        // --- Reward validators
-        for subnet_node in SubnetNodesData::<T>::iter_prefix_values(subnet_id) {
-          let account_id: T::AccountId = subnet_node.account_id;
-
-          // --- (if) Check if subnet node is past the max registration epochs to update to Included
-          // --- (else if) Check if past Idle and can be included in validation data
-          // Always continue if any of these are true
-          // Note: Only ``included`` or above nodes can get emissions
-          if subnet_node.classification.class == SubnetNodeClass::Registered {
-            if epoch as u64 > subnet_node.classification.start_epoch.saturating_add(subnet_node_registration_epochs) {
-              Self::perform_remove_subnet_node(block, subnet_id, account_id);
-            }
-            continue
-          } else if subnet_node.classification.class == SubnetNodeClass::Idle {
-            // If not, upgrade classification and continue
-            // --- Upgrade to included
-            SubnetNodesData::<T>::mutate(
-              subnet_id,
-              account_id,
-              |params: &mut SubnetNode<T::AccountId>| {
-                params.classification = SubnetNodeClassification {
-                  class: SubnetNodeClass::Included,
-                  start_epoch: (epoch) as u64,
-                };
-              },
-            );
-            continue
-          }
+        Self::record_rewards_receipt(subnet_id, epoch, receipt);
 
-          // --- At this point, all nodes should be included in consensus data
+        // --- If the subnet was deemed in a broken stake by the validator, rewards are bypassed
+        return 0
+      }
 
-          let peer_id: PeerId = subnet_node.peer_id;
+      // --- If the minimum required attestation not reached, assume validator is dishonest, slash, and continue
+      // We don't increase subnet penalty count here because this is likely the validators fault
+      if attestation_percentage < min_attestation_percentage {
+        // --- Slash validator and increase penalty score
+        receipt.validator_slash = Self::slash_validator(subnet_id, validator, attestation_percentage, block);
 
-          let mut subnet_node_data: SubnetNodeData = SubnetNodeData::default();
+        Self::record_rewards_receipt(subnet_id, epoch, receipt);
+        
+        // --- Attestation not successful, move on to next subnet
+        return 0
+      }
 
-          // --- Confirm if ``peer_id`` is present in validator data
-          let subnet_node_data_find: Option<(usize, &SubnetNodeData)> = submission.data.iter().enumerate().find(
-            |&x| x.1.peer_id == peer_id
+      // --- Get sum of subnet total scores for use of divvying rewards
+      let sum = submission.data.iter().fold(0, |acc, x| acc + x.score);
+  
+      // --- Reward validators
+      for subnet_node in SubnetNodesData::<T>::iter_prefix_values(subnet_id) {
+        let account_id: T::AccountId = subnet_node.account_id;
+
+        // --- (if) Check if subnet node is past the max registration epochs to update to Included
+        // --- (else if) Check if past Idle and can be included in validation data
+        // Always continue if any of these are true
+        // Note: Only ``included`` or above nodes can get emissions
+        if subnet_node.classification.class == SubnetNodeClass::Deactivated {
+          // --- Deactivated nodes aren't penalized for being absent, only removed once deactivated for too long
+          if epoch as u64 > subnet_node.classification.start_epoch.saturating_add(max_subnet_node_deactivation_epochs) {
+            Self::perform_remove_subnet_node(block, subnet_id, account_id);
+          }
+          continue
+        } else if subnet_node.classification.class == SubnetNodeClass::Registered {
+          if epoch as u64 > subnet_node.classification.start_epoch.saturating_add(subnet_node_registration_epochs) {
+            Self::perform_remove_subnet_node(block, subnet_id, account_id);
+          }
+          continue
+        } else if subnet_node.classification.class == SubnetNodeClass::Idle {
+          // If not, upgrade classification and continue
+          // --- Upgrade to included
+          SubnetNodesData::<T>::mutate(
+            subnet_id,
+            account_id,
+            |params: &mut SubnetNode<T::AccountId>| {
+              params.classification = SubnetNodeClassification {
+                class: SubnetNodeClass::Included,
+                start_epoch: (epoch) as u64,
+              };
+            },
           );
-          
-          // --- If peer_id is present in validator data
-          let validated: bool = subnet_node_data_find.is_some();
+          continue
+        }
 
-          if validated {
-            subnet_node_data = subnet_node_data_find.unwrap().1.clone();
-            submission.data.remove(subnet_node_data_find.unwrap().0);
-          }
+        // --- Reactivated nodes are included starting on the epoch following their reactivation
+        if subnet_node.classification.start_epoch > epoch as u64 {
+          continue
+        }
+
+        // --- At this point, all nodes should be included in consensus data
+
+        let peer_id: PeerId = subnet_node.peer_id;
+
+        let mut subnet_node_data: SubnetNodeData = SubnetNodeData::default();
+
+        // --- Confirm if ``peer_id`` is present in validator data
+        let subnet_node_data_find: Option<(usize, &SubnetNodeData)> = submission.data.iter().enumerate().find(
+          |&x| x.1.peer_id == peer_id
+        );
+        
+        // --- If peer_id is present in validator data
+        let validated: bool = subnet_node_data_find.is_some();
+
+        if validated {
+          subnet_node_data = subnet_node_data_find.unwrap().1.clone();
+          submission.data.remove(subnet_node_data_find.unwrap().0);
+        }
 
-          let penalties = SubnetNodePenalties::<T>::get(subnet_id, account_id.clone());
-
-          // --- If node not validated and consensus reached:
-          //      otherwise, increment penalty score only
-          //      remove them if max penalties threshold is reached
-          if !validated {
-            // --- Mutate nodes penalties count if not in consensus
-            SubnetNodePenalties::<T>::insert(subnet_id, account_id.clone(), penalties + 1);
-
-            // --- To be removed or increase absent count, the consensus threshold must be reached
-            if attestation_percentage > node_attestation_removal_threshold {
-              // We don't slash nodes for not being in consensus
-              // A node can be removed for any reason and may not be due to dishonesty
-              // If subnet validators want to remove and slash a node, they can use the proposals mechanism
-
-              // --- Ensure maximum sequential removal consensus threshold is reached
-              // We make sure the super majority are in agreeance to remove someone
-              // TODO: Check the size of subnet and scale it from there
-              if penalties + 1 > max_subnet_node_penalties {
-                // --- Increase account penalty count
-                // AccountPenaltyCount::<T>::mutate(account_id.clone(), |n: &mut u32| *n += 1);
-                Self::perform_remove_subnet_node(block, subnet_id, account_id.clone());
-              }
+        let penalties = SubnetNodePenalties::<T>::get(subnet_id, account_id.clone());
+
+        // --- If node not validated and consensus reached:
+        //      otherwise, increment penalty score only
+        //      remove them if max penalties threshold is reached
+        if !validated {
+          // --- Mutate nodes penalties count if not in consensus
+          SubnetNodePenalties::<T>::insert(subnet_id, account_id.clone(), penalties + 1);
+
+          // --- To be removed or increase absent count, the consensus threshold must be reached
+          if attestation_percentage > node_attestation_removal_threshold {
+            // We don't slash nodes for not being in consensus
+            // A node can be removed for any reason and may not be due to dishonesty
+            // If subnet validators want to remove and slash a node, they can use the proposals mechanism
+
+            // --- Ensure maximum sequential removal consensus threshold is reached
+            // We make sure the super majority are in agreeance to remove someone
+            // TODO: Check the size of subnet and scale it from there
+            if penalties + 1 > max_subnet_node_penalties {
+              // --- Increase account penalty count
+              Self::increase_account_penalty_count(&account_id);
+              Self::perform_remove_subnet_node(block, subnet_id, account_id.clone());
             }
-            // Even if there is a n-1 100% consensus on the node being out of consensus, we don't remove them.
-            // In the case where a subnet wants to remove a node, they should initiate a proposal to have them removed
-            // using ``propose``method
-            continue
           }
+          // Even if there is a n-1 100% consensus on the node being out of consensus, we don't remove them.
+          // In the case where a subnet wants to remove a node, they should initiate a proposal to have them removed
+          // using ``propose``method
+          continue
+        }
 
-          // --- At this point, a subnet node is in the consensus data
-
-          // --- Check if can be included in validation data
-          // By this point, node is validated, update to submittable if they have no penalties
-          let is_included = subnet_node.classification.class == SubnetNodeClass::Included;
-          if is_included && penalties == 0 {
-            // --- Upgrade to Submittable
-            SubnetNodesData::<T>::mutate(
-              subnet_id,
-              account_id.clone(),
-              |params: &mut SubnetNode<T::AccountId>| {
-                params.classification = SubnetNodeClassification {
-                  class: SubnetNodeClass::Submittable,
-                  start_epoch: (epoch) as u64, // in case rewards are called late, we add them to the next epoch, 2 from the consensus data
-                };
-              },
-            );
-            continue
-          } else if is_included && penalties != 0 {
-            // --- Decrease subnet node penalty count by one if in consensus and attested consensus
-            SubnetNodePenalties::<T>::mutate(subnet_id, account_id.clone(), |n: &mut u32| n.saturating_dec());
-            continue
-          }
+        // --- At this point, a subnet node is in the consensus data
+
+        // --- Check if can be included in validation data
+        // By this point, node is validated, update to submittable if they have no penalties
+        let is_included = subnet_node.classification.class == SubnetNodeClass::Included;
+        if is_included && penalties == 0 {
+          // --- Reactivated nodes return to their class before deactivation
+          let class: SubnetNodeClass = match DeactivateSubnetNodeLedger::<T>::try_get(subnet_id, account_id.clone()) {
+            Ok(previous) => {
+              DeactivateSubnetNodeLedger::<T>::remove(subnet_id, account_id.clone());
+              previous.classification.class
+            },
+            Err(()) => SubnetNodeClass::Submittable,
+          };
+
+          // --- Upgrade to Submittable
+          SubnetNodesData::<T>::mutate(
+            subnet_id,
+            account_id.clone(),
+            |params: &mut SubnetNode<T::AccountId>| {
+              params.classification = SubnetNodeClassification {
+                class: class,
+                start_epoch: (epoch) as u64, // in case rewards are called late, we add them to the next epoch, 2 from the consensus data
+              };
+            },
+          );
+          continue
+        } else if is_included && penalties != 0 {
+          // --- Decrease subnet node penalty count by one if in consensus and attested consensus
+          SubnetNodePenalties::<T>::mutate(subnet_id, account_id.clone(), |n: &mut u32| n.saturating_dec());
+          continue
+        }
+
+        // --- At this point, the subnet node is submittable and included in consensus data
 
-          // --- At this point, the subnet node is submittable and included in consensus data
+        //
+        // TODO: Test removing this ``!submission.attests.contains(&account_id)`` to allow those that do not attest to gain rewards
+        //
 
-          //
-          // TODO: Test removing this ``!submission.attests.contains(&account_id)`` to allow those that do not attest to gain rewards
-          //
+        // --- If not attested, do not receive rewards
+        // We don't penalize accounts for not attesting data in case data is corrupted
+        // It is up to subnet nodes to remove them via consensus
+        // But since consensus was formed at the least, we assume they're against the consensus, therefor likely dishonest
+        if !submission.attests.contains_key(&account_id) {
+          continue
+        }
 
-          // --- If not attested, do not receive rewards
-          // We don't penalize accounts for not attesting data in case data is corrupted
-          // It is up to subnet nodes to remove them via consensus
-          // But since consensus was formed at the least, we assume they're against the consensus, therefor likely dishonest
-          if !submission.attests.contains_key(&account_id) {
-            continue
-          }
+        let score = subnet_node_data.score;
 
-          let score = subnet_node_data.score;
+        // The subnet node has passed the gauntlet and is about to receive rewards
+        
+        // --- Decrease subnet node penalty count by one if in consensus and attested consensus
+        // Don't hit the storage unless we have to
+        if penalties != 0 {
+          SubnetNodePenalties::<T>::mutate(subnet_id, account_id.clone(), |n: &mut u32| n.saturating_dec());
+        }
 
-          // The subnet node has passed the gauntlet and is about to receive rewards
-          
-          // --- Decrease subnet node penalty count by one if in consensus and attested consensus
-          // Don't hit the storage unless we have to
-          if penalties != 0 {
-            SubnetNodePenalties::<T>::mutate(subnet_id, account_id.clone(), |n: &mut u32| n.saturating_dec());
+        // --- Calculate score percentage of peer versus sum
+        let score_percentage: u128 = Self::percent_div(subnet_node_data.score, sum as u128);
+        // --- Calculate score percentage of total subnet generated epoch rewards
+        let mut account_reward: u128 = Self::percent_mul(score_percentage, subnet_reward);
+
+        // --- Receipt holds the score reward, the validator reward is kept separately
+        if account_reward != 0 {
+          // Bound is the upper limit of ``MaxSubnetNodes``, any reward past it is still accounted for
+          if receipt.node_rewards.try_push(SubnetNodeReward {
+            account_id: account_id.clone(),
+            reward: account_reward,
+          }).is_err() {
+            receipt.unlisted_node_rewards = receipt.unlisted_node_rewards.saturating_add(account_reward);
           }
+        }
 
-          // --- Calculate score percentage of peer versus sum
-          let score_percentage: u128 = Self::percent_div(subnet_node_data.score, sum as u128);
-          // --- Calculate score percentage of total subnet generated epoch rewards
-          let mut account_reward: u128 = Self::percent_mul(score_percentage, subnet_reward);
+        // --- Increase reward if validator
+        if account_id == validator {
+          account_reward += validator_reward;
+          receipt.validator_reward = validator_reward;
+        }
 
-          // --- Increase reward if validator
-          if account_id == validator {
-            account_reward += Self::get_validator_reward(attestation_percentage);    
-          }
+        // --- Skip if no rewards to give
+        // Unlikely to happen
+        if account_reward == 0 {
+          continue
+        }
 
-          // --- Skip if no rewards to give
-          // Unlikely to happen
-          if account_reward == 0 {
-            continue
-          }
+        // --- Increase account stake and emit event
+        Self::increase_account_stake(
+          &account_id,
+          subnet_id, 
+          account_reward,
+        ); 
+        emitted = emitted.saturating_add(account_reward);
+      }
 
-          // --- Increase account stake and emit event
-          Self::increase_account_stake(
-            &account_id,
-            subnet_id, 
-            account_reward,
-          ); 
+      // --- Portion of rewards to delegate stakers
+      Self::do_increase_delegate_stake(
+        subnet_id,
+        delegate_stake_reward,
+      );
+      emitted = emitted.saturating_add(delegate_stake_reward);
+      receipt.delegate_stake_reward = delegate_stake_reward;
+
+      // --- Increment down subnet penalty score on successful epochs
+      SubnetPenaltyCount::<T>::mutate(subnet_id, |n: &mut u32| n.saturating_dec());
+
+      Self::record_rewards_receipt(subnet_id, epoch, receipt);
+    } else if let Ok(rewards_validator) = SubnetRewardsValidator::<T>::try_get(subnet_id, epoch) {
+      // --- If a validator has been chosen that means they are supposed to be submitting consensus data
+      //     since the subnet is past its MinRequiredSubnetConsensusSubmitEpochs
+      // --- If there is no submission but validator chosen, increase penalty on subnet and validator
+      // --- Increase the penalty count for the subnet
+      // The next validator on the next epoch can increment the penalty score down
+      SubnetPenaltyCount::<T>::mutate(subnet_id, |n: &mut u32| *n += 1);
+
+      // NOTE:
+      //  Each subnet increases the penalty score if they don't have the minimum subnet nodes required by the time
+      //  the subnet is enabled for emissions. This happens by the blockchain validator before choosing the subnet validator
+
+      // If validator didn't submit anything, then slash
+      // Even if a subnet is in a broken state, the chosen validator must submit blank data
+      let validator_slash: u128 = Self::slash_validator(subnet_id, rewards_validator.clone(), 0, block);
+
+      Self::record_rewards_receipt(
+        subnet_id,
+        epoch,
+        SubnetRewardsReceipt {
+          validator: rewards_validator,
+          validator_reward: 0,
+          node_rewards: BoundedVec::new(),
+          unlisted_node_rewards: 0,
+          delegate_stake_reward: 0,
+          validator_slash: validator_slash,
         }
+      );
+    }
 
-        // --- Portion of rewards to delegate stakers
-        Self::do_increase_delegate_stake(
-          subnet_id,
-          delegate_stake_reward,
-        );
+    // TODO: Automatically remove subnet if greater than max penalties count
+    // TODO: Get benchmark for removing max subnets in one epoch to ensure does not surpass max weights
 
-        // --- Increment down subnet penalty score on successful epochs
-        SubnetPenaltyCount::<T>::mutate(subnet_id, |n: &mut u32| n.saturating_dec());
-      } else if let Ok(rewards_validator) = SubnetRewardsValidator::<T>::try_get(subnet_id, epoch) {
-        // --- If a validator has been chosen that means they are supposed to be submitting consensus data
-        //     since the subnet is past its MinRequiredSubnetConsensusSubmitEpochs
-        // --- If there is no submission but validator chosen, increase penalty on subnet and validator
-        // --- Increase the penalty count for the subnet
-        // The next validator on the next epoch can increment the penalty score down
-        SubnetPenaltyCount::<T>::mutate(subnet_id, |n: &mut u32| *n += 1);
+    // --- If subnet is past its max penalty count, remove
+    let subnet_penalty_count = SubnetPenaltyCount::<T>::get(subnet_id);
+    if subnet_penalty_count > max_subnet_penalty_count {
+      Self::deactivate_subnet(
+        data.path.into(),
+        SubnetRemovalReason::MaxPenalties,
+      );
+    }
 
-        // NOTE:
-        //  Each subnet increases the penalty score if they don't have the minimum subnet nodes required by the time
-        //  the subnet is enabled for emissions. This happens by the blockchain validator before choosing the subnet validator
+    emitted
+  }
 
-        // If validator didn't submit anything, then slash
-        // Even if a subnet is in a broken state, the chosen validator must submit blank data
-        Self::slash_validator(subnet_id, rewards_validator, 0, block);
+  /// Store the subnets rewards receipt of the epoch and prune the receipts past ``RewardsReceiptRetentionEpochs``
+  fn record_rewards_receipt(subnet_id: u32, epoch: u32, receipt: SubnetRewardsReceipt<T::AccountId>) {
+    let node_rewards: u128 = receipt.node_rewards
+      .iter()
+      .fold(receipt.unlisted_node_rewards, |acc, x| acc.saturating_add(x.reward));
+
+    Self::deposit_event(
+      Event::RewardsDistributed { 
+        subnet_id: subnet_id, 
+        epoch: epoch, 
+        node_rewards: node_rewards,
+        validator_reward: receipt.validator_reward,
+        delegate_stake_reward: receipt.delegate_stake_reward,
+        slashed: receipt.validator_slash,
       }
+    );
 
-      // TODO: Automatically remove subnet if greater than max penalties count
-      // TODO: Get benchmark for removing max subnets in one epoch to ensure does not surpass max weights
+    SubnetRewardsReceipts::<T>::insert(subnet_id, epoch, receipt);
 
-      // --- If subnet is past its max penalty count, remove
-      let subnet_penalty_count = SubnetPenaltyCount::<T>::get(subnet_id);
-      if subnet_penalty_count > max_subnet_penalty_count {
-        Self::deactivate_subnet(
-          data.path,
-          SubnetRemovalReason::MaxPenalties,
-        );
+    Self::prune_rewards_receipts(subnet_id, epoch);
+  }
+
+  /// Prune the subnets receipts older than ``RewardsReceiptRetentionEpochs`` as of the epoch
+  //
+  // Epochs are pruned in order from the oldest that may have a receipt, so receipts left behind by skipped
+  // epochs or a lowered retention are pruned, up to ``DefaultRewardsReceiptsPrunedPerEpoch`` per call
+  fn prune_rewards_receipts(subnet_id: u32, epoch: u32) {
+    let retention_epochs: u32 = RewardsReceiptRetentionEpochs::<T>::get();
+    // --- Receipts before this epoch are past the retention
+    let retained_epoch: u32 = epoch.saturating_add(1).saturating_sub(retention_epochs);
+
+    // --- No receipt is older than the subnets first
+    let mut prune_epoch: u32 = SubnetRewardsReceiptsPruneEpoch::<T>::get(subnet_id).unwrap_or(epoch);
+    let end_epoch: u32 = retained_epoch.min(
+      prune_epoch.saturating_add(DefaultRewardsReceiptsPrunedPerEpoch::get())
+    );
+
+    while prune_epoch < end_epoch {
+      SubnetRewardsReceipts::<T>::remove(subnet_id, prune_epoch);
+      prune_epoch += 1;
+    }
+
+    SubnetRewardsReceiptsPruneEpoch::<T>::insert(subnet_id, prune_epoch);
+  }
+
+  /// Emission budget of an epoch, the balance accrued in the stake vault from block subsidies,
+  /// registration fees and any rolled over budget
+  pub fn get_epoch_emission_budget() -> u128 {
+    StakeVaultBalance::<T>::get()
+  }
+
+  /// Emission shares of subnets eligible for emissions on the epoch as a percentage of the budget
+  //
+  // A subnet is eligible once a validator has been chosen for the epoch in ``do_epoch_preliminaries``
+  // and it isn't paused
+  // Eligible subnets that are broken or fail attestation receive no emissions and leave their share unpaid
+  //
+  // Each share combines the subnets portion of the eligible memory and of the eligible delegate stake,
+  // weighted by ``StakeRewardWeight``, and is capped at ``MaxSubnetRewardsWeight``
+  pub fn get_subnet_emission_shares(epoch: u32) -> BTreeMap<u32, u128> {
+    let mut subnets: Vec<(u32, u128, u128)> = Vec::new();
+    let mut total_memory_mb: u128 = 0;
+    let mut total_delegate_stake: u128 = 0;
+    for (subnet_id, data) in SubnetsData::<T>::iter() {
+      if !SubnetRewardsValidator::<T>::contains_key(subnet_id, epoch) || data.paused != 0 {
+        continue
       }
+      let delegate_stake = TotalSubnetDelegateStakeBalance::<T>::get(subnet_id);
+      total_memory_mb = total_memory_mb.saturating_add(data.memory_mb);
+      total_delegate_stake = total_delegate_stake.saturating_add(delegate_stake);
+      subnets.push((subnet_id, data.memory_mb, delegate_stake));
     }
 
-    Ok(None.into())
+    // --- If no eligible subnet has delegate stake, shares are by memory only
+    let stake_reward_weight: u128 = if total_delegate_stake == 0 {
+      0
+    } else {
+      StakeRewardWeight::<T>::get()
+    };
+    let max_subnet_rewards_weight: u128 = MaxSubnetRewardsWeight::<T>::get();
+
+    let mut shares: BTreeMap<u32, u128> = BTreeMap::new();
+    for (subnet_id, memory_mb, delegate_stake) in subnets {
+      let memory_share: u128 = Self::get_share(memory_mb, total_memory_mb);
+      let stake_share: u128 = Self::get_share(delegate_stake, total_delegate_stake);
+      let share: u128 = Self::percent_mul(memory_share, Self::PERCENTAGE_FACTOR - stake_reward_weight)
+        .saturating_add(Self::percent_mul(stake_share, stake_reward_weight));
+      shares.insert(subnet_id, share.min(max_subnet_rewards_weight));
+    }
+    shares
+  }
+
+  /// Portion of ``total`` as a percentage
+  fn get_share(value: u128, total: u128) -> u128 {
+    if total == 0 {
+      return 0
+    }
+    multiply_by_rational_with_rounding(Self::PERCENTAGE_FACTOR, value, total, Rounding::Down).unwrap_or(0)
+  }
+
+  /// A subnets emission from its share of the budget
+  pub fn get_subnet_emission(budget: u128, share: u128) -> u128 {
+    multiply_by_rational_with_rounding(budget, share, Self::PERCENTAGE_FACTOR, Rounding::Down).unwrap_or(0)
   }
-}
\ No newline at end of file
+}
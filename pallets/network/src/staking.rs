@@ -176,6 +176,30 @@ impl<T: Config> Pallet<T> {
     Ok(())
   }
 
+  /// Infallible version of ``add_balance_to_stake_unbonding_ledger`` used when the stake is released by the network
+  // If the account already unbonded this epoch or the ledger is full, the amount is merged into the latest unbonding
+  pub fn force_add_balance_to_stake_unbonding_ledger(
+    account_id: &T::AccountId,
+    subnet_id: u32, 
+    amount: u128,
+    block: u64,
+  ) {
+    let epoch_length: u64 = T::EpochLength::get();
+    let epoch: u64 = block / epoch_length;
+
+    if SubnetStakeUnbondingLedger::<T>::get(account_id.clone(), subnet_id).len() as u32 >= T::MaxStakeUnlockings::get() {
+      Self::do_claim_stake_unbondings(&account_id, subnet_id);
+    }
+
+    SubnetStakeUnbondingLedger::<T>::mutate(account_id.clone(), subnet_id, |unbondings| {
+      let key: u64 = match unbondings.last_key_value() {
+        Some((last_epoch, _)) if unbondings.len() as u32 >= T::MaxStakeUnlockings::get() => *last_epoch,
+        _ => epoch,
+      };
+      unbondings.entry(key).or_insert(0).saturating_accrue(amount);
+    });
+  }
+
   // Infallible
   pub fn do_claim_stake_unbondings(account_id: &T::AccountId, subnet_id: u32) -> u32 {
     let block: u64 = Self::get_current_block_as_u64();
@@ -215,8 +239,12 @@ impl<T: Config> Pallet<T> {
     subnet_id: u32, 
     amount: u128,
   ) {
+    Self::snapshot_account_subnet_stake(account_id, subnet_id);
+    Self::snapshot_subnet_stake(subnet_id);
+
     // -- increase account subnet staking balance
     AccountSubnetStake::<T>::mutate(account_id, subnet_id, |mut n| n.saturating_accrue(amount));
+    SubnetStakeAccounts::<T>::insert(subnet_id, account_id, ());
 
     // -- increase account_id total stake
     TotalAccountStake::<T>::mutate(account_id, |mut n| n.saturating_accrue(amount));
@@ -233,8 +261,14 @@ impl<T: Config> Pallet<T> {
     subnet_id: u32, 
     amount: u128,
   ) {
+    Self::snapshot_account_subnet_stake(account_id, subnet_id);
+    Self::snapshot_subnet_stake(subnet_id);
+
     // -- decrease account subnet staking balance
     AccountSubnetStake::<T>::mutate(account_id, subnet_id, |mut n| n.saturating_reduce(amount));
+    if AccountSubnetStake::<T>::get(account_id, subnet_id) == 0 {
+      SubnetStakeAccounts::<T>::remove(subnet_id, account_id);
+    }
 
     // -- decrease account_id total stake
     TotalAccountStake::<T>::mutate(account_id, |mut n| n.saturating_reduce(amount));
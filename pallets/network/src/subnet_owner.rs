@@ -0,0 +1,277 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<T: Config> Pallet<T> {
+  pub fn ensure_subnet_owner(account_id: &T::AccountId, subnet_id: u32) -> DispatchResult {
+    ensure!(
+      SubnetsData::<T>::contains_key(subnet_id),
+      Error::<T>::SubnetNotExist
+    );
+
+    ensure!(
+      SubnetOwner::<T>::get(subnet_id).as_ref() == Some(account_id),
+      Error::<T>::NotSubnetOwner
+    );
+
+    Ok(())
+  }
+
+  /// Reserve the ``SubnetOwnerDeposit`` from the owner of a newly registered subnet
+  pub fn reserve_subnet_owner_deposit(owner: &T::AccountId, subnet_id: u32) -> DispatchResult {
+    let deposit: u128 = SubnetOwnerDeposit::<T>::get();
+    if deposit == 0 {
+      return Ok(())
+    }
+
+    let deposit_as_balance = match Self::u128_to_balance(deposit) {
+      Some(deposit_as_balance) => deposit_as_balance,
+      None => return Err(Error::<T>::CouldNotConvertToBalance.into()),
+    };
+
+    T::Currency::reserve(owner, deposit_as_balance).map_err(|_| Error::<T>::NotEnoughBalanceToStake)?;
+
+    SubnetOwnerDeposits::<T>::insert(subnet_id, (owner.clone(), deposit));
+
+    Ok(())
+  }
+
+  /// Return the registration deposit of a removed subnet to the account that paid it
+  // The deposit stays with the depositor when ownership is transferred or renounced
+  pub fn refund_subnet_owner_deposit(subnet_id: u32) {
+    let (depositor, deposit) = match SubnetOwnerDeposits::<T>::take(subnet_id) {
+      Some(deposit) => deposit,
+      None => return,
+    };
+
+    if let Some(deposit_as_balance) = Self::u128_to_balance(deposit) {
+      T::Currency::unreserve(&depositor, deposit_as_balance);
+    }
+
+    Self::deposit_event(Event::SubnetOwnerDepositRefunded { subnet_id: subnet_id, account_id: depositor, amount: deposit });
+  }
+
+  /// Update the subnet parameters the owner is allowed to manage
+  //
+  // ``memory_mb`` changes the network memory and the registration cost and remains under subnet democracy
+  pub fn do_owner_update_subnet(
+    account_id: T::AccountId,
+    subnet_id: u32,
+    update: SubnetUpdateData,
+  ) -> DispatchResult {
+    Self::ensure_subnet_owner(&account_id, subnet_id)?;
+
+    ensure!(
+      update.memory_mb.is_none(),
+      Error::<T>::SubnetOwnerUpdateNotAllowed
+    );
+
+    Self::do_update_subnet(subnet_id, update)
+  }
+
+  /// Set the accounts allowed to register subnet nodes while the subnet is registering
+  /// Anyone can register if both the account and peer ID whitelists are empty
+  pub fn do_set_subnet_registration_whitelist(
+    account_id: T::AccountId,
+    subnet_id: u32,
+    whitelist: BTreeSet<T::AccountId>,
+  ) -> DispatchResult {
+    Self::ensure_subnet_owner(&account_id, subnet_id)?;
+    Self::ensure_subnet_registration_whitelist_editable(subnet_id)?;
+    Self::ensure_subnet_registration_whitelist_len(whitelist.len())?;
+
+    Self::insert_subnet_registration_whitelist(subnet_id, whitelist);
+
+    Ok(())
+  }
+
+  /// Set the peer IDs allowed to register subnet nodes while the subnet is registering
+  /// Anyone can register if both the account and peer ID whitelists are empty
+  pub fn do_set_subnet_registration_peer_whitelist(
+    account_id: T::AccountId,
+    subnet_id: u32,
+    whitelist: BTreeSet<PeerId>,
+  ) -> DispatchResult {
+    Self::ensure_subnet_owner(&account_id, subnet_id)?;
+    Self::ensure_subnet_registration_whitelist_editable(subnet_id)?;
+    Self::ensure_subnet_registration_peer_whitelist_valid(&whitelist)?;
+
+    Self::insert_subnet_registration_peer_whitelist(subnet_id, whitelist);
+
+    Ok(())
+  }
+
+  /// Register a subnet and set its registration whitelists in one step, ahead of any subnet node registering
+  pub fn do_register_subnet_with_whitelist(
+    account_id: T::AccountId,
+    subnet_data: RegistrationSubnetData<T::MaxPathLength>,
+    whitelist: BTreeSet<T::AccountId>,
+    peer_whitelist: BTreeSet<PeerId>,
+  ) -> DispatchResult {
+    Self::ensure_subnet_registration_whitelist_len(whitelist.len())?;
+    Self::ensure_subnet_registration_peer_whitelist_valid(&peer_whitelist)?;
+
+    let path: Vec<u8> = subnet_data.path.to_vec();
+    Self::do_register_subnet(account_id, subnet_data)?;
+
+    let subnet_id: u32 = SubnetPaths::<T>::get(path).ok_or(Error::<T>::SubnetNotExist)?;
+
+    if !whitelist.is_empty() {
+      Self::insert_subnet_registration_whitelist(subnet_id, whitelist);
+    }
+    if !peer_whitelist.is_empty() {
+      Self::insert_subnet_registration_peer_whitelist(subnet_id, peer_whitelist);
+    }
+
+    Ok(())
+  }
+
+  /// Keep the registration whitelists of a subnet after activation, or open registration if disabled
+  /// on an activated subnet
+  pub fn do_set_subnet_permissioned(subnet_id: u32, permissioned: bool) -> DispatchResult {
+    let subnet = SubnetsData::<T>::get(subnet_id).ok_or(Error::<T>::SubnetNotExist)?;
+
+    if permissioned {
+      SubnetPermissioned::<T>::insert(subnet_id, true);
+    } else {
+      SubnetPermissioned::<T>::remove(subnet_id);
+      if subnet.activated != 0 {
+        Self::lift_subnet_registration_whitelist(subnet_id);
+      }
+    }
+
+    Self::deposit_event(Event::SubnetPermissionedUpdated { subnet_id: subnet_id, permissioned: permissioned });
+
+    Ok(())
+  }
+
+  /// Remove the registration whitelists of a subnet, allowing anyone to register subnet nodes
+  pub fn lift_subnet_registration_whitelist(subnet_id: u32) {
+    let whitelisted: bool = SubnetRegistrationWhitelist::<T>::contains_key(subnet_id) || 
+      SubnetRegistrationPeerWhitelist::<T>::contains_key(subnet_id);
+
+    if !whitelisted {
+      return
+    }
+
+    SubnetRegistrationWhitelist::<T>::remove(subnet_id);
+    SubnetRegistrationPeerWhitelist::<T>::remove(subnet_id);
+
+    Self::deposit_event(Event::SubnetRegistrationWhitelistLifted { subnet_id: subnet_id });
+  }
+
+  /// Whether ``account_id`` or ``peer_id`` can register a subnet node based on the subnets registration whitelists
+  pub fn is_subnet_registration_whitelisted(subnet: &SubnetData<T::MaxPathLength>, account_id: &T::AccountId, peer_id: &PeerId) -> bool {
+    if subnet.activated != 0 && !SubnetPermissioned::<T>::get(subnet.id) {
+      return true
+    }
+    let whitelist: BTreeSet<T::AccountId> = SubnetRegistrationWhitelist::<T>::get(subnet.id);
+    let peer_whitelist: BTreeSet<PeerId> = SubnetRegistrationPeerWhitelist::<T>::get(subnet.id);
+    if whitelist.is_empty() && peer_whitelist.is_empty() {
+      return true
+    }
+    whitelist.contains(account_id) || peer_whitelist.contains(peer_id)
+  }
+
+  /// Registration whitelists can only be updated while the subnet is registering, or if it's permissioned
+  fn ensure_subnet_registration_whitelist_editable(subnet_id: u32) -> DispatchResult {
+    let subnet = SubnetsData::<T>::get(subnet_id).ok_or(Error::<T>::SubnetNotExist)?;
+
+    ensure!(
+      subnet.activated == 0 || SubnetPermissioned::<T>::get(subnet_id),
+      Error::<T>::SubnetActivatedAlready
+    );
+
+    Ok(())
+  }
+
+  fn ensure_subnet_registration_whitelist_len(len: usize) -> DispatchResult {
+    ensure!(
+      len as u32 <= MaxSubnetNodes::<T>::get(),
+      Error::<T>::InvalidSubnetRegistrationWhitelist
+    );
+
+    Ok(())
+  }
+
+  fn ensure_subnet_registration_peer_whitelist_valid(whitelist: &BTreeSet<PeerId>) -> DispatchResult {
+    Self::ensure_subnet_registration_whitelist_len(whitelist.len())?;
+
+    ensure!(
+      whitelist.iter().all(|peer_id| Self::validate_peer_id(peer_id.clone())),
+      Error::<T>::InvalidSubnetRegistrationWhitelist
+    );
+
+    Ok(())
+  }
+
+  fn insert_subnet_registration_whitelist(subnet_id: u32, whitelist: BTreeSet<T::AccountId>) {
+    if whitelist.is_empty() {
+      SubnetRegistrationWhitelist::<T>::remove(subnet_id);
+    } else {
+      SubnetRegistrationWhitelist::<T>::insert(subnet_id, whitelist.clone());
+    }
+
+    Self::deposit_event(Event::SubnetRegistrationWhitelistUpdated { subnet_id: subnet_id, whitelist: whitelist });
+  }
+
+  fn insert_subnet_registration_peer_whitelist(subnet_id: u32, whitelist: BTreeSet<PeerId>) {
+    if whitelist.is_empty() {
+      SubnetRegistrationPeerWhitelist::<T>::remove(subnet_id);
+    } else {
+      SubnetRegistrationPeerWhitelist::<T>::insert(subnet_id, whitelist.clone());
+    }
+
+    Self::deposit_event(Event::SubnetRegistrationPeerWhitelistUpdated { subnet_id: subnet_id, whitelist: whitelist });
+  }
+
+  pub fn do_owner_set_subnet_metadata(
+    account_id: T::AccountId,
+    subnet_id: u32,
+    metadata: SubnetMetadata,
+  ) -> DispatchResult {
+    Self::ensure_subnet_owner(&account_id, subnet_id)?;
+    Self::do_set_subnet_metadata(subnet_id, metadata)
+  }
+
+  pub fn do_transfer_subnet_ownership(
+    account_id: T::AccountId,
+    subnet_id: u32,
+    new_owner: T::AccountId,
+  ) -> DispatchResult {
+    Self::ensure_subnet_owner(&account_id, subnet_id)?;
+
+    SubnetOwner::<T>::insert(subnet_id, new_owner.clone());
+
+    Self::deposit_event(Event::SubnetOwnershipTransferred { subnet_id: subnet_id, from: account_id, to: new_owner });
+
+    Ok(())
+  }
+
+  /// Leave the subnet without an owner, only governance can manage it afterwards
+  pub fn do_renounce_subnet_ownership(
+    account_id: T::AccountId,
+    subnet_id: u32,
+  ) -> DispatchResult {
+    Self::ensure_subnet_owner(&account_id, subnet_id)?;
+
+    SubnetOwner::<T>::remove(subnet_id);
+
+    Self::deposit_event(Event::SubnetOwnershipRenounced { subnet_id: subnet_id, owner: account_id });
+
+    Ok(())
+  }
+}
@@ -0,0 +1,208 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<T: Config> Pallet<T> {
+  pub fn is_subnet_paused(subnet_id: u32) -> bool {
+    match SubnetsData::<T>::try_get(subnet_id) {
+      Ok(data) => data.paused != 0,
+      Err(()) => false,
+    }
+  }
+
+  /// Pause a subnet on behalf of its owner, reserving the ``SubnetPauseBond``
+  pub fn do_owner_pause_subnet(account_id: T::AccountId, subnet_id: u32) -> DispatchResult {
+    Self::ensure_subnet_owner(&account_id, subnet_id)?;
+    Self::ensure_subnet_pausable(subnet_id)?;
+
+    let bond: u128 = SubnetPauseBond::<T>::get();
+    if bond > 0 {
+      let bond_as_balance = match Self::u128_to_balance(bond) {
+        Some(bond_as_balance) => bond_as_balance,
+        None => return Err(Error::<T>::CouldNotConvertToBalance.into()),
+      };
+
+      T::Currency::reserve(&account_id, bond_as_balance).map_err(|_| Error::<T>::NotEnoughBalanceToStake)?;
+    }
+
+    // --- Inserted even without a bond so the owner can unpause the subnet
+    SubnetPauseBonds::<T>::insert(subnet_id, (account_id.clone(), bond));
+
+    Self::pause_subnet(subnet_id, Some(account_id));
+
+    Ok(())
+  }
+
+  /// Unpause a subnet paused by its owner, a subnet paused by the council can only be unpaused by the council
+  //
+  // The subnet must stay paused for ``MinSubnetPauseEpochs`` so delegate stakers exiting without the cooldown
+  // can't be followed by an unpause in the same block
+  pub fn do_owner_unpause_subnet(account_id: T::AccountId, subnet_id: u32) -> DispatchResult {
+    Self::ensure_subnet_owner(&account_id, subnet_id)?;
+
+    let paused: u64 = match SubnetsData::<T>::try_get(subnet_id) {
+      Ok(data) => data.paused,
+      Err(()) => 0,
+    };
+
+    ensure!(
+      paused != 0,
+      Error::<T>::SubnetNotPaused
+    );
+
+    ensure!(
+      SubnetPauseBonds::<T>::contains_key(subnet_id),
+      Error::<T>::SubnetPausedByCouncil
+    );
+
+    let block: u64 = Self::get_current_block_as_u64();
+    let paused_epochs: u64 = block.saturating_sub(paused) / T::EpochLength::get();
+    ensure!(
+      paused_epochs >= MinSubnetPauseEpochs::<T>::get(),
+      Error::<T>::SubnetPauseTooShort
+    );
+
+    Self::unpause_subnet(subnet_id);
+
+    Ok(())
+  }
+
+  /// Pause or unpause a subnet through the council, unpausing returns the owners pause bond if any
+  pub fn do_council_set_subnet_paused(subnet_id: u32, paused: bool) -> DispatchResult {
+    ensure!(
+      SubnetsData::<T>::contains_key(subnet_id),
+      Error::<T>::SubnetNotExist
+    );
+
+    if paused {
+      Self::ensure_subnet_pausable(subnet_id)?;
+      Self::pause_subnet(subnet_id, None);
+    } else {
+      ensure!(
+        Self::is_subnet_paused(subnet_id),
+        Error::<T>::SubnetNotPaused
+      );
+      Self::unpause_subnet(subnet_id);
+    }
+
+    Ok(())
+  }
+
+  fn ensure_subnet_pausable(subnet_id: u32) -> DispatchResult {
+    let data = match SubnetsData::<T>::try_get(subnet_id) {
+      Ok(data) => data,
+      Err(()) => return Err(Error::<T>::SubnetNotExist.into()),
+    };
+
+    ensure!(
+      data.activated != 0,
+      Error::<T>::SubnetNotActivated
+    );
+
+    ensure!(
+      data.paused == 0,
+      Error::<T>::SubnetIsPaused
+    );
+
+    Ok(())
+  }
+
+  fn pause_subnet(subnet_id: u32, account_id: Option<T::AccountId>) {
+    let block: u64 = Self::get_current_block_as_u64();
+
+    SubnetsData::<T>::mutate(subnet_id, |maybe_data| {
+      if let Some(data) = maybe_data {
+        data.paused = block;
+      }
+    });
+
+    Self::deposit_event(Event::SubnetPaused { subnet_id: subnet_id, account_id: account_id });
+  }
+
+  fn unpause_subnet(subnet_id: u32) {
+    SubnetsData::<T>::mutate(subnet_id, |maybe_data| {
+      if let Some(data) = maybe_data {
+        data.paused = 0;
+      }
+    });
+
+    Self::refund_subnet_pause_bond(subnet_id);
+
+    Self::deposit_event(Event::SubnetUnpaused { subnet_id: subnet_id });
+  }
+
+  /// Return the pause bond of a subnet to the account that paid it
+  pub fn refund_subnet_pause_bond(subnet_id: u32) {
+    let (depositor, bond) = match SubnetPauseBonds::<T>::take(subnet_id) {
+      Some(bond) => bond,
+      None => return,
+    };
+
+    if bond == 0 {
+      return
+    }
+
+    if let Some(bond_as_balance) = Self::u128_to_balance(bond) {
+      T::Currency::unreserve(&depositor, bond_as_balance);
+    }
+
+    Self::deposit_event(Event::SubnetPauseBondRefunded { subnet_id: subnet_id, account_id: depositor, amount: bond });
+  }
+
+  /// Slash the pause bond of a subnet, a portion goes to the treasury and the rest is burned
+  pub fn forfeit_subnet_pause_bond(subnet_id: u32) {
+    let (depositor, bond) = match SubnetPauseBonds::<T>::take(subnet_id) {
+      Some(bond) => bond,
+      None => return,
+    };
+
+    if bond == 0 {
+      return
+    }
+
+    if let Some(bond_as_balance) = Self::u128_to_balance(bond) {
+      let _ = T::Currency::slash_reserved(&depositor, bond_as_balance);
+    }
+
+    Self::deposit_treasury_share(bond, TreasurySplits::<T>::get().slash);
+
+    Self::deposit_event(Event::SubnetPauseBondForfeited { subnet_id: subnet_id, account_id: depositor, amount: bond });
+  }
+
+  /// Epoch preliminaries of a paused subnet, no validator is chosen and penalties aren't counted
+  //
+  // Once paused longer than ``MaxSubnetPauseEpochs`` the subnet is unpaused if it has the minimum
+  // submittable subnet nodes, otherwise the pause bond is forfeited and the subnet is removed
+  pub fn do_paused_subnet_epoch_preliminaries(block: u64, epoch: u32, data: SubnetData<T::MaxPathLength>) {
+    let epoch_length: u64 = T::EpochLength::get();
+    let paused_epochs: u64 = block.saturating_sub(data.paused) / epoch_length;
+    if paused_epochs <= MaxSubnetPauseEpochs::<T>::get() {
+      return
+    }
+
+    let subnet_nodes_count = Self::get_classified_accounts(data.id, &SubnetNodeClass::Submittable, epoch as u64).len();
+    if subnet_nodes_count as u32 >= data.min_nodes {
+      Self::unpause_subnet(data.id);
+      return
+    }
+
+    Self::forfeit_subnet_pause_bond(data.id);
+    Self::deactivate_subnet(
+      data.path.into(),
+      SubnetRemovalReason::MaxPauseLength,
+    );
+  }
+}
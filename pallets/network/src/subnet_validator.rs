@@ -208,7 +208,8 @@ impl<T: Config> Pallet<T> {
     Self::percent_mul(BaseValidatorReward::<T>::get(), attestation_percentage)
   }
 
-  pub fn slash_validator(subnet_id: u32, validator: T::AccountId, attestation_percentage: u128, block: u64) {
+  /// Slash the validator and increase their penalty count, returns the amount slashed
+  pub fn slash_validator(subnet_id: u32, validator: T::AccountId, attestation_percentage: u128, block: u64) -> u128 {
     // We never ensure balance is above 0 because any validator chosen must have the target stake
     // balance at a minimum
 
@@ -234,9 +235,11 @@ impl<T: Config> Pallet<T> {
       slash_amount,
     );
 
+    // --- Send portion of the slash to treasury, the rest is burned
+    Self::deposit_treasury_share(slash_amount, TreasurySplits::<T>::get().slash);
+
     // --- Increase validator penalty count
-    // AccountPenaltyCount::<T>::mutate(validator.clone(), |n: &mut u32| *n += 1);
-    // SubnetNodePenalties::<T>::mutate(subnet_id, validator.clone(), |n: &mut u32| *n += 1);
+    Self::increase_account_penalty_count(&validator);
 
     let penalties = SubnetNodePenalties::<T>::get(subnet_id, validator.clone());
     SubnetNodePenalties::<T>::insert(subnet_id, validator.clone(), penalties + 1);
@@ -244,9 +247,8 @@ impl<T: Config> Pallet<T> {
     // --- Ensure maximum sequential removal consensus threshold is reached
     if penalties + 1 > MaxSubnetNodePenalties::<T>::get() {
       // --- Increase account penalty count
+      Self::increase_account_penalty_count(&validator);
       Self::perform_remove_subnet_node(block, subnet_id, validator.clone());
-    } else {
-      
     }
 
     Self::deposit_event(
@@ -257,6 +259,7 @@ impl<T: Config> Pallet<T> {
       }
     );
 
+    slash_amount
   }
 
   /// Increase a subnet nodes classification
@@ -0,0 +1,77 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::Imbalance;
+use sp_runtime::traits::AccountIdConversion;
+
+impl<T: Config> Pallet<T> {
+  /// Treasury account, a sub account of the pallets ``PalletId``
+  pub fn treasury_account() -> T::AccountId {
+    T::PalletId::get().into_sub_account_truncating(b"treasury")
+  }
+
+  pub fn get_treasury_balance() -> u128 {
+    let balance = Self::get_coldkey_balance(&Self::treasury_account());
+    balance.try_into().unwrap_or(0)
+  }
+
+  /// Deposit ``percentage`` of ``amount`` into the treasury
+  ///
+  /// Returns the amount deposited, the caller is responsible for the remainder
+  //
+  // Shares below the existential deposit of a reaped treasury account are not deposited and ``0`` is returned
+  pub fn deposit_treasury_share(amount: u128, percentage: u128) -> u128 {
+    let share: u128 = Self::percent_mul(amount, percentage);
+    if share == 0 {
+      return 0
+    }
+
+    let share_as_balance = match Self::u128_to_balance(share) {
+      Some(share_as_balance) => share_as_balance,
+      None => return 0,
+    };
+
+    let imbalance = T::Currency::deposit_creating(&Self::treasury_account(), share_as_balance);
+    let deposited: u128 = imbalance.peek().try_into().unwrap_or(0);
+
+    if deposited > 0 {
+      Self::deposit_event(Event::TreasuryDeposited { amount: deposited });
+    }
+
+    deposited
+  }
+
+  /// Transfer ``amount`` from the treasury to ``beneficiary``, called by the council
+  pub fn do_treasury_spend(beneficiary: T::AccountId, amount: u128) -> DispatchResult {
+    let amount_as_balance = Self::u128_to_balance(amount);
+
+    ensure!(
+      amount_as_balance.is_some(),
+      Error::<T>::CouldNotConvertToBalance
+    );
+
+    T::Currency::transfer(
+      &Self::treasury_account(),
+      &beneficiary,
+      amount_as_balance.unwrap(),
+      ExistenceRequirement::AllowDeath,
+    ).map_err(|_| Error::<T>::InsufficientTreasuryBalance)?;
+
+    Self::deposit_event(Event::TreasurySpent { beneficiary, amount });
+
+    Ok(())
+  }
+}
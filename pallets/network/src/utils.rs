@@ -15,7 +15,9 @@
 
 use super::*;
 use frame_system::pallet_prelude::BlockNumberFor;
+use frame_support::weights::Weight;
 use libm::exp;
+use sp_runtime::{helpers_128bit::multiply_by_rational_with_rounding, Rounding};
 
 impl<T: Config> Pallet<T> {
   pub fn get_current_block_as_u64() -> u64 {
@@ -70,6 +72,42 @@ impl<T: Config> Pallet<T> {
     penalties <= max_account_penalty_count
   }
 
+  pub fn increase_account_penalty_count(account_id: &T::AccountId) {
+    let penalties: u32 = AccountPenaltyCount::<T>::mutate(account_id, |n: &mut u32| {
+      *n = n.saturating_add(1);
+      *n
+    });
+
+    Self::deposit_event(Event::AccountPenalized { account_id: account_id.clone(), penalties: penalties });
+  }
+
+  /// Remove the subnet nodes of an account from all subnets and ban the account for ``AccountPenaltyBanEpochs``
+  // Bounded by ``MaxSubnets``
+  pub fn do_remove_account_subnet_nodes(block: u64, account_id: T::AccountId) {
+    let mut removed: u32 = 0;
+    for subnet_id in SubnetsData::<T>::iter_keys() {
+      if SubnetNodesData::<T>::contains_key(subnet_id, account_id.clone()) {
+        Self::perform_remove_subnet_node(block, subnet_id, account_id.clone());
+        removed += 1;
+      }
+    }
+
+    let epoch: u64 = block / T::EpochLength::get();
+    let banned_until_epoch: u64 = epoch.saturating_add(AccountPenaltyBanEpochs::<T>::get());
+    AccountBans::<T>::insert(account_id.clone(), banned_until_epoch);
+
+    // --- The account starts over once the ban ends
+    AccountPenaltyCount::<T>::remove(account_id.clone());
+
+    Self::deposit_event(
+      Event::AccountSubnetNodesRemoved { 
+        account_id: account_id, 
+        removed: removed, 
+        banned_until_epoch: banned_until_epoch,
+      }
+    );
+  }
+
   pub fn is_subnet_node_eligible(subnet_node: u32, account_id: T::AccountId) -> bool {
     let max_subnet_node_penalties = MaxSubnetNodePenalties::<T>::get();
     let penalties = SubnetNodePenalties::<T>::get(subnet_node, account_id);
@@ -122,7 +160,7 @@ impl<T: Config> Pallet<T> {
     // Take and remove SubnetNodesData account_id as key
     // `take()` returns and removes data
     if let Ok(subnet_node) = SubnetNodesData::<T>::try_get(subnet_id, account_id.clone()) {
-      let peer_id = subnet_node.peer_id;
+      let peer_id = subnet_node.peer_id.clone();
 
       // Remove from attestations
       let epoch_length: u64 = T::EpochLength::get();
@@ -167,11 +205,21 @@ impl<T: Config> Pallet<T> {
 
       SubnetNodesData::<T>::remove(subnet_id, account_id.clone());
 
+      // Release the nodes unique ``a`` parameter
+      if !subnet_node.a.is_empty() {
+        SubnetNodeParam::<T>::remove(subnet_id, subnet_node.a.clone());
+      }
+      SubnetNodeParamsLastUpdate::<T>::remove(subnet_id, account_id.clone());
+      DeactivateSubnetNodeLedger::<T>::remove(subnet_id, account_id.clone());
+
       // Remove SubnetNodeAccount peer_id as key
       SubnetNodeAccount::<T>::remove(subnet_id, peer_id.clone());
       // Update total subnet peers by substracting 1
       TotalSubnetNodes::<T>::mutate(subnet_id, |n: &mut u32| n.saturating_dec());
-      TotalActiveSubnetNodes::<T>::mutate(subnet_id, |n: &mut u32| n.saturating_dec());
+      // Deactivated subnet nodes are already subtracted
+      if subnet_node.classification.class != SubnetNodeClass::Deactivated {
+        TotalActiveSubnetNodes::<T>::mutate(subnet_id, |n: &mut u32| n.saturating_dec());
+      }
 
       // Reset sequential absent subnet node count
       SubnetNodePenalties::<T>::remove(subnet_id, account_id.clone());
@@ -181,10 +229,212 @@ impl<T: Config> Pallet<T> {
   }
 
 
+  /// Continue the teardown of a deactivated subnet, processing up to ``limit`` storage items
+  ///
+  /// Node and delegate stake is moved into the unbonding ledgers, which are kept for stakers to claim, the bonds
+  /// of unsettled proposals are returned, then all subnet prefixed storage is removed
   pub fn do_clear_subnet(
     subnet_id: u32,
-  ) {
+    limit: u32,
+  ) -> u32 {
+    let mut step: SubnetTeardownStep = match SubnetTeardowns::<T>::get(subnet_id) {
+      Some(step) => step,
+      None => return 0,
+    };
+
+    let block: u64 = Self::get_current_block_as_u64();
+    let mut processed: u32 = 0;
+
+    while processed < limit {
+      let remaining: u32 = limit - processed;
+
+      step = match step {
+        SubnetTeardownStep::NodeStake => {
+          let count: u32 = Self::unbond_subnet_stake(subnet_id, remaining, block);
+          processed = processed.saturating_add(count);
+          if count < remaining {
+            SubnetTeardownStep::DelegateStake
+          } else {
+            SubnetTeardownStep::NodeStake
+          }
+        },
+        SubnetTeardownStep::DelegateStake => {
+          let count: u32 = Self::unbond_subnet_delegate_stake(subnet_id, remaining, block);
+          processed = processed.saturating_add(count);
+          if count < remaining {
+            SubnetTeardownStep::ProposalBonds
+          } else {
+            SubnetTeardownStep::DelegateStake
+          }
+        },
+        SubnetTeardownStep::ProposalBonds => {
+          let count: u32 = Self::return_subnet_proposal_bonds(subnet_id, remaining);
+          processed = processed.saturating_add(count);
+          if count < remaining {
+            SubnetTeardownStep::Storage { map: 0, cursor: None }
+          } else {
+            SubnetTeardownStep::ProposalBonds
+          }
+        },
+        SubnetTeardownStep::Storage { map, cursor } => {
+          match Self::clear_subnet_storage_map(subnet_id, map, cursor, remaining) {
+            Some((count, Some(cursor))) => {
+              processed = processed.saturating_add(count);
+              SubnetTeardownStep::Storage { map, cursor: Some(cursor) }
+            },
+            Some((count, None)) => {
+              processed = processed.saturating_add(count);
+              SubnetTeardownStep::Storage { map: map.saturating_add(1), cursor: None }
+            },
+            None => {
+              Self::finish_clear_subnet(subnet_id);
+              return processed
+            },
+          }
+        },
+      };
+    }
+
+    SubnetTeardowns::<T>::insert(subnet_id, step);
+
+    Self::deposit_event(Event::SubnetTeardownProgress { subnet_id: subnet_id, processed: processed });
+
+    processed
+  }
+
+  /// Move the node stake of up to ``limit`` accounts of ``SubnetStakeAccounts`` into unbonding
+  /// Returns the number of accounts unbonded, less than ``limit`` once none are left
+  // This also releases the stake of previously removed nodes
+  fn unbond_subnet_stake(subnet_id: u32, limit: u32, block: u64) -> u32 {
+    let accounts: Vec<T::AccountId> = SubnetStakeAccounts::<T>::iter_key_prefix(subnet_id)
+      .take(limit as usize)
+      .collect();
+
+    for account_id in accounts.iter() {
+      let stake: u128 = AccountSubnetStake::<T>::get(account_id, subnet_id);
+
+      Self::decrease_account_stake(account_id, subnet_id, stake);
+      AccountSubnetStake::<T>::remove(account_id, subnet_id);
+      SubnetStakeAccounts::<T>::remove(subnet_id, account_id);
+
+      if stake > 0 {
+        Self::force_add_balance_to_stake_unbonding_ledger(account_id, subnet_id, stake, block);
+      }
+    }
+
+    accounts.len() as u32
+  }
+
+  /// Move the delegate stake of up to ``limit`` accounts of ``SubnetDelegateStakeAccounts`` into unbonding
+  /// Returns the number of accounts unbonded, less than ``limit`` once none are left
+  fn unbond_subnet_delegate_stake(subnet_id: u32, limit: u32, block: u64) -> u32 {
+    let accounts: Vec<T::AccountId> = SubnetDelegateStakeAccounts::<T>::iter_key_prefix(subnet_id)
+      .take(limit as usize)
+      .collect();
+
+    for account_id in accounts.iter() {
+      let shares: u128 = AccountSubnetDelegateStakeShares::<T>::get(account_id, subnet_id);
+      let balance: u128 = Self::convert_account_shares_to_balance(account_id, subnet_id);
+
+      Self::decrease_account_delegate_stake_shares(account_id, subnet_id, balance, shares);
+      AccountSubnetDelegateStakeShares::<T>::remove(account_id, subnet_id);
+      SubnetDelegateStakeAccounts::<T>::remove(subnet_id, account_id);
+
+      if balance > 0 {
+        Self::force_add_balance_to_delegate_stake_unbonding_ledger(account_id, subnet_id, balance, block);
+      }
+    }
+
+    accounts.len() as u32
+  }
+
+  /// Return the bonds of up to ``limit`` unsettled appeals and proposals of the subnet, removing them
+  /// Returns the number removed, less than ``limit`` once none are left
+  fn return_subnet_proposal_bonds(subnet_id: u32, limit: u32) -> u32 {
+    let appeals: Vec<(u32, AppealParams<T::AccountId>)> = ProposalAppeals::<T>::iter_prefix(subnet_id)
+      .take(limit as usize)
+      .collect();
+    let mut removed: u32 = appeals.len() as u32;
+
+    for (proposal_id, appeal) in appeals {
+      ProposalAppeals::<T>::remove(subnet_id, proposal_id);
+      Self::return_bond(&appeal.appellant, appeal.bond);
+    }
+
+    if removed >= limit {
+      return removed
+    }
+
+    // Settled proposals have their bonds zeroed out
+    let proposals: Vec<(u32, ProposalParams<T::AccountId>)> = Proposals::<T>::iter_prefix(subnet_id)
+      .take((limit - removed) as usize)
+      .collect();
+    removed += proposals.len() as u32;
+
+    for (proposal_id, proposal) in proposals {
+      Proposals::<T>::remove(subnet_id, proposal_id);
+      Self::return_bond(&proposal.plaintiff, proposal.plaintiff_bond);
+      Self::return_bond(&proposal.defendant, proposal.defendant_bond);
+    }
+
+    removed
+  }
+
+  fn return_bond(account_id: &T::AccountId, bond: u128) {
+    if bond == 0 {
+      return
+    }
+    if let Some(bond_as_balance) = Self::u128_to_balance(bond) {
+      T::Currency::deposit_creating(account_id, bond_as_balance);
+    }
+  }
+
+  /// Remove up to ``limit`` keys of the ``map``th subnet prefixed storage map
+  /// Returns the keys removed and the cursor to resume from, or ``None`` if there is no such map
+  fn clear_subnet_storage_map(
+    subnet_id: u32,
+    map: u8,
+    cursor: Option<Vec<u8>>,
+    limit: u32,
+  ) -> Option<(u32, Option<Vec<u8>>)> {
+    let cursor: Option<&[u8]> = cursor.as_deref();
+    let result = match map {
+      0 => SubnetNodesData::<T>::clear_prefix(subnet_id, limit, cursor),
+      1 => SubnetNodeAccount::<T>::clear_prefix(subnet_id, limit, cursor),
+      2 => DeactivateSubnetNodeLedger::<T>::clear_prefix(subnet_id, limit, cursor),
+      3 => SubnetNodeParam::<T>::clear_prefix(subnet_id, limit, cursor),
+      4 => SubnetNodesClasses::<T>::clear_prefix(subnet_id, limit, cursor),
+      5 => SequentialAbsentSubnetNode::<T>::clear_prefix(subnet_id, limit, cursor),
+      6 => SubnetNodePenalties::<T>::clear_prefix(subnet_id, limit, cursor),
+      7 => SubnetRewardsValidator::<T>::clear_prefix(subnet_id, limit, cursor),
+      8 => SubnetRewardsSubmission::<T>::clear_prefix(subnet_id, limit, cursor),
+      9 => SubnetRewardsReceipts::<T>::clear_prefix(subnet_id, limit, cursor),
+      10 => CurrentAccountants::<T>::clear_prefix(subnet_id, limit, cursor),
+      11 => AccountantData::<T>::clear_prefix(subnet_id, limit, cursor),
+      12 => SubnetProposalReasonAttests::<T>::clear_prefix(subnet_id, limit, cursor),
+      13 => SubnetNodeParamsLastUpdate::<T>::clear_prefix(subnet_id, limit, cursor),
+      _ => return None,
+    };
+    Some((result.unique, result.maybe_cursor))
+  }
 
+  /// Remove the remaining subnet storage once every subnet prefixed map is cleared
+  fn finish_clear_subnet(subnet_id: u32) {
+    TotalSubnetNodes::<T>::remove(subnet_id);
+    TotalActiveSubnetNodes::<T>::remove(subnet_id);
+    SubnetAccount::<T>::remove(subnet_id);
+    SubnetPenaltyCount::<T>::remove(subnet_id);
+    SubnetRewardsReceiptsPruneEpoch::<T>::remove(subnet_id);
+    AccountantDataCount::<T>::remove(subnet_id);
+    SubnetProposalReasons::<T>::remove(subnet_id);
+    TotalSubnetStake::<T>::remove(subnet_id);
+    // Any delegate stake balance left is rounding from the share conversion
+    TotalSubnetDelegateStakeShares::<T>::remove(subnet_id);
+    TotalSubnetDelegateStakeBalance::<T>::remove(subnet_id);
+
+    SubnetTeardowns::<T>::remove(subnet_id);
+
+    Self::deposit_event(Event::SubnetCleared { subnet_id: subnet_id });
   }
 
   pub fn get_min_subnet_nodes(base_node_memory: u128, memory_mb: u128) -> u32 {
@@ -258,87 +508,279 @@ impl<T: Config> Pallet<T> {
     )
   }
 
-  pub fn get_subnet_initialization_cost(block: u64) -> u128 {
-    T::SubnetInitializationCost::get()
+  /// Cost of registering a subnet with ``memory_mb`` on ``block``
+  //
+  // The registration cost rises by ``SubnetRegistrationCostMultiplier`` on each registration and linearly decays back
+  // to ``MinSubnetRegistrationCost`` over ``SubnetRegistrationCostDecayBlocks``
+  //
+  // On top of this, each minimum subnet node required by ``memory_mb`` costs ``SubnetPerNodeInitCost``
+  pub fn get_subnet_initialization_cost(block: u64, memory_mb: u128) -> u128 {
+    let base_node_memory: u128 = BaseSubnetNodeMemoryMB::<T>::get();
+    let min_subnet_nodes: u32 = Self::get_min_subnet_nodes(base_node_memory, memory_mb);
+
+    let memory_cost: u128 = SubnetPerNodeInitCost::<T>::get().saturating_mul(min_subnet_nodes as u128);
+
+    Self::get_subnet_registration_cost(block).saturating_add(memory_cost)
+  }
+
+  /// Registration cost on ``block`` excluding the memory cost
+  pub fn get_subnet_registration_cost(block: u64) -> u128 {
+    let min_cost: u128 = MinSubnetRegistrationCost::<T>::get();
+    let last_cost: u128 = LastSubnetRegistrationCost::<T>::get();
+
+    if last_cost <= min_cost {
+      return min_cost
+    }
+
+    let elapsed: u64 = block.saturating_sub(LastSubnetRegistrationBlock::<T>::get());
+    let decay_blocks: u64 = SubnetRegistrationCostDecayBlocks::<T>::get();
+
+    if elapsed >= decay_blocks {
+      return min_cost
+    }
+
+    let decayed: u128 = multiply_by_rational_with_rounding(
+      last_cost - min_cost,
+      elapsed as u128,
+      decay_blocks as u128,
+      Rounding::Down
+    ).unwrap_or(last_cost - min_cost);
+
+    last_cost - decayed
+  }
+
+  /// Called on each subnet registration
+  pub fn increase_subnet_registration_cost(block: u64) {
+    let cost: u128 = Self::get_subnet_registration_cost(block);
+
+    let next_cost: u128 = multiply_by_rational_with_rounding(
+      cost,
+      SubnetRegistrationCostMultiplier::<T>::get(),
+      Self::PERCENTAGE_FACTOR,
+      Rounding::Down
+    ).unwrap_or(u128::MAX);
+
+    LastSubnetRegistrationCost::<T>::set(next_cost);
+    LastSubnetRegistrationBlock::<T>::set(block);
   }
 
+  /// Choose validators for the epoch of all subnets at once
+  //
+  // ``on_initialize`` carries out the same steps over multiple blocks, see ``do_epoch_processing``
   pub fn do_epoch_preliminaries(block: u64, epoch: u32, epoch_length: u64) {
+    let subnet_ids: Vec<u32> = SubnetsData::<T>::iter_keys().collect();
+    for subnet_id in subnet_ids {
+      Self::do_subnet_epoch_preliminaries(block, epoch, subnet_id);
+    }
+  }
+
+  /// Choose the validator of a subnet for the epoch, or remove the subnet if it no longer qualifies
+  pub fn do_subnet_epoch_preliminaries(block: u64, epoch: u32, subnet_id: u32) {
+    let data = match SubnetsData::<T>::try_get(subnet_id) {
+      Ok(data) => data,
+      Err(()) => return,
+    };
+
     let min_required_subnet_consensus_submit_epochs = MinRequiredSubnetConsensusSubmitEpochs::<T>::get();
     let target_accountants_len: u32 = TargetAccountantsLength::<T>::get();
     let max_subnet_penalty_count = MaxSubnetPenaltyCount::<T>::get();
     let subnet_activation_enactment_period = SubnetActivationEnactmentPeriod::<T>::get();
 
-    for (subnet_id, data) in SubnetsData::<T>::iter() {
-      // --- Ensure subnet is active is able to submit consensus
-      let max_registration_block = data.initialized + data.registration_blocks + subnet_activation_enactment_period;
-      if data.activated == 0 && block <= max_registration_block {
-        // We check if the subnet is still in registration phase and not yet out of the enactment phase
-        continue
-      } else if data.activated == 0 && block > max_registration_block {
-        // --- Ensure subnet is in registration period and hasn't passed enactment period
-        // If subnet hasn't been activated after the enacement period, then remove subnet
-				Self::deactivate_subnet(
-					data.path,
-					SubnetRemovalReason::EnactmentPeriod,
-				);
-        continue
-			}
+    // --- Ensure subnet is active is able to submit consensus
+    let max_registration_block = data.initialized + data.registration_blocks + subnet_activation_enactment_period;
+    if data.activated == 0 && block <= max_registration_block {
+      // We check if the subnet is still in registration phase and not yet out of the enactment phase
+      return
+    } else if data.activated == 0 && block > max_registration_block {
+      // --- Ensure subnet is in registration period and hasn't passed enactment period
+      // If subnet hasn't been activated after the enacement period, then remove subnet
+      Self::deactivate_subnet(
+        data.path.into(),
+        SubnetRemovalReason::EnactmentPeriod,
+      );
+      return
+    }
+
+    // --- Paused subnets are frozen until unpaused or paused longer than ``MaxSubnetPauseEpochs``
+    if data.paused != 0 {
+      Self::do_paused_subnet_epoch_preliminaries(block, epoch, data);
+      return
+    }
 
-      // --- All subnets are now activated and passed the registration period
-      // Must have:
-      //  - Minimum nodes (increases penalties if less than)
-      //  - Minimum delegate stake balance (remove subnet if less than)
+    // --- All subnets are now activated and passed the registration period
+    // Must have:
+    //  - Minimum nodes (increases penalties if less than)
+    //  - Minimum delegate stake balance (remove subnet if less than)
 
-      let min_subnet_nodes = data.min_nodes;
-			let subnet_delegate_stake_balance = TotalSubnetDelegateStakeBalance::<T>::get(subnet_id);
-			let min_subnet_delegate_stake_balance = Self::get_min_subnet_delegate_stake_balance(min_subnet_nodes);
-
-      // --- Ensure min delegate stake balance is met
-      if subnet_delegate_stake_balance < min_subnet_delegate_stake_balance {
-        Self::deactivate_subnet(
-          data.path,
-          SubnetRemovalReason::MinSubnetDelegateStake,
-        );
-        continue
-      }
+    let min_subnet_nodes = data.min_nodes;
+    let subnet_delegate_stake_balance = TotalSubnetDelegateStakeBalance::<T>::get(subnet_id);
+    let min_subnet_delegate_stake_balance = Self::get_min_subnet_delegate_stake_balance(min_subnet_nodes);
+
+    // --- Ensure min delegate stake balance is met
+    if subnet_delegate_stake_balance < min_subnet_delegate_stake_balance {
+      Self::deactivate_subnet(
+        data.path.into(),
+        SubnetRemovalReason::MinSubnetDelegateStake,
+      );
+      return
+    }
+
+    // --- Get all possible validators
+    let subnet_node_accounts: Vec<T::AccountId> = Self::get_classified_accounts(subnet_id, &SubnetNodeClass::Submittable, epoch as u64);
+    let subnet_nodes_count = subnet_node_accounts.len();
+    
+    // --- Ensure min nodes are active
+    // Only choose validator if min nodes are present
+    // The ``SubnetPenaltyCount`` when surpassed doesn't penalize anyone, only removes the subnet from the chain
+    if (subnet_nodes_count as u32) < min_subnet_nodes {
+      SubnetPenaltyCount::<T>::mutate(subnet_id, |n: &mut u32| *n += 1);
+    }
+
+    // --- Check penalties and remove subnet is threshold is breached
+    let penalties = SubnetPenaltyCount::<T>::get(subnet_id);
+    if penalties >  max_subnet_penalty_count{
+      Self::deactivate_subnet(
+        data.path.into(),
+        SubnetRemovalReason::MaxPenalties,
+      );
+      return
+    }
+
+    Self::choose_validator(
+      block,
+      subnet_id,
+      subnet_node_accounts.clone(),
+      min_subnet_nodes,
+      epoch,
+    );
+
+    // Self::choose_accountants(
+    //   block,
+    //   epoch,
+    //   subnet_id,
+    //   subnet_node_accounts,
+    //   min_subnet_nodes,
+    //   target_accountants_len,
+    // );
+  }
+
+  /// Start rewarding subnets for the epoch, carried out in ``do_epoch_processing``
+  pub fn begin_epoch_processing(epoch: u32) -> Weight {
+    let budget: u128 = Self::begin_epoch_rewards(epoch);
 
-      // --- Get all possible validators
-      let subnet_node_accounts: Vec<T::AccountId> = Self::get_classified_accounts(subnet_id, &SubnetNodeClass::Submittable, epoch as u64);
-      let subnet_nodes_count = subnet_node_accounts.len();
-      
-      // --- Ensure min nodes are active
-      // Only choose validator if min nodes are present
-      // The ``SubnetPenaltyCount`` when surpassed doesn't penalize anyone, only removes the subnet from the chain
-      if (subnet_nodes_count as u32) < min_subnet_nodes {
-        SubnetPenaltyCount::<T>::mutate(subnet_id, |n: &mut u32| *n += 1);
+    EpochProcessing::<T>::put(
+      EpochProcessingStep::Rewards { 
+        epoch: epoch, 
+        budget: budget, 
+        emitted: 0, 
+        cursor: None,
       }
+    );
+
+    T::WeightInfo::begin_epoch_rewards(TotalSubnets::<T>::get())
+  }
 
-      // --- Check penalties and remove subnet is threshold is breached
-      let penalties = SubnetPenaltyCount::<T>::get(subnet_id);
-      if penalties >  max_subnet_penalty_count{
-        Self::deactivate_subnet(
-          data.path,
-          SubnetRemovalReason::MaxPenalties,
+  /// Process the epoch from the ``EpochProcessing`` cursor up to ``nodes_per_block`` subnet nodes,
+  /// returns the weight used
+  //
+  // Live subnets are processed in ``SubnetsData`` key order and are never split across blocks, so at least one
+  // subnet is processed on each block. Once all subnets are rewarded the epochs emissions are finalized and
+  // validators are chosen for the current epoch starting on the next block
+  pub fn do_epoch_processing(block: u64, nodes_per_block: u32) -> Weight {
+    let db_weight = T::DbWeight::get();
+
+    let step: EpochProcessingStep = match EpochProcessing::<T>::get() {
+      Some(step) => step,
+      None => return db_weight.reads(1),
+    };
+
+    let mut weight: Weight = db_weight.reads_writes(1, 1);
+    let mut nodes_processed: u32 = 0;
+
+    match step {
+      EpochProcessingStep::Rewards { epoch, budget, mut emitted, mut cursor } => {
+        let subnet_ids = match cursor.clone() {
+          Some(key) => SubnetsData::<T>::iter_keys_from(key),
+          None => SubnetsData::<T>::iter_keys(),
+        };
+        for subnet_id in subnet_ids {
+          let subnet_nodes: u32 = TotalSubnetNodes::<T>::get(subnet_id).max(1);
+          if nodes_processed > 0 && nodes_processed.saturating_add(subnet_nodes) > nodes_per_block {
+            EpochProcessing::<T>::put(
+              EpochProcessingStep::Rewards { 
+                epoch: epoch, 
+                budget: budget, 
+                emitted: emitted, 
+                cursor: cursor,
+              }
+            );
+            return weight.saturating_add(db_weight.reads(2))
+          }
+
+          emitted = emitted.saturating_add(Self::reward_subnet(block, epoch, subnet_id));
+          weight = weight.saturating_add(T::WeightInfo::reward_subnet(subnet_nodes));
+          nodes_processed = nodes_processed.saturating_add(subnet_nodes);
+          cursor = Some(SubnetsData::<T>::hashed_key_for(subnet_id));
+        }
+
+        Self::finish_epoch_rewards(epoch, budget, emitted);
+
+        // --- Choose validators for the current epoch starting on the next block
+        // This is the following epoch unless the rewards ran past its start, see ``skip_ended_epoch_processing``
+        let current_epoch: u32 = (block / T::EpochLength::get()) as u32;
+        EpochProcessing::<T>::put(
+          EpochProcessingStep::Preliminaries { 
+            epoch: current_epoch, 
+            cursor: None,
+          }
         );
-        continue
-      }
 
-      Self::choose_validator(
-        block,
-        subnet_id,
-        subnet_node_accounts.clone(),
-        min_subnet_nodes,
-        epoch,
-      );
+        weight.saturating_add(T::WeightInfo::finish_epoch_rewards())
+      },
+      EpochProcessingStep::Preliminaries { epoch, mut cursor } => {
+        let subnet_ids = match cursor.clone() {
+          Some(key) => SubnetsData::<T>::iter_keys_from(key),
+          None => SubnetsData::<T>::iter_keys(),
+        };
+        for subnet_id in subnet_ids {
+          let subnet_nodes: u32 = TotalSubnetNodes::<T>::get(subnet_id).max(1);
+          if nodes_processed > 0 && nodes_processed.saturating_add(subnet_nodes) > nodes_per_block {
+            EpochProcessing::<T>::put(
+              EpochProcessingStep::Preliminaries { 
+                epoch: epoch, 
+                cursor: cursor,
+              }
+            );
+            return weight.saturating_add(db_weight.reads(2))
+          }
+
+          Self::do_subnet_epoch_preliminaries(block, epoch, subnet_id);
+          weight = weight.saturating_add(T::WeightInfo::subnet_epoch_preliminaries(subnet_nodes));
+          nodes_processed = nodes_processed.saturating_add(subnet_nodes);
+          cursor = Some(SubnetsData::<T>::hashed_key_for(subnet_id));
+        }
 
-      // Self::choose_accountants(
-      //   block,
-      //   epoch,
-      //   subnet_id,
-      //   subnet_node_accounts,
-      //   min_subnet_nodes,
-      //   target_accountants_len,
-      // );
+        EpochProcessing::<T>::kill();
+
+        weight
+      },
+    }
+  }
+
+  /// Skip what is left of the epoch processing once the epoch it was choosing validators for has ended,
+  /// called on the first block of each epoch
+  //
+  // Subnets left without a validator are not rewarded, attested or penalized for the ended epoch.
+  // Rewards of an ended epoch carry on at ``EpochProcessingNodesPerBlock`` per block, the epoch that
+  // ended while they were in progress had no validators chosen and is not rewarded
+  pub fn skip_ended_epoch_processing() -> Weight {
+    let db_weight = T::DbWeight::get();
+    match EpochProcessing::<T>::get() {
+      Some(EpochProcessingStep::Preliminaries { .. }) => {
+        EpochProcessing::<T>::kill();
+        db_weight.reads_writes(1, 1)
+      },
+      _ => db_weight.reads(1),
     }
   }
 
@@ -365,7 +807,9 @@ impl<T: Config> Pallet<T> {
   //   Ok(())
   // }
 
-  pub fn get_account_total_stake_balance(account_id: T::AccountId) -> u128 {
+  /// Stake of an account across the subnets it's a submittable subnet node of, as of the start of a
+  /// voting power snapshot ``snapshot_block``
+  pub fn get_account_total_stake_balance(account_id: T::AccountId, snapshot_block: u64) -> u128 {
     let min_required_subnet_consensus_submit_epochs = MinRequiredSubnetConsensusSubmitEpochs::<T>::get();
     let epoch_length: u64 = T::EpochLength::get();
     let block: u64 = Self::get_current_block_as_u64();
@@ -397,16 +841,19 @@ impl<T: Config> Pallet<T> {
       // --- Ensure account is submittable to include in subnet democracy
       if let Some(is_submittable) = is_submittable {
         // TODO: Ensure this is removed for subnet node staking cooldown during unbondings
-        total_stake_balance += AccountSubnetStake::<T>::get(&account_id, subnet_id);
+        total_stake_balance += Self::get_account_subnet_stake_at(&account_id, subnet_id, snapshot_block).0;
       }
     }
 		total_stake_balance
 	}
 
-  pub fn get_total_voting_power() -> u128 {
+  /// Total stake and delegate stake of subnets included in subnet democracy, as of the start of a
+  /// voting power snapshot ``snapshot_block``
+  pub fn get_total_voting_power(snapshot_block: u64) -> u128 {
     let min_required_subnet_consensus_submit_epochs = MinRequiredSubnetConsensusSubmitEpochs::<T>::get();
     let epoch_length: u64 = T::EpochLength::get();
     let block: u64 = Self::get_current_block_as_u64();
+    let epoch: u64 = block / epoch_length;
 
     let mut total_voting_power = 0;
     for (subnet_id, data) in SubnetsData::<T>::iter() {
@@ -424,22 +871,38 @@ impl<T: Config> Pallet<T> {
         continue
       }
 
-      let node_sets: BTreeMap<T::AccountId, u64> = SubnetNodesClasses::<T>::get(subnet_id, SubnetNodeClass::Submittable);
+      let subnet_nodes: BTreeSet<T::AccountId> = Self::get_classified_accounts(subnet_id, &SubnetNodeClass::Submittable, epoch);
 
       // --- Ensure min subnet nodes that are submittable are at least the minimum required to include in subnet democracy
-      if (node_sets.len() as u32) < min_subnet_nodes {
+      if (subnet_nodes.len() as u32) < min_subnet_nodes {
         continue
       }
 
+      let (total_subnet_stake, total_subnet_delegate_stake_balance, _) = Self::get_subnet_stake_at(subnet_id, snapshot_block);
+
       // Get total subnet node stake balance
-      total_voting_power += TotalSubnetStake::<T>::get(subnet_id);
+      total_voting_power += total_subnet_stake;
 
       // Get total delegate subnet stake balance
-      total_voting_power += TotalSubnetDelegateStakeBalance::<T>::get(subnet_id);
+      total_voting_power += total_subnet_delegate_stake_balance;
     }
     total_voting_power
   }
   
+  /// Is the account a submittable subnet node in any subnet
+  pub fn is_submittable_subnet_node_account(account_id: T::AccountId) -> bool {
+    let epoch_length: u64 = T::EpochLength::get();
+    let block: u64 = Self::get_current_block_as_u64();
+    let epoch: u64 = block / epoch_length;
+
+    SubnetsData::<T>::iter_keys().any(|subnet_id| {
+      match SubnetNodesData::<T>::try_get(subnet_id, account_id.clone()) {
+        Ok(subnet_node) => subnet_node.has_classification(&SubnetNodeClass::Submittable, epoch),
+        Err(()) => false,
+      }
+    })
+  }
+
   /// The minimum delegate stake balance for a subnet to stay live
   pub fn get_min_subnet_delegate_stake_balance(min_subnet_nodes: u32) -> u128 {
     // --- Get minimum stake balance per subnet node
@@ -476,6 +939,9 @@ impl<T: Config> Pallet<T> {
           account_id: subnet_node.account_id,
           hotkey: subnet_node.hotkey,
           peer_id: subnet_node.peer_id,
+          a: subnet_node.a,
+          b: subnet_node.b,
+          c: subnet_node.c,
         }
       })
       .collect()
@@ -0,0 +1,144 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<T: Config> Pallet<T> {
+  /// Track stake changes from ``block`` on so voting power can be read as of the start of ``block``
+  // Called by subnet democracy for the ``start_vote_block`` of each proposal, ``block`` must not have passed
+  pub fn do_add_voting_power_snapshot(block: u64) {
+    VotingPowerSnapshotBlocks::<T>::mutate(|blocks| {
+      let count = blocks.entry(block).or_insert(0);
+      *count = count.saturating_add(1);
+    });
+  }
+
+  /// Stop tracking stake changes for ``block`` once no proposal uses it
+  // The recorded snapshots are cleared over multiple blocks in ``on_idle``
+  pub fn do_remove_voting_power_snapshot(block: u64) {
+    let mut blocks = VotingPowerSnapshotBlocks::<T>::get();
+    match blocks.get_mut(&block) {
+      Some(count) if *count > 1 => *count -= 1,
+      Some(_) => {
+        blocks.remove(&block);
+        VotingPowerSnapshotTeardowns::<T>::mutate(|teardowns| teardowns.insert(block));
+      },
+      None => return,
+    }
+    VotingPowerSnapshotBlocks::<T>::put(blocks);
+  }
+
+  /// Record the stake and delegate stake shares of an account in a subnet ahead of their first change
+  /// since each tracked snapshot block
+  pub fn snapshot_account_subnet_stake(account_id: &T::AccountId, subnet_id: u32) {
+    let block: u64 = Self::get_current_block_as_u64();
+    for snapshot_block in VotingPowerSnapshotBlocks::<T>::get().into_keys().take_while(|b| *b <= block) {
+      let key = (account_id.clone(), subnet_id);
+      if AccountSubnetStakeSnapshots::<T>::contains_key(snapshot_block, &key) {
+        continue
+      }
+      AccountSubnetStakeSnapshots::<T>::insert(
+        snapshot_block,
+        key,
+        (
+          AccountSubnetStake::<T>::get(account_id, subnet_id),
+          AccountSubnetDelegateStakeShares::<T>::get(account_id, subnet_id),
+        )
+      );
+    }
+  }
+
+  /// Record the total stake, delegate stake balance and delegate stake shares of a subnet ahead of
+  /// their first change since each tracked snapshot block
+  pub fn snapshot_subnet_stake(subnet_id: u32) {
+    let block: u64 = Self::get_current_block_as_u64();
+    for snapshot_block in VotingPowerSnapshotBlocks::<T>::get().into_keys().take_while(|b| *b <= block) {
+      if SubnetStakeSnapshots::<T>::contains_key(snapshot_block, subnet_id) {
+        continue
+      }
+      SubnetStakeSnapshots::<T>::insert(
+        snapshot_block,
+        subnet_id,
+        (
+          TotalSubnetStake::<T>::get(subnet_id),
+          TotalSubnetDelegateStakeBalance::<T>::get(subnet_id),
+          TotalSubnetDelegateStakeShares::<T>::get(subnet_id),
+        )
+      );
+    }
+  }
+
+  /// Stake and delegate stake shares of an account in a subnet as of the start of a tracked snapshot block
+  // Unchanged since ``block`` if no snapshot was recorded
+  pub fn get_account_subnet_stake_at(account_id: &T::AccountId, subnet_id: u32, block: u64) -> (u128, u128) {
+    AccountSubnetStakeSnapshots::<T>::get(block, (account_id.clone(), subnet_id)).unwrap_or_else(|| {
+      (
+        AccountSubnetStake::<T>::get(account_id, subnet_id),
+        AccountSubnetDelegateStakeShares::<T>::get(account_id, subnet_id),
+      )
+    })
+  }
+
+  /// Total stake, delegate stake balance and delegate stake shares of a subnet as of the start of a
+  /// tracked snapshot block
+  // Unchanged since ``block`` if no snapshot was recorded
+  pub fn get_subnet_stake_at(subnet_id: u32, block: u64) -> (u128, u128, u128) {
+    SubnetStakeSnapshots::<T>::get(block, subnet_id).unwrap_or_else(|| {
+      (
+        TotalSubnetStake::<T>::get(subnet_id),
+        TotalSubnetDelegateStakeBalance::<T>::get(subnet_id),
+        TotalSubnetDelegateStakeShares::<T>::get(subnet_id),
+      )
+    })
+  }
+
+  /// Delegate stake balance of an account across all subnets as of the start of a tracked snapshot block
+  pub fn get_account_delegate_stake_balance_at(account_id: &T::AccountId, block: u64) -> u128 {
+    let mut total_delegate_stake_balance: u128 = 0;
+    for subnet_id in SubnetsData::<T>::iter_keys() {
+      let (_, shares) = Self::get_account_subnet_stake_at(account_id, subnet_id, block);
+      if shares == 0 {
+        continue
+      }
+      let (_, total_balance, total_shares) = Self::get_subnet_stake_at(subnet_id, block);
+      total_delegate_stake_balance = total_delegate_stake_balance.saturating_add(
+        Self::convert_to_balance(shares, total_shares, total_balance)
+      );
+    }
+    total_delegate_stake_balance
+  }
+
+  /// Clear up to ``limit`` snapshots of one untracked snapshot block, returns the number removed
+  pub fn clear_voting_power_snapshots(limit: u32) -> u32 {
+    let block: u64 = match VotingPowerSnapshotTeardowns::<T>::get().into_iter().next() {
+      Some(block) => block,
+      None => return 0,
+    };
+
+    let accounts = AccountSubnetStakeSnapshots::<T>::clear_prefix(block, limit, None);
+    let mut removed: u32 = accounts.unique;
+    if accounts.maybe_cursor.is_some() || removed >= limit {
+      return removed
+    }
+
+    let subnets = SubnetStakeSnapshots::<T>::clear_prefix(block, limit.saturating_sub(removed), None);
+    removed = removed.saturating_add(subnets.unique);
+    if subnets.maybe_cursor.is_none() {
+      VotingPowerSnapshotTeardowns::<T>::mutate(|teardowns| teardowns.remove(&block));
+    }
+
+    removed
+  }
+}
@@ -42,7 +42,8 @@ pub trait WeightInfo {
 	fn add_to_delegate_stake() -> Weight;
 	fn transfer_delegate_stake() -> Weight;
 	fn remove_delegate_stake() -> Weight;
-	fn claim_delegate_stake_unbondings() -> Weight;
+	fn claim_delegate_stake_unbondings(u: u32, ) -> Weight;
+	fn claim_stake_unbondings(u: u32, ) -> Weight;
 	fn increase_delegate_stake() -> Weight;
 	fn validate() -> Weight;
 	fn attest() -> Weight;
@@ -50,7 +51,30 @@ pub trait WeightInfo {
 	fn cancel_proposal() -> Weight;
 	fn challenge_proposal() -> Weight;
 	fn vote() -> Weight;
-	fn finalize_proposal() -> Weight;
+	fn attest_proposal() -> Weight;
+	fn finalize_proposal(n: u32, ) -> Weight;
+	fn appeal_proposal() -> Weight;
+	fn enact_proposal(n: u32, ) -> Weight;
+	fn remove_subnet() -> Weight;
+	fn on_initialize() -> Weight;
+	fn clear_subnet(n: u32, ) -> Weight;
+	fn begin_epoch_rewards(s: u32, ) -> Weight;
+	fn reward_subnet(n: u32, ) -> Weight;
+	fn finish_epoch_rewards() -> Weight;
+	fn subnet_epoch_preliminaries(n: u32, ) -> Weight;
+	fn remove_account_subnet_nodes(s: u32, ) -> Weight;
+	fn owner_update_subnet() -> Weight;
+	fn transfer_subnet_ownership() -> Weight;
+	fn renounce_subnet_ownership() -> Weight;
+	fn owner_set_subnet_metadata() -> Weight;
+	fn update_subnet_node_params() -> Weight;
+	fn owner_pause_subnet() -> Weight;
+	fn owner_unpause_subnet() -> Weight;
+	fn owner_set_registration_whitelist(n: u32, ) -> Weight;
+	fn deactivate_subnet_node() -> Weight;
+	fn reactivate_subnet_node() -> Weight;
+	fn owner_set_registration_peer_whitelist(n: u32, ) -> Weight;
+	fn register_subnet_with_whitelist(n: u32, p: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_network` using the Substrate node and recommended hardware.
@@ -369,14 +393,35 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Network::DelegateStakeUnbondingLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn claim_delegate_stake_unbondings() -> Weight {
+	/// The range of component `u` is `[1, 32]`.
+	fn claim_delegate_stake_unbondings(u: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `794`
-		//  Estimated: `4259`
-		// Minimum execution time: 34_920_000 picoseconds.
-		Weight::from_parts(49_285_000, 4259)
+		//  Measured:  `794 + u * (32 ±0)`
+		//  Estimated: `4259 + u * (32 ±0)`
+		// Minimum execution time: 36_104_000 picoseconds.
+		Weight::from_parts(38_917_000, 4259)
+			// Standard Error: 2_204
+			.saturating_add(Weight::from_parts(1_412_000, 0).saturating_mul(u.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(u.into()))
+	}
+	/// Storage: `Network::SubnetStakeUnbondingLedger` (r:1 w:1)
+	/// Proof: `Network::SubnetStakeUnbondingLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `u` is `[1, 32]`.
+	fn claim_stake_unbondings(u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812 + u * (32 ±0)`
+		//  Estimated: `4277 + u * (32 ±0)`
+		// Minimum execution time: 36_388_000 picoseconds.
+		Weight::from_parts(39_206_000, 4277)
+			// Standard Error: 2_317
+			.saturating_add(Weight::from_parts(1_436_000, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(u.into()))
 	}
 	/// Storage: `Network::SubnetsData` (r:1 w:0)
 	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -503,7 +548,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Network::ProposalQuorum` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::ProposalConsensusThreshold` (r:1 w:0)
 	/// Proof: `Network::ProposalConsensusThreshold` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::SubnetNodesData` (r:65 w:1)
+	/// Storage: `Network::SubnetNodesData` (r:255 w:1)
 	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetRewardsSubmission` (r:1 w:1)
 	/// Proof: `Network::SubnetRewardsSubmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -517,14 +562,498 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Network::SubnetNodeAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetNodePenalties` (r:0 w:1)
 	/// Proof: `Network::SubnetNodePenalties` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn finalize_proposal() -> Weight {
+	/// The range of component `n` is `[16, 254]`.
+	fn finalize_proposal(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `30407`
-		//  Estimated: `192272`
-		// Minimum execution time: 1_140_336_000 picoseconds.
-		Weight::from_parts(1_151_861_000, 192272)
-			.saturating_add(T::DbWeight::get().reads(135_u64))
-			.saturating_add(T::DbWeight::get().writes(70_u64))
+		//  Measured:  `2117 + n * (448 ±0)`
+		//  Estimated: `12780 + n * (2603 ±0)`
+		// Minimum execution time: 318_475_000 picoseconds.
+		Weight::from_parts(94_118_000, 12780)
+			// Standard Error: 64_913
+			.saturating_add(Weight::from_parts(17_542_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `Network::StakeVaultBalance` (r:1 w:0)
+	/// Storage: `Network::SubnetsData` (r:17 w:0)
+	/// Storage: `Network::SubnetRewardsValidator` (r:16 w:0)
+	/// Storage: `Network::TotalSubnetDelegateStakeBalance` (r:16 w:0)
+	/// Storage: `Network::EpochSubnetEmissions` (r:0 w:16)
+	/// The range of component `s` is `[1, 16]`.
+	fn begin_epoch_rewards(s: u32, ) -> Weight {
+		// Minimum execution time: 18_412_000 picoseconds.
+		Weight::from_parts(19_870_000, 3553)
+			// Standard Error: 4_101
+			.saturating_add(Weight::from_parts(14_266_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 7500).saturating_mul(s.into()))
+	}
+	/// Storage: `Network::EpochSubnetEmissions` (r:1 w:1)
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Storage: `Network::SubnetRewardsSubmission` (r:1 w:0)
+	/// Storage: `Network::SubnetNodesData` (r:255 w:0)
+	/// Storage: `Network::SubnetNodePenalties` (r:254 w:254)
+	/// Storage: `Network::AccountSubnetStake` (r:255 w:255)
+	/// Storage: `Network::TotalAccountStake` (r:254 w:254)
+	/// Storage: `Network::TotalSubnetStake` (r:1 w:1)
+	/// Storage: `Network::TotalStake` (r:1 w:1)
+	/// Storage: `Network::SubnetRewardsReceipts` (r:0 w:2)
+	/// The range of component `n` is `[12, 254]`.
+	fn reward_subnet(n: u32, ) -> Weight {
+		// Minimum execution time: 112_530_000 picoseconds.
+		Weight::from_parts(121_604_000, 9840)
+			// Standard Error: 38_215
+			.saturating_add(Weight::from_parts(47_382_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(21_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10420).saturating_mul(n.into()))
+	}
+	/// Storage: `Network::StakeVaultBalance` (r:1 w:1)
+	/// Storage: `Network::UnpaidEmissions` (r:1 w:0)
+	fn finish_epoch_rewards() -> Weight {
+		// Minimum execution time: 11_301_000 picoseconds.
+		Weight::from_parts(12_045_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Storage: `Network::TotalSubnetDelegateStakeBalance` (r:1 w:0)
+	/// Storage: `Network::SubnetNodesData` (r:255 w:0)
+	/// Storage: `Network::SubnetPenaltyCount` (r:1 w:1)
+	/// Storage: `Network::SubnetRewardsValidator` (r:0 w:1)
+	/// The range of component `n` is `[12, 254]`.
+	fn subnet_epoch_preliminaries(n: u32, ) -> Weight {
+		// Minimum execution time: 64_980_000 picoseconds.
+		Weight::from_parts(70_112_000, 5204)
+			// Standard Error: 12_480
+			.saturating_add(Weight::from_parts(9_813_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2860).saturating_mul(n.into()))
+	}
+	/// Storage: `Network::Proposals` (r:1 w:1)
+	/// Proof: `Network::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodesData` (r:1 w:0)
+	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::ChallengePeriod` (r:1 w:0)
+	/// Proof: `Network::ChallengePeriod` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn attest_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3318`
+		//  Estimated: `6783`
+		// Minimum execution time: 44_715_000 picoseconds.
+		Weight::from_parts(48_902_000, 6783)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Network::Proposals` (r:1 w:0)
+	/// Proof: `Network::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::ProposalAppeals` (r:1 w:1)
+	/// Proof: `Network::ProposalAppeals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::AppealPeriod` (r:1 w:0)
+	/// Proof: `Network::AppealPeriod` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::AppealBondAmount` (r:1 w:0)
+	/// Proof: `Network::AppealBondAmount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn appeal_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3906`
+		//  Estimated: `7371`
+		// Minimum execution time: 58_231_000 picoseconds.
+		Weight::from_parts(62_480_000, 7371)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Network::Proposals` (r:1 w:1)
+	/// Proof: `Network::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::ProposalAppeals` (r:1 w:0)
+	/// Proof: `Network::ProposalAppeals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::AppealPeriod` (r:1 w:0)
+	/// Proof: `Network::AppealPeriod` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodesData` (r:1 w:1)
+	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalSubnetNodes` (r:1 w:1)
+	/// Proof: `Network::TotalSubnetNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalActiveSubnetNodes` (r:1 w:1)
+	/// Proof: `Network::TotalActiveSubnetNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:253 w:253)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Network::SubnetNodeAccount` (r:0 w:1)
+	/// Proof: `Network::SubnetNodeAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodePenalties` (r:0 w:1)
+	/// Proof: `Network::SubnetNodePenalties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[16, 254]`.
+	fn enact_proposal(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2508 + n * (448 ±0)`
+		//  Estimated: `8517 + n * (2603 ±0)`
+		// Minimum execution time: 131_260_000 picoseconds.
+		Weight::from_parts(98_410_000, 8517)
+			// Standard Error: 41_087
+			.saturating_add(Weight::from_parts(8_913_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:1)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetPenaltyCount` (r:1 w:1)
+	/// Proof: `Network::SubnetPenaltyCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetPenaltyCount` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetPenaltyCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetPaths` (r:1 w:1)
+	/// Proof: `Network::SubnetPaths` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodesData` (r:13 w:12)
+	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalSubnetMemoryMB` (r:1 w:1)
+	/// Proof: `Network::TotalSubnetMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn remove_subnet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6214`
+		//  Estimated: `38579`
+		// Minimum execution time: 402_917_000 picoseconds.
+		Weight::from_parts(418_365_000, 38579)
+			.saturating_add(T::DbWeight::get().reads(41_u64))
+			.saturating_add(T::DbWeight::get().writes(58_u64))
+	}
+	/// Storage: `Network::EpochProcessing` (r:1 w:0)
+	/// Proof: `Network::EpochProcessing` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::EpochProcessingNodesPerBlock` (r:1 w:0)
+	/// Proof: `Network::EpochProcessingNodesPerBlock` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_initialize() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1527`
+		// Minimum execution time: 3_912_000 picoseconds.
+		Weight::from_parts(4_180_000, 1527)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `Network::SubnetTeardowns` (r:1 w:1)
+	/// Proof: `Network::SubnetTeardowns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetTeardownItemsPerCall` (r:1 w:0)
+	/// Proof: `Network::SubnetTeardownItemsPerCall` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::AccountSubnetStake` (r:257 w:256)
+	/// Proof: `Network::AccountSubnetStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalAccountStake` (r:256 w:256)
+	/// Proof: `Network::TotalAccountStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalSubnetStake` (r:1 w:1)
+	/// Proof: `Network::TotalSubnetStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalStake` (r:1 w:1)
+	/// Proof: `Network::TotalStake` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetStakeUnbondingLedger` (r:256 w:256)
+	/// Proof: `Network::SubnetStakeUnbondingLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 256]`.
+	fn clear_subnet(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `366 + n * (143 ±0)`
+		//  Estimated: `3831 + n * (2618 ±0)`
+		// Minimum execution time: 18_734_000 picoseconds.
+		Weight::from_parts(21_305_000, 3831)
+			// Standard Error: 9_412
+			.saturating_add(Weight::from_parts(24_871_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2618).saturating_mul(n.into()))
+	}
+	/// Storage: `Network::AccountPenaltyCount` (r:1 w:1)
+	/// Proof: `Network::AccountPenaltyCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxAccountPenaltyCount` (r:1 w:0)
+	/// Proof: `Network::MaxAccountPenaltyCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetsData` (r:17 w:0)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodesData` (r:16 w:16)
+	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetRewardsSubmission` (r:16 w:16)
+	/// Proof: `Network::SubnetRewardsSubmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalSubnetNodes` (r:16 w:16)
+	/// Proof: `Network::TotalSubnetNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalActiveSubnetNodes` (r:16 w:16)
+	/// Proof: `Network::TotalActiveSubnetNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::AccountPenaltyBanEpochs` (r:1 w:0)
+	/// Proof: `Network::AccountPenaltyBanEpochs` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodeAccount` (r:0 w:16)
+	/// Proof: `Network::SubnetNodeAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodePenalties` (r:0 w:16)
+	/// Proof: `Network::SubnetNodePenalties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::AccountBans` (r:0 w:1)
+	/// Proof: `Network::AccountBans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 16]`.
+	fn remove_account_subnet_nodes(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1248 + s * (612 ±0)`
+		//  Estimated: `4713 + s * (2835 ±0)`
+		// Minimum execution time: 41_503_000 picoseconds.
+		Weight::from_parts(38_271_000, 4713)
+			// Standard Error: 14_207
+			.saturating_add(Weight::from_parts(101_845_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2835).saturating_mul(s.into()))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:1)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetMemoryMB` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalSubnetMemoryMB` (r:1 w:1)
+	/// Proof: `Network::TotalSubnetMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxTotalSubnetMemoryMB` (r:1 w:0)
+	/// Proof: `Network::MaxTotalSubnetMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MinSubnetNodes` (r:1 w:0)
+	/// Proof: `Network::MinSubnetNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetNodes` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn owner_update_subnet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `624`
+		//  Estimated: `4089`
+		// Minimum execution time: 27_114_000 picoseconds.
+		Weight::from_parts(28_390_000, 4089)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:1)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_subnet_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 15_826_000 picoseconds.
+		Weight::from_parts(16_437_000, 3977)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:1)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn renounce_subnet_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 15_102_000 picoseconds.
+		Weight::from_parts(15_918_000, 3977)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetMetadataRevision` (r:1 w:1)
+	/// Proof: `Network::SubnetMetadataRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetsMetadata` (r:0 w:1)
+	/// Proof: `Network::SubnetsMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn owner_set_subnet_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 22_640_000 picoseconds.
+		Weight::from_parts(23_581_000, 3977)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Network::SubnetNodesData` (r:1 w:1)
+	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodeParamsLastUpdate` (r:1 w:1)
+	/// Proof: `Network::SubnetNodeParamsLastUpdate` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodeParamsUpdateInterval` (r:1 w:0)
+	/// Proof: `Network::SubnetNodeParamsUpdateInterval` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn update_subnet_node_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `795`
+		//  Estimated: `4260`
+		// Minimum execution time: 26_912_000 picoseconds.
+		Weight::from_parts(28_744_000, 4260)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:2 w:1)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetPauseBond` (r:1 w:0)
+	/// Proof: `Network::SubnetPauseBond` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Network::SubnetPauseBonds` (r:0 w:1)
+	/// Proof: `Network::SubnetPauseBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn owner_pause_subnet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `743`
+		//  Estimated: `4208`
+		// Minimum execution time: 39_806_000 picoseconds.
+		Weight::from_parts(41_219_000, 4208)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:2 w:1)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetPauseBonds` (r:1 w:1)
+	/// Proof: `Network::SubnetPauseBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MinSubnetPauseEpochs` (r:1 w:0)
+	/// Proof: `Network::MinSubnetPauseEpochs` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn owner_unpause_subnet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `889`
+		//  Estimated: `4354`
+		// Minimum execution time: 39_462_000 picoseconds.
+		Weight::from_parts(41_305_000, 4354)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetPermissioned` (r:1 w:0)
+	/// Proof: `Network::SubnetPermissioned` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetNodes` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetRegistrationWhitelist` (r:0 w:1)
+	/// Proof: `Network::SubnetRegistrationWhitelist` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn owner_set_registration_whitelist(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512 + n * (0 ±0)`
+		//  Estimated: `3977 + n * (0 ±0)`
+		// Minimum execution time: 19_884_000 picoseconds.
+		Weight::from_parts(20_417_000, 3977)
+			// Standard Error: 3_912
+			.saturating_add(Weight::from_parts(1_173_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetRewardsValidator` (r:1 w:0)
+	/// Proof: `Network::SubnetRewardsValidator` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodesData` (r:1 w:1)
+	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalActiveSubnetNodes` (r:1 w:1)
+	/// Proof: `Network::TotalActiveSubnetNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::DeactivateSubnetNodeLedger` (r:0 w:1)
+	/// Proof: `Network::DeactivateSubnetNodeLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn deactivate_subnet_node() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1342`
+		//  Estimated: `4807`
+		// Minimum execution time: 25_410_000 picoseconds.
+		Weight::from_parts(27_102_000, 4807)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Network::MaxSubnetNodeDeactivationEpochs` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetNodeDeactivationEpochs` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodesData` (r:1 w:1)
+	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::DeactivateSubnetNodeLedger` (r:1 w:1)
+	/// Proof: `Network::DeactivateSubnetNodeLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalActiveSubnetNodes` (r:1 w:1)
+	/// Proof: `Network::TotalActiveSubnetNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reactivate_subnet_node() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1511`
+		//  Estimated: `4976`
+		// Minimum execution time: 26_880_000 picoseconds.
+		Weight::from_parts(28_934_000, 4976)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetPermissioned` (r:1 w:0)
+	/// Proof: `Network::SubnetPermissioned` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetNodes` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetRegistrationPeerWhitelist` (r:0 w:1)
+	/// Proof: `Network::SubnetRegistrationPeerWhitelist` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn owner_set_registration_peer_whitelist(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512 + n * (0 ±0)`
+		//  Estimated: `3977 + n * (0 ±0)`
+		// Minimum execution time: 20_113_000 picoseconds.
+		Weight::from_parts(20_651_000, 3977)
+			// Standard Error: 5_106
+			.saturating_add(Weight::from_parts(1_842_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Network::SubnetPaths` (r:2 w:1)
+	/// Proof: `Network::SubnetPaths` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalSubnetMemoryMB` (r:1 w:1)
+	/// Proof: `Network::TotalSubnetMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxTotalSubnetMemoryMB` (r:1 w:0)
+	/// Proof: `Network::MaxTotalSubnetMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetsData` (r:1 w:1)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnets` (r:1 w:0)
+	/// Proof: `Network::MaxSubnets` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MinSubnetRegistrationBlocks` (r:1 w:0)
+	/// Proof: `Network::MinSubnetRegistrationBlocks` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetRegistrationBlocks` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetRegistrationBlocks` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetMemoryMB` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Network::StakeVaultBalance` (r:1 w:1)
+	/// Proof: `Network::StakeVaultBalance` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalSubnets` (r:1 w:1)
+	/// Proof: `Network::TotalSubnets` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::BaseSubnetNodeMemoryMB` (r:1 w:0)
+	/// Proof: `Network::BaseSubnetNodeMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MinNodesCurveParameters` (r:1 w:0)
+	/// Proof: `Network::MinNodesCurveParameters` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MinSubnetNodes` (r:1 w:0)
+	/// Proof: `Network::MinSubnetNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TargetSubnetNodesMultiplier` (r:1 w:0)
+	/// Proof: `Network::TargetSubnetNodesMultiplier` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetNodes` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetRegistrationWhitelist` (r:0 w:1)
+	/// Proof: `Network::SubnetRegistrationWhitelist` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetRegistrationPeerWhitelist` (r:0 w:1)
+	/// Proof: `Network::SubnetRegistrationPeerWhitelist` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 1000]`.
+	/// The range of component `p` is `[1, 1000]`.
+	fn register_subnet_with_whitelist(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `616 + n * (0 ±0) + p * (0 ±0)`
+		//  Estimated: `4081 + n * (0 ±0) + p * (0 ±0)`
+		// Minimum execution time: 64_307_000 picoseconds.
+		Weight::from_parts(65_218_000, 4081)
+			// Standard Error: 4_027
+			.saturating_add(Weight::from_parts(1_181_000, 0).saturating_mul(n.into()))
+			// Standard Error: 5_219
+			.saturating_add(Weight::from_parts(1_856_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 }
 
@@ -843,14 +1372,35 @@ impl WeightInfo for () {
 	/// Proof: `Network::DelegateStakeUnbondingLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn claim_delegate_stake_unbondings() -> Weight {
+	/// The range of component `u` is `[1, 32]`.
+	fn claim_delegate_stake_unbondings(u: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `794`
-		//  Estimated: `4259`
-		// Minimum execution time: 34_920_000 picoseconds.
-		Weight::from_parts(49_285_000, 4259)
+		//  Measured:  `794 + u * (32 ±0)`
+		//  Estimated: `4259 + u * (32 ±0)`
+		// Minimum execution time: 36_104_000 picoseconds.
+		Weight::from_parts(38_917_000, 4259)
+			// Standard Error: 2_204
+			.saturating_add(Weight::from_parts(1_412_000, 0).saturating_mul(u.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(u.into()))
+	}
+	/// Storage: `Network::SubnetStakeUnbondingLedger` (r:1 w:1)
+	/// Proof: `Network::SubnetStakeUnbondingLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `u` is `[1, 32]`.
+	fn claim_stake_unbondings(u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812 + u * (32 ±0)`
+		//  Estimated: `4277 + u * (32 ±0)`
+		// Minimum execution time: 36_388_000 picoseconds.
+		Weight::from_parts(39_206_000, 4277)
+			// Standard Error: 2_317
+			.saturating_add(Weight::from_parts(1_436_000, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(u.into()))
 	}
 	/// Storage: `Network::SubnetsData` (r:1 w:0)
 	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -977,7 +1527,7 @@ impl WeightInfo for () {
 	/// Proof: `Network::ProposalQuorum` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::ProposalConsensusThreshold` (r:1 w:0)
 	/// Proof: `Network::ProposalConsensusThreshold` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Network::SubnetNodesData` (r:65 w:1)
+	/// Storage: `Network::SubnetNodesData` (r:255 w:1)
 	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetRewardsSubmission` (r:1 w:1)
 	/// Proof: `Network::SubnetRewardsSubmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -991,13 +1541,497 @@ impl WeightInfo for () {
 	/// Proof: `Network::SubnetNodeAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Network::SubnetNodePenalties` (r:0 w:1)
 	/// Proof: `Network::SubnetNodePenalties` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn finalize_proposal() -> Weight {
+	/// The range of component `n` is `[16, 254]`.
+	fn finalize_proposal(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2117 + n * (448 ±0)`
+		//  Estimated: `12780 + n * (2603 ±0)`
+		// Minimum execution time: 318_475_000 picoseconds.
+		Weight::from_parts(94_118_000, 12780)
+			// Standard Error: 64_913
+			.saturating_add(Weight::from_parts(17_542_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `Network::StakeVaultBalance` (r:1 w:0)
+	/// Storage: `Network::SubnetsData` (r:17 w:0)
+	/// Storage: `Network::SubnetRewardsValidator` (r:16 w:0)
+	/// Storage: `Network::TotalSubnetDelegateStakeBalance` (r:16 w:0)
+	/// Storage: `Network::EpochSubnetEmissions` (r:0 w:16)
+	/// The range of component `s` is `[1, 16]`.
+	fn begin_epoch_rewards(s: u32, ) -> Weight {
+		// Minimum execution time: 18_412_000 picoseconds.
+		Weight::from_parts(19_870_000, 3553)
+			// Standard Error: 4_101
+			.saturating_add(Weight::from_parts(14_266_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 7500).saturating_mul(s.into()))
+	}
+	/// Storage: `Network::EpochSubnetEmissions` (r:1 w:1)
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Storage: `Network::SubnetRewardsSubmission` (r:1 w:0)
+	/// Storage: `Network::SubnetNodesData` (r:255 w:0)
+	/// Storage: `Network::SubnetNodePenalties` (r:254 w:254)
+	/// Storage: `Network::AccountSubnetStake` (r:255 w:255)
+	/// Storage: `Network::TotalAccountStake` (r:254 w:254)
+	/// Storage: `Network::TotalSubnetStake` (r:1 w:1)
+	/// Storage: `Network::TotalStake` (r:1 w:1)
+	/// Storage: `Network::SubnetRewardsReceipts` (r:0 w:2)
+	/// The range of component `n` is `[12, 254]`.
+	fn reward_subnet(n: u32, ) -> Weight {
+		// Minimum execution time: 112_530_000 picoseconds.
+		Weight::from_parts(121_604_000, 9840)
+			// Standard Error: 38_215
+			.saturating_add(Weight::from_parts(47_382_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10420).saturating_mul(n.into()))
+	}
+	/// Storage: `Network::StakeVaultBalance` (r:1 w:1)
+	/// Storage: `Network::UnpaidEmissions` (r:1 w:0)
+	fn finish_epoch_rewards() -> Weight {
+		// Minimum execution time: 11_301_000 picoseconds.
+		Weight::from_parts(12_045_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Storage: `Network::TotalSubnetDelegateStakeBalance` (r:1 w:0)
+	/// Storage: `Network::SubnetNodesData` (r:255 w:0)
+	/// Storage: `Network::SubnetPenaltyCount` (r:1 w:1)
+	/// Storage: `Network::SubnetRewardsValidator` (r:0 w:1)
+	/// The range of component `n` is `[12, 254]`.
+	fn subnet_epoch_preliminaries(n: u32, ) -> Weight {
+		// Minimum execution time: 64_980_000 picoseconds.
+		Weight::from_parts(70_112_000, 5204)
+			// Standard Error: 12_480
+			.saturating_add(Weight::from_parts(9_813_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2860).saturating_mul(n.into()))
+	}
+	/// Storage: `Network::Proposals` (r:1 w:1)
+	/// Proof: `Network::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodesData` (r:1 w:0)
+	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::ChallengePeriod` (r:1 w:0)
+	/// Proof: `Network::ChallengePeriod` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn attest_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3318`
+		//  Estimated: `6783`
+		// Minimum execution time: 44_715_000 picoseconds.
+		Weight::from_parts(48_902_000, 6783)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Network::Proposals` (r:1 w:0)
+	/// Proof: `Network::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::ProposalAppeals` (r:1 w:1)
+	/// Proof: `Network::ProposalAppeals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::AppealPeriod` (r:1 w:0)
+	/// Proof: `Network::AppealPeriod` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::AppealBondAmount` (r:1 w:0)
+	/// Proof: `Network::AppealBondAmount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn appeal_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3906`
+		//  Estimated: `7371`
+		// Minimum execution time: 58_231_000 picoseconds.
+		Weight::from_parts(62_480_000, 7371)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Network::Proposals` (r:1 w:1)
+	/// Proof: `Network::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::ProposalAppeals` (r:1 w:0)
+	/// Proof: `Network::ProposalAppeals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::AppealPeriod` (r:1 w:0)
+	/// Proof: `Network::AppealPeriod` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodesData` (r:1 w:1)
+	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalSubnetNodes` (r:1 w:1)
+	/// Proof: `Network::TotalSubnetNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalActiveSubnetNodes` (r:1 w:1)
+	/// Proof: `Network::TotalActiveSubnetNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:253 w:253)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Network::SubnetNodeAccount` (r:0 w:1)
+	/// Proof: `Network::SubnetNodeAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodePenalties` (r:0 w:1)
+	/// Proof: `Network::SubnetNodePenalties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[16, 254]`.
+	fn enact_proposal(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2508 + n * (448 ±0)`
+		//  Estimated: `8517 + n * (2603 ±0)`
+		// Minimum execution time: 131_260_000 picoseconds.
+		Weight::from_parts(98_410_000, 8517)
+			// Standard Error: 41_087
+			.saturating_add(Weight::from_parts(8_913_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:1)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetPenaltyCount` (r:1 w:1)
+	/// Proof: `Network::SubnetPenaltyCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetPenaltyCount` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetPenaltyCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetPaths` (r:1 w:1)
+	/// Proof: `Network::SubnetPaths` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodesData` (r:13 w:12)
+	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalSubnetMemoryMB` (r:1 w:1)
+	/// Proof: `Network::TotalSubnetMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn remove_subnet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6214`
+		//  Estimated: `38579`
+		// Minimum execution time: 402_917_000 picoseconds.
+		Weight::from_parts(418_365_000, 38579)
+			.saturating_add(RocksDbWeight::get().reads(41_u64))
+			.saturating_add(RocksDbWeight::get().writes(58_u64))
+	}
+	/// Storage: `Network::EpochProcessing` (r:1 w:0)
+	/// Proof: `Network::EpochProcessing` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::EpochProcessingNodesPerBlock` (r:1 w:0)
+	/// Proof: `Network::EpochProcessingNodesPerBlock` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_initialize() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1527`
+		// Minimum execution time: 3_912_000 picoseconds.
+		Weight::from_parts(4_180_000, 1527)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `Network::SubnetTeardowns` (r:1 w:1)
+	/// Proof: `Network::SubnetTeardowns` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetTeardownItemsPerCall` (r:1 w:0)
+	/// Proof: `Network::SubnetTeardownItemsPerCall` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::AccountSubnetStake` (r:257 w:256)
+	/// Proof: `Network::AccountSubnetStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalAccountStake` (r:256 w:256)
+	/// Proof: `Network::TotalAccountStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalSubnetStake` (r:1 w:1)
+	/// Proof: `Network::TotalSubnetStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalStake` (r:1 w:1)
+	/// Proof: `Network::TotalStake` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetStakeUnbondingLedger` (r:256 w:256)
+	/// Proof: `Network::SubnetStakeUnbondingLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 256]`.
+	fn clear_subnet(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `366 + n * (143 ±0)`
+		//  Estimated: `3831 + n * (2618 ±0)`
+		// Minimum execution time: 18_734_000 picoseconds.
+		Weight::from_parts(21_305_000, 3831)
+			// Standard Error: 9_412
+			.saturating_add(Weight::from_parts(24_871_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2618).saturating_mul(n.into()))
+	}
+	/// Storage: `Network::AccountPenaltyCount` (r:1 w:1)
+	/// Proof: `Network::AccountPenaltyCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxAccountPenaltyCount` (r:1 w:0)
+	/// Proof: `Network::MaxAccountPenaltyCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetsData` (r:17 w:0)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodesData` (r:16 w:16)
+	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetRewardsSubmission` (r:16 w:16)
+	/// Proof: `Network::SubnetRewardsSubmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalSubnetNodes` (r:16 w:16)
+	/// Proof: `Network::TotalSubnetNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalActiveSubnetNodes` (r:16 w:16)
+	/// Proof: `Network::TotalActiveSubnetNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::AccountPenaltyBanEpochs` (r:1 w:0)
+	/// Proof: `Network::AccountPenaltyBanEpochs` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodeAccount` (r:0 w:16)
+	/// Proof: `Network::SubnetNodeAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodePenalties` (r:0 w:16)
+	/// Proof: `Network::SubnetNodePenalties` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::AccountBans` (r:0 w:1)
+	/// Proof: `Network::AccountBans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 16]`.
+	fn remove_account_subnet_nodes(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1248 + s * (612 ±0)`
+		//  Estimated: `4713 + s * (2835 ±0)`
+		// Minimum execution time: 41_503_000 picoseconds.
+		Weight::from_parts(38_271_000, 4713)
+			// Standard Error: 14_207
+			.saturating_add(Weight::from_parts(101_845_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2835).saturating_mul(s.into()))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:1)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetMemoryMB` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalSubnetMemoryMB` (r:1 w:1)
+	/// Proof: `Network::TotalSubnetMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxTotalSubnetMemoryMB` (r:1 w:0)
+	/// Proof: `Network::MaxTotalSubnetMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MinSubnetNodes` (r:1 w:0)
+	/// Proof: `Network::MinSubnetNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetNodes` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn owner_update_subnet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `624`
+		//  Estimated: `4089`
+		// Minimum execution time: 27_114_000 picoseconds.
+		Weight::from_parts(28_390_000, 4089)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:1)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_subnet_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 15_826_000 picoseconds.
+		Weight::from_parts(16_437_000, 3977)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:1)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn renounce_subnet_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 15_102_000 picoseconds.
+		Weight::from_parts(15_918_000, 3977)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetMetadataRevision` (r:1 w:1)
+	/// Proof: `Network::SubnetMetadataRevision` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetsMetadata` (r:0 w:1)
+	/// Proof: `Network::SubnetsMetadata` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn owner_set_subnet_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 22_640_000 picoseconds.
+		Weight::from_parts(23_581_000, 3977)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Network::SubnetNodesData` (r:1 w:1)
+	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodeParamsLastUpdate` (r:1 w:1)
+	/// Proof: `Network::SubnetNodeParamsLastUpdate` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodeParamsUpdateInterval` (r:1 w:0)
+	/// Proof: `Network::SubnetNodeParamsUpdateInterval` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn update_subnet_node_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `795`
+		//  Estimated: `4260`
+		// Minimum execution time: 26_912_000 picoseconds.
+		Weight::from_parts(28_744_000, 4260)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:2 w:1)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetPauseBond` (r:1 w:0)
+	/// Proof: `Network::SubnetPauseBond` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Network::SubnetPauseBonds` (r:0 w:1)
+	/// Proof: `Network::SubnetPauseBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn owner_pause_subnet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `743`
+		//  Estimated: `4208`
+		// Minimum execution time: 39_806_000 picoseconds.
+		Weight::from_parts(41_219_000, 4208)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:2 w:1)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetPauseBonds` (r:1 w:1)
+	/// Proof: `Network::SubnetPauseBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MinSubnetPauseEpochs` (r:1 w:0)
+	/// Proof: `Network::MinSubnetPauseEpochs` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn owner_unpause_subnet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `889`
+		//  Estimated: `4354`
+		// Minimum execution time: 39_462_000 picoseconds.
+		Weight::from_parts(41_305_000, 4354)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetPermissioned` (r:1 w:0)
+	/// Proof: `Network::SubnetPermissioned` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetNodes` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetRegistrationWhitelist` (r:0 w:1)
+	/// Proof: `Network::SubnetRegistrationWhitelist` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn owner_set_registration_whitelist(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512 + n * (0 ±0)`
+		//  Estimated: `3977 + n * (0 ±0)`
+		// Minimum execution time: 19_884_000 picoseconds.
+		Weight::from_parts(20_417_000, 3977)
+			// Standard Error: 3_912
+			.saturating_add(Weight::from_parts(1_173_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetRewardsValidator` (r:1 w:0)
+	/// Proof: `Network::SubnetRewardsValidator` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodesData` (r:1 w:1)
+	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalActiveSubnetNodes` (r:1 w:1)
+	/// Proof: `Network::TotalActiveSubnetNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::DeactivateSubnetNodeLedger` (r:0 w:1)
+	/// Proof: `Network::DeactivateSubnetNodeLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn deactivate_subnet_node() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1342`
+		//  Estimated: `4807`
+		// Minimum execution time: 25_410_000 picoseconds.
+		Weight::from_parts(27_102_000, 4807)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Network::MaxSubnetNodeDeactivationEpochs` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetNodeDeactivationEpochs` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetNodesData` (r:1 w:1)
+	/// Proof: `Network::SubnetNodesData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::DeactivateSubnetNodeLedger` (r:1 w:1)
+	/// Proof: `Network::DeactivateSubnetNodeLedger` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalActiveSubnetNodes` (r:1 w:1)
+	/// Proof: `Network::TotalActiveSubnetNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn reactivate_subnet_node() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1511`
+		//  Estimated: `4976`
+		// Minimum execution time: 26_880_000 picoseconds.
+		Weight::from_parts(28_934_000, 4976)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Network::SubnetsData` (r:1 w:0)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetOwner` (r:1 w:0)
+	/// Proof: `Network::SubnetOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetPermissioned` (r:1 w:0)
+	/// Proof: `Network::SubnetPermissioned` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetNodes` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetRegistrationPeerWhitelist` (r:0 w:1)
+	/// Proof: `Network::SubnetRegistrationPeerWhitelist` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn owner_set_registration_peer_whitelist(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512 + n * (0 ±0)`
+		//  Estimated: `3977 + n * (0 ±0)`
+		// Minimum execution time: 20_113_000 picoseconds.
+		Weight::from_parts(20_651_000, 3977)
+			// Standard Error: 5_106
+			.saturating_add(Weight::from_parts(1_842_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Network::SubnetPaths` (r:2 w:1)
+	/// Proof: `Network::SubnetPaths` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalSubnetMemoryMB` (r:1 w:1)
+	/// Proof: `Network::TotalSubnetMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxTotalSubnetMemoryMB` (r:1 w:0)
+	/// Proof: `Network::MaxTotalSubnetMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetsData` (r:1 w:1)
+	/// Proof: `Network::SubnetsData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnets` (r:1 w:0)
+	/// Proof: `Network::MaxSubnets` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MinSubnetRegistrationBlocks` (r:1 w:0)
+	/// Proof: `Network::MinSubnetRegistrationBlocks` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetRegistrationBlocks` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetRegistrationBlocks` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetMemoryMB` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Network::StakeVaultBalance` (r:1 w:1)
+	/// Proof: `Network::StakeVaultBalance` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TotalSubnets` (r:1 w:1)
+	/// Proof: `Network::TotalSubnets` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::BaseSubnetNodeMemoryMB` (r:1 w:0)
+	/// Proof: `Network::BaseSubnetNodeMemoryMB` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MinNodesCurveParameters` (r:1 w:0)
+	/// Proof: `Network::MinNodesCurveParameters` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MinSubnetNodes` (r:1 w:0)
+	/// Proof: `Network::MinSubnetNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::TargetSubnetNodesMultiplier` (r:1 w:0)
+	/// Proof: `Network::TargetSubnetNodesMultiplier` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::MaxSubnetNodes` (r:1 w:0)
+	/// Proof: `Network::MaxSubnetNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetRegistrationWhitelist` (r:0 w:1)
+	/// Proof: `Network::SubnetRegistrationWhitelist` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Network::SubnetRegistrationPeerWhitelist` (r:0 w:1)
+	/// Proof: `Network::SubnetRegistrationPeerWhitelist` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 1000]`.
+	/// The range of component `p` is `[1, 1000]`.
+	fn register_subnet_with_whitelist(n: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `30407`
-		//  Estimated: `192272`
-		// Minimum execution time: 1_140_336_000 picoseconds.
-		Weight::from_parts(1_151_861_000, 192272)
-			.saturating_add(RocksDbWeight::get().reads(135_u64))
-			.saturating_add(RocksDbWeight::get().writes(70_u64))
+		//  Measured:  `616 + n * (0 ±0) + p * (0 ±0)`
+		//  Estimated: `4081 + n * (0 ±0) + p * (0 ±0)`
+		// Minimum execution time: 64_307_000 picoseconds.
+		Weight::from_parts(65_218_000, 4081)
+			// Standard Error: 4_027
+			.saturating_add(Weight::from_parts(1_181_000, 0).saturating_mul(n.into()))
+			// Standard Error: 5_219
+			.saturating_add(Weight::from_parts(1_856_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
-}
\ No newline at end of file
+}
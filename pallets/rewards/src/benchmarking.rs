@@ -0,0 +1,39 @@
+//! Benchmarking setup for pallet-rewards
+// ./target/release/solochain-template-node benchmark pallet --chain=dev --wasm-execution=compiled --pallet=pallet_rewards --extrinsic=* --steps=5 --repeat=2 --output="pallets/rewards/src/weights.rs" --template ./.maintain/frame-weight-template.hbs
+
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Rewards;
+use frame_benchmarking::v2::*;
+use frame_support::BoundedVec;
+use frame_system::RawOrigin;
+
+#[benchmarks]
+mod benchmarks {
+  use super::*;
+
+  #[benchmark]
+  fn set_validator_reward_percent() {
+    #[extrinsic_call]
+    set_validator_reward_percent(RawOrigin::Root, 50);
+
+    assert_eq!(ValidatorRewardPercent::<T>::get(), 50);
+  }
+
+  #[benchmark]
+  fn set_emission_curve(p: Linear<1, 32>) {
+    // --- Replace a curve of the same kind with ``p`` points
+    let points: BoundedVec<(u64, Perbill), crate::EmissionCurvePointsLimit> = BoundedVec::truncate_from(
+      (1..=p).map(|i| (i as u64 * 1000, Perbill::from_rational(i, p))).collect()
+    );
+    let curve = EmissionCurve::TargetSupply { target_supply: 1_000_000_000, points: points.clone() };
+    ActiveEmissionCurve::<T>::put(EmissionCurve::TargetSupply { target_supply: 1, points: points });
+
+    #[extrinsic_call]
+    set_emission_curve(RawOrigin::Root, curve.clone());
+
+    assert_eq!(ActiveEmissionCurve::<T>::get(), curve);
+  }
+}
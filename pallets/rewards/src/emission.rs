@@ -0,0 +1,318 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode};
+use frame_support::{
+  pallet_prelude::{ConstU32, RuntimeDebug},
+  sp_runtime::{
+    helpers_128bit::multiply_by_rational_with_rounding, traits::Saturating, PerThing, Perbill, Rounding,
+  },
+  BoundedVec,
+};
+use scale_info::TypeInfo;
+
+/// Maximum number of points in a ``EmissionCurve::TargetSupply`` curve
+pub type EmissionCurvePointsLimit = ConstU32<32>;
+
+/// Halvings after which the ``EmissionCurve::Halving`` subsidy is zero
+pub const MAX_HALVINGS: u64 = 64;
+
+/// Block subsidy schedule
+///
+/// Blocks are counted from the block the curve was started on, see ``EmissionCurveStartBlock``
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum EmissionCurve {
+  /// Subsidy is halved every ``interval`` blocks
+  Halving {
+    initial_subsidy: u128,
+    interval: u64,
+  },
+  /// Subsidy is reduced by ``decay`` of itself every ``interval`` blocks
+  ExponentialDecay {
+    initial_subsidy: u128,
+    interval: u64,
+    decay: Perbill,
+  },
+  /// Cumulative issuance of the curve is linearly interpolated between ``(block, fraction of target_supply)`` points,
+  /// starting from ``(0, 0)``
+  //
+  // Blocks must be strictly increasing and fractions must be non-decreasing
+  // No subsidy is issued after the last point
+  TargetSupply {
+    target_supply: u128,
+    points: BoundedVec<(u64, Perbill), EmissionCurvePointsLimit>,
+  },
+}
+
+impl EmissionCurve {
+  /// Whether the curve parameters are usable
+  pub fn is_valid(&self) -> bool {
+    match self {
+      EmissionCurve::Halving { interval, .. } => *interval > 0,
+      EmissionCurve::ExponentialDecay { interval, .. } => *interval > 0,
+      EmissionCurve::TargetSupply { points, .. } => {
+        if points.is_empty() {
+          return false
+        }
+        let mut prev: (u64, Perbill) = (0, Perbill::zero());
+        for point in points.iter() {
+          if point.0 <= prev.0 || point.1 < prev.1 {
+            return false
+          }
+          prev = *point;
+        }
+        true
+      },
+    }
+  }
+
+  /// Whether both curves are of the same kind
+  //
+  // Only parameters may be updated by governance, switching the kind of curve requires a migration
+  pub fn is_same_kind(&self, other: &EmissionCurve) -> bool {
+    core::mem::discriminant(self) == core::mem::discriminant(other)
+  }
+
+  /// Subsidy of the ``n``th block of the curve
+  pub fn block_subsidy(&self, n: u64) -> u128 {
+    match self {
+      EmissionCurve::Halving { initial_subsidy, interval } => {
+        let halvings: u64 = n / interval.max(&1);
+        if halvings >= MAX_HALVINGS {
+          return 0
+        }
+        initial_subsidy >> halvings
+      },
+      EmissionCurve::ExponentialDecay { initial_subsidy, interval, decay } => {
+        let intervals: u64 = n / interval.max(&1);
+        Self::decayed(*initial_subsidy, *decay, intervals)
+      },
+      EmissionCurve::TargetSupply { .. } => {
+        self.cumulative_issuance(n.saturating_add(1)).saturating_sub(self.cumulative_issuance(n))
+      },
+    }
+  }
+
+  /// Total subsidy of the first ``n`` blocks of the curve
+  //
+  // ``ExponentialDecay`` is computed in closed form and may differ from the sum of ``block_subsidy`` by rounding
+  pub fn cumulative_issuance(&self, n: u64) -> u128 {
+    match self {
+      EmissionCurve::Halving { initial_subsidy, interval } => {
+        let interval: u64 = *interval.max(&1);
+        let halvings: u64 = n / interval;
+        let remainder: u64 = n % interval;
+
+        let mut issuance: u128 = 0;
+        for halving in 0..halvings.min(MAX_HALVINGS) {
+          issuance = issuance.saturating_add((initial_subsidy >> halving).saturating_mul(interval as u128));
+        }
+        if halvings < MAX_HALVINGS {
+          issuance = issuance.saturating_add((initial_subsidy >> halvings).saturating_mul(remainder as u128));
+        }
+        issuance
+      },
+      EmissionCurve::ExponentialDecay { initial_subsidy, interval, decay } => {
+        let interval: u64 = *interval.max(&1);
+        let intervals: u64 = n / interval;
+        let remainder: u64 = n % interval;
+
+        let per_interval: u128 = initial_subsidy.saturating_mul(interval as u128);
+
+        // sum(r^i, 0..k) = (1 - r^k) / (1 - r)
+        let issuance: u128 = if decay.is_zero() {
+          per_interval.saturating_mul(intervals as u128)
+        } else {
+          let retained: Perbill = Self::retained(*decay, intervals);
+          decay.saturating_reciprocal_mul((Perbill::one() - retained) * per_interval)
+        };
+
+        issuance.saturating_add(
+          Self::decayed(*initial_subsidy, *decay, intervals).saturating_mul(remainder as u128)
+        )
+      },
+      EmissionCurve::TargetSupply { target_supply, points } => {
+        let mut prev: (u64, Perbill) = (0, Perbill::zero());
+        for point in points.iter() {
+          if n <= point.0 {
+            let prev_issuance: u128 = prev.1 * *target_supply;
+            let delta: u128 = (point.1 * *target_supply).saturating_sub(prev_issuance);
+            return prev_issuance.saturating_add(
+              multiply_by_rational_with_rounding(
+                delta,
+                (n - prev.0) as u128,
+                (point.0.saturating_sub(prev.0)).max(1) as u128,
+                Rounding::Down
+              ).unwrap_or(0)
+            )
+          }
+          prev = *point;
+        }
+        prev.1 * *target_supply
+      },
+    }
+  }
+
+  /// Number of ``TargetSupply`` points, ``0`` for other curves
+  pub fn points_len(&self) -> u32 {
+    match self {
+      EmissionCurve::TargetSupply { points, .. } => points.len() as u32,
+      _ => 0,
+    }
+  }
+
+  /// Blocks between subsidy reductions, ``0`` if the curve has no fixed interval
+  pub fn interval(&self) -> u64 {
+    match self {
+      EmissionCurve::Halving { interval, .. } => *interval,
+      EmissionCurve::ExponentialDecay { interval, .. } => *interval,
+      EmissionCurve::TargetSupply { .. } => 0,
+    }
+  }
+
+  /// ``(1 - decay)^intervals``
+  fn retained(decay: Perbill, intervals: u64) -> Perbill {
+    (Perbill::one() - decay).saturating_pow(intervals.min(u32::MAX as u64) as usize)
+  }
+
+  fn decayed(initial_subsidy: u128, decay: Perbill, intervals: u64) -> u128 {
+    Self::retained(decay, intervals) * initial_subsidy
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn target_supply(points: Vec<(u64, Perbill)>) -> EmissionCurve {
+    EmissionCurve::TargetSupply { target_supply: 1_000_000, points: BoundedVec::truncate_from(points) }
+  }
+
+  #[test]
+  fn test_halving() {
+    let curve = EmissionCurve::Halving { initial_subsidy: 1000, interval: 10 };
+
+    assert_eq!(curve.block_subsidy(0), 1000);
+    assert_eq!(curve.block_subsidy(9), 1000);
+    assert_eq!(curve.block_subsidy(10), 500);
+    assert_eq!(curve.block_subsidy(25), 250);
+    assert_eq!(curve.block_subsidy(MAX_HALVINGS * 10), 0);
+
+    assert_eq!(curve.cumulative_issuance(0), 0);
+    assert_eq!(curve.cumulative_issuance(10), 10000);
+    assert_eq!(curve.cumulative_issuance(15), 12500);
+    assert_eq!(curve.cumulative_issuance(25), 16250);
+    // 10 * (1000 + 500 + 250 + 125 + 62 + 31 + 15 + 7 + 3 + 1)
+    assert_eq!(curve.cumulative_issuance(10000), 19940);
+  }
+
+  #[test]
+  fn test_halving_saturates() {
+    let curve = EmissionCurve::Halving { initial_subsidy: u128::MAX, interval: u64::MAX };
+
+    assert_eq!(curve.block_subsidy(u64::MAX), u128::MAX >> 1);
+    assert_eq!(curve.cumulative_issuance(u64::MAX), u128::MAX);
+  }
+
+  #[test]
+  fn test_exponential_decay() {
+    let curve = EmissionCurve::ExponentialDecay {
+      initial_subsidy: 1_000_000,
+      interval: 10,
+      decay: Perbill::from_percent(50),
+    };
+
+    assert_eq!(curve.block_subsidy(0), 1_000_000);
+    assert_eq!(curve.block_subsidy(10), 500_000);
+    assert_eq!(curve.block_subsidy(20), 250_000);
+
+    assert_eq!(curve.cumulative_issuance(0), 0);
+    assert_eq!(curve.cumulative_issuance(20), 15_000_000);
+    assert_eq!(curve.cumulative_issuance(25), 16_250_000);
+
+    // Converges to ``initial_subsidy * interval / decay``
+    assert_eq!(curve.block_subsidy(u64::MAX), 0);
+    assert_eq!(curve.cumulative_issuance(u64::MAX), 20_000_000);
+  }
+
+  #[test]
+  fn test_exponential_decay_zero_decay() {
+    let curve = EmissionCurve::ExponentialDecay {
+      initial_subsidy: 100,
+      interval: 10,
+      decay: Perbill::zero(),
+    };
+
+    assert_eq!(curve.block_subsidy(1000), 100);
+    assert_eq!(curve.cumulative_issuance(35), 3500);
+  }
+
+  #[test]
+  fn test_exponential_decay_matches_block_subsidy() {
+    let curve = EmissionCurve::ExponentialDecay {
+      initial_subsidy: 1_000_000_000_000,
+      interval: 1,
+      decay: Perbill::from_percent(10),
+    };
+
+    let sum: u128 = (0..5).map(|n| curve.block_subsidy(n)).sum();
+    assert_eq!(sum, 4_095_100_000_000);
+    assert_eq!(curve.cumulative_issuance(5), sum);
+  }
+
+  #[test]
+  fn test_target_supply() {
+    let curve = target_supply(vec![(100, Perbill::from_percent(50)), (300, Perbill::one())]);
+
+    assert_eq!(curve.cumulative_issuance(0), 0);
+    assert_eq!(curve.cumulative_issuance(50), 250_000);
+    assert_eq!(curve.cumulative_issuance(100), 500_000);
+    assert_eq!(curve.cumulative_issuance(200), 750_000);
+    assert_eq!(curve.cumulative_issuance(300), 1_000_000);
+    assert_eq!(curve.cumulative_issuance(1000), 1_000_000);
+
+    assert_eq!(curve.block_subsidy(0), 5000);
+    assert_eq!(curve.block_subsidy(99), 5000);
+    assert_eq!(curve.block_subsidy(150), 2500);
+    assert_eq!(curve.block_subsidy(299), 2500);
+    assert_eq!(curve.block_subsidy(300), 0);
+  }
+
+  #[test]
+  fn test_target_supply_flat_segment() {
+    let curve = target_supply(vec![(100, Perbill::from_percent(50)), (200, Perbill::from_percent(50))]);
+
+    assert!(curve.is_valid());
+    assert_eq!(curve.block_subsidy(150), 0);
+    assert_eq!(curve.cumulative_issuance(200), 500_000);
+  }
+
+  #[test]
+  fn test_is_valid() {
+    assert!(EmissionCurve::Halving { initial_subsidy: 1, interval: 1 }.is_valid());
+    assert!(!EmissionCurve::Halving { initial_subsidy: 1, interval: 0 }.is_valid());
+    assert!(!EmissionCurve::ExponentialDecay {
+      initial_subsidy: 1,
+      interval: 0,
+      decay: Perbill::from_percent(10),
+    }.is_valid());
+
+    assert!(target_supply(vec![(100, Perbill::from_percent(50))]).is_valid());
+    assert!(!target_supply(vec![]).is_valid());
+    assert!(!target_supply(vec![(0, Perbill::from_percent(50))]).is_valid());
+    assert!(!target_supply(vec![(100, Perbill::from_percent(10)), (100, Perbill::from_percent(20))]).is_valid());
+    assert!(!target_supply(vec![(100, Perbill::from_percent(20)), (200, Perbill::from_percent(10))]).is_valid());
+  }
+}
@@ -17,9 +17,16 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-// pub mod weights;
+pub mod weights;
+pub use weights::WeightInfo;
 
 pub use pallet::*;
+pub mod emission;
+pub use emission::{EmissionCurve, EmissionCurvePointsLimit};
+pub mod migrations;
+mod rewards_info;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 // use frame_support::weights::Weight;
 // use frame_support::pallet_prelude::DispatchResult;
 use frame_system::{
@@ -27,7 +34,7 @@ use frame_system::{
   ensure_signed, ensure_root
 };
 use frame_support::{
-  traits::{Currency, FindAuthor, Get},
+  traits::{Currency, FindAuthor, Get, StorageVersion},
   weights::Weight,
   pallet_prelude::DispatchResult,
   sp_runtime::SaturatedConversion,
@@ -42,9 +49,9 @@ use frame_support::{
 // use frame_support::sp_runtime::BLOCKS_PER_HALVING;
 // use frame_system::pallet_prelude::BLOCKS_PER_HALVING;
 // use node_template_runtime::BLOCKS_PER_HALVING;
-// pub trait WeightInfo {
-//   fn on_initialize() -> Weight;
-// }
+
+/// The current storage version
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -52,7 +59,7 @@ pub mod pallet {
   use frame_support::pallet_prelude::*;
   use frame_support::traits::Currency;
   use frame_support::traits::FindAuthor;
-  use pallet_network::IncreaseStakeVault;
+  use pallet_network::{IncreaseStakeVault, TreasuryInterface};
 
   pub type BalanceOf<T> =
     <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -66,15 +73,23 @@ pub mod pallet {
 
     type IncreaseStakeVault: IncreaseStakeVault;
 
+    /// Receives its share of the block subsidy before the block author and stake vault
+    type Treasury: TreasuryInterface;
+
+    /// Halving interval of the ``EmissionCurve::Halving`` curve used prior to storage version ``1``
     #[pallet::constant]
     type HalvingInterval: Get<u32>;
 
+    /// Initial subsidy of the ``EmissionCurve::Halving`` curve used prior to storage version ``1``
     #[pallet::constant]
     type InitialBlockSubsidy: Get<u128>;
 
+    /// Emission curve of a new chain, see ``ActiveEmissionCurve``
+    type InitialEmissionCurve: Get<EmissionCurve>;
+
     type FindAuthor: FindAuthor<Self::AccountId>;
 
-    // type WeightInfo: WeightInfo;
+    type WeightInfo: WeightInfo;
   }
 
   #[pallet::type_value]
@@ -86,9 +101,30 @@ pub mod pallet {
   #[pallet::getter(fn validator_reward_percent)]
 	pub type ValidatorRewardPercent<T> = StorageValue<_, u32, ValueQuery, DefaultValidatorRewardPercent<T>>;
 
+  #[pallet::type_value]
+  pub fn DefaultEmissionCurve<T: Config>() -> EmissionCurve {
+    T::InitialEmissionCurve::get()
+  }
+
+  /// Block subsidy schedule
+  //
+  // Parameters can be updated by governance, switching to a different kind of curve is done through
+  // ``migrations::SwitchEmissionCurve``
+  #[pallet::storage]
+  pub type ActiveEmissionCurve<T> = StorageValue<_, EmissionCurve, ValueQuery, DefaultEmissionCurve<T>>;
+
+  /// ID of the last ``migrations::SwitchEmissionCurve`` applied
+  #[pallet::storage]
+  pub type EmissionCurveSwitch<T> = StorageValue<_, u32, ValueQuery>;
+
+  /// Block ``ActiveEmissionCurve`` is counted from
+  #[pallet::storage]
+  pub type EmissionCurveStartBlock<T> = StorageValue<_, u64, ValueQuery>;
+
   /// Pallet rewards for issuing rewards to block producers.
   #[pallet::pallet]
 	#[pallet::without_storage_info] /// for testing purpses remove in production
+  #[pallet::storage_version(STORAGE_VERSION)]
   pub struct Pallet<T>(_);
 
   /// `pallet-rewards` events
@@ -100,15 +136,21 @@ pub mod pallet {
       block_author: T::AccountId,
       validator_reward: BalanceOf<T>,
       subnet_nodes_reward: BalanceOf<T>,
+      treasury_reward: BalanceOf<T>,
     },
 
     SetValidatorRewardPercent(u32),
+    SetEmissionCurve(EmissionCurve),
   }
 
   #[pallet::error]
 	pub enum Error<T> {
     ValidatorPercentTooHigh,
     ValidatorPercentTooLow,
+    /// Emission curve parameters are out of bounds
+    InvalidEmissionCurve,
+    /// Emission curves can only be switched to a different kind through a migration
+    EmissionCurveKindMismatch,
   }
 
   #[pallet::hooks]
@@ -124,7 +166,7 @@ pub mod pallet {
   #[pallet::call]
   impl<T: Config> Pallet<T> {
     #[pallet::call_index(1)]
-    #[pallet::weight(0)]
+    #[pallet::weight(T::WeightInfo::set_validator_reward_percent())]
     pub fn set_validator_reward_percent(origin: OriginFor<T>, percent: u32) -> DispatchResult {
       ensure_root(origin)?;
 
@@ -139,13 +181,38 @@ pub mod pallet {
 
       Ok(())
     }
+
+    /// Update the parameters of the active emission curve
+    ///
+    /// The curve must be of the same kind as ``ActiveEmissionCurve`` and keeps its start block
+    #[pallet::call_index(2)]
+    #[pallet::weight(T::WeightInfo::set_emission_curve(curve.points_len()))]
+    pub fn set_emission_curve(origin: OriginFor<T>, curve: EmissionCurve) -> DispatchResult {
+      ensure_root(origin)?;
+
+      ensure!(
+        curve.is_valid(),
+        Error::<T>::InvalidEmissionCurve
+      );
+
+      ensure!(
+        curve.is_same_kind(&ActiveEmissionCurve::<T>::get()),
+        Error::<T>::EmissionCurveKindMismatch
+      );
+
+      ActiveEmissionCurve::<T>::set(curve.clone());
+
+      Self::deposit_event(Event::SetEmissionCurve(curve));
+
+      Ok(())
+    }
   }
 }
 
 impl<T: Config> Pallet<T> {
 
   fn do_initialize(_n: BlockNumberFor<T>) {
-    use pallet_network::IncreaseStakeVault;
+    use pallet_network::{IncreaseStakeVault, TreasuryInterface};
 
     let block_author = T::FindAuthor::find_author(
       frame_system::Pallet::<T>::digest()
@@ -155,8 +222,15 @@ impl<T: Config> Pallet<T> {
       )
       .expect("Block author must always be present; QED");
 
-    let subsidy: BalanceOf<T> = Self::get_block_subsidy(_n);
-    
+    let block_subsidy: BalanceOf<T> = Self::get_block_subsidy(_n);
+
+    // --- Send portion to treasury
+    let treasury_reward: BalanceOf<T> = T::Treasury::deposit_block_subsidy_share(
+      block_subsidy.saturated_into::<u128>()
+    ).saturated_into::<BalanceOf<T>>();
+
+    let subsidy: BalanceOf<T> = block_subsidy - treasury_reward;
+
     let validator_percent = Perbill::from_rational(ValidatorRewardPercent::<T>::get(), 100 as u32);
     let validator_reward = validator_percent * subsidy;
 
@@ -169,28 +243,22 @@ impl<T: Config> Pallet<T> {
     Self::deposit_event(Event::BlockReward {
       block_author,
       validator_reward,
-      subnet_nodes_reward
+      subnet_nodes_reward,
+      treasury_reward,
     });
   }
 
   fn get_block_subsidy(block_number: BlockNumberFor<T>) -> BalanceOf<T> {
-    let halving_interval: u32 = T::HalvingInterval::get();
-
     let block_num_as_u64: u64 = TryInto::try_into(block_number)
       .ok()
       .expect("fn get_block_subsidy block_num_as_u64 Err.");
 
-    let halvings: u64 = block_num_as_u64 / halving_interval as u64;
-
-    if halvings >= 64 {
-      return (0 as u128).saturated_into::<BalanceOf<T>>();
-    }
-
-    let mut initial_block_subsidy: u128 = T::InitialBlockSubsidy::get();
-    initial_block_subsidy >>= halvings;
-
-    let block_subsidy: BalanceOf<T> = initial_block_subsidy.saturated_into::<BalanceOf<T>>();
+    Self::block_subsidy(block_num_as_u64).saturated_into::<BalanceOf<T>>()
+  }
 
-    block_subsidy
+  /// Subsidy of ``block`` under the active emission curve
+  pub fn block_subsidy(block: u64) -> u128 {
+    let n: u64 = block.saturating_sub(EmissionCurveStartBlock::<T>::get());
+    ActiveEmissionCurve::<T>::get().block_subsidy(n)
   }
 }
\ No newline at end of file
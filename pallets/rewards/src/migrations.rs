@@ -0,0 +1,91 @@
+// Copyright (C) Hypertensor.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::traits::{OnRuntimeUpgrade, UncheckedOnRuntimeUpgrade};
+
+/// Moves the block subsidy from the ``HalvingInterval`` and ``InitialBlockSubsidy`` constants into storage
+pub mod v1 {
+  use super::*;
+
+  /// Stores the ``EmissionCurve::Halving`` curve previously derived from ``Config`` constants
+  //
+  // The curve is started at block ``0`` so the subsidy of every block is unchanged
+  pub struct UncheckedMigrateToV1<T>(core::marker::PhantomData<T>);
+
+  impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+    fn on_runtime_upgrade() -> Weight {
+      ActiveEmissionCurve::<T>::put(EmissionCurve::Halving {
+        initial_subsidy: T::InitialBlockSubsidy::get(),
+        interval: T::HalvingInterval::get() as u64,
+      });
+      EmissionCurveStartBlock::<T>::put(0);
+
+      T::DbWeight::get().writes(2)
+    }
+  }
+
+  /// [`UncheckedMigrateToV1`] wrapped in a [`VersionedMigration`](frame_support::migrations::VersionedMigration)
+  /// so it only runs when the on-chain storage version is ``0``
+  pub type MigrateToV1<T> = frame_support::migrations::VersionedMigration<
+    0,
+    1,
+    UncheckedMigrateToV1<T>,
+    Pallet<T>,
+    <T as frame_system::Config>::DbWeight,
+  >;
+}
+
+/// Switches ``ActiveEmissionCurve`` to ``Curve`` starting on the block the runtime upgrade is applied
+//
+// Each switch is given an ``ID`` greater than the last applied, tracked in ``EmissionCurveSwitch``, so it only
+// runs once and leaves the pallets storage version untouched. Curves that fail validation are not applied
+pub struct SwitchEmissionCurve<T, Curve, const ID: u32>(core::marker::PhantomData<(T, Curve)>);
+
+impl<T: Config, Curve: Get<EmissionCurve>, const ID: u32> OnRuntimeUpgrade for SwitchEmissionCurve<T, Curve, ID> {
+  fn on_runtime_upgrade() -> Weight {
+    if EmissionCurveSwitch::<T>::get() >= ID {
+      log::info!(
+        target: "runtime::rewards",
+        "emission curve switch {} already applied, skipping",
+        ID
+      );
+      return T::DbWeight::get().reads(1)
+    }
+
+    let curve: EmissionCurve = Curve::get();
+
+    if !curve.is_valid() {
+      log::warn!(
+        target: "runtime::rewards",
+        "emission curve {:?} failed validation, keeping active curve",
+        curve
+      );
+      return T::DbWeight::get().reads(1)
+    }
+
+    let block: u64 = TryInto::try_into(frame_system::Pallet::<T>::block_number())
+      .ok()
+      .expect("fn on_runtime_upgrade block Err.");
+
+    ActiveEmissionCurve::<T>::put(curve.clone());
+    EmissionCurveStartBlock::<T>::put(block);
+    EmissionCurveSwitch::<T>::put(ID);
+
+    Pallet::<T>::deposit_event(Event::SetEmissionCurve(curve));
+
+    T::DbWeight::get().reads_writes(1, 3)
+  }
+}
@@ -1,17 +1,36 @@
 use super::*;
-use frame_support::pallet_prelude::{Decode, Encode};
-use frame_support::storage::IterableStorageMap;
-extern crate alloc;
-use alloc::vec::Vec;
-use codec::Compact;
-
-#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
-pub struct RewardsParams<T: Config> {
-  subsidy_halving_interval: Compact<u16>,
-}
 
 impl<T: Config> Pallet<T> {
-  pub fn get_subsidy_halving_interval(netuid: u16) -> u64 {
-    return 0;
+  /// Blocks between subsidy reductions of the active emission curve, ``0`` for ``EmissionCurve::TargetSupply``
+  pub fn get_subsidy_halving_interval() -> u64 {
+    ActiveEmissionCurve::<T>::get().interval()
+  }
+
+  pub fn get_emission_curve() -> (EmissionCurve, u64) {
+    (ActiveEmissionCurve::<T>::get(), EmissionCurveStartBlock::<T>::get())
+  }
+
+  /// Total issuance once ``block`` has been initialized, assuming the active emission curve is unchanged
+  ///
+  /// Returns the current total issuance if ``block`` isn't in the future
+  pub fn get_projected_issuance(block: u64) -> u128 {
+    let total_issuance: u128 = T::Currency::total_issuance().saturated_into::<u128>();
+
+    let current_block: u64 = TryInto::try_into(frame_system::Pallet::<T>::block_number())
+      .ok()
+      .expect("fn get_projected_issuance current_block Err.");
+
+    if block <= current_block {
+      return total_issuance
+    }
+
+    let curve: EmissionCurve = ActiveEmissionCurve::<T>::get();
+    let start_block: u64 = EmissionCurveStartBlock::<T>::get();
+
+    // --- Blocks are issued on initialize, ``cumulative_issuance(n)`` covers blocks ``0..n`` of the curve
+    let issued: u128 = curve.cumulative_issuance(current_block.saturating_add(1).saturating_sub(start_block));
+    let projected: u128 = curve.cumulative_issuance(block.saturating_add(1).saturating_sub(start_block));
+
+    total_issuance.saturating_add(projected.saturating_sub(issued))
   }
 }
\ No newline at end of file
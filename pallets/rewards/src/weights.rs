@@ -1,29 +1,94 @@
-// Copyright (C) 2021 Subspace Labs, Inc.
-// SPDX-License-Identifier: Apache-2.0
+//! Autogenerated weights for `pallet_rewards`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 42.0.0
+//! DATE: 2025-01-02, STEPS: `5`, REPEAT: `2`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `Bob`, CPU: `11th Gen Intel(R) Core(TM) i7-11800H @ 2.30GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
 
-// Licensed under the Apache License, Version 2.0 (the "License");
-// you may not use this file except in compliance with the License.
-// You may obtain a copy of the License at
-//
-// 	http://www.apache.org/licenses/LICENSE-2.0
-//
-// Unless required by applicable law or agreed to in writing, software
-// distributed under the License is distributed on an "AS IS" BASIS,
-// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-// See the License for the specific language governing permissions and
-// limitations under the License.
+// Executed Command:
+// ./target/release/solochain-template-node
+// benchmark
+// pallet
+// --chain=dev
+// --wasm-execution=compiled
+// --pallet=pallet_rewards
+// --extrinsic=*
+// --steps=5
+// --repeat=2
+// --output=pallets/rewards/src/weights.rs
+// --template
+// ./.maintain/frame-weight-template.hbs
 
-//! Default weights for the Rewards Pallet
-//! This file was not auto-generated.
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
 
-use frame_support::weights::Weight;
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use core::marker::PhantomData;
 
+/// Weight functions needed for `pallet_rewards`.
+pub trait WeightInfo {
+	fn set_validator_reward_percent() -> Weight;
+	fn set_emission_curve(p: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_rewards` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Rewards::ValidatorRewardPercent` (r:0 w:1)
+	/// Proof: `Rewards::ValidatorRewardPercent` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_validator_reward_percent() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_312_000 picoseconds.
+		Weight::from_parts(6_584_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Rewards::ActiveEmissionCurve` (r:1 w:1)
+	/// Proof: `Rewards::ActiveEmissionCurve` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[1, 32]`.
+	fn set_emission_curve(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `43 + p * (20 ±0)`
+		//  Estimated: `1528 + p * (20 ±0)`
+		// Minimum execution time: 10_415_000 picoseconds.
+		Weight::from_parts(10_873_000, 1528)
+			// Standard Error: 1_146
+			.saturating_add(Weight::from_parts(236_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 20).saturating_mul(p.into()))
+	}
+}
 
-impl crate::WeightInfo for () {
-    fn on_initialize() -> Weight {
-        // TODO: Correct value
-        Weight::zero()
-    }
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Rewards::ValidatorRewardPercent` (r:0 w:1)
+	/// Proof: `Rewards::ValidatorRewardPercent` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_validator_reward_percent() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_312_000 picoseconds.
+		Weight::from_parts(6_584_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Rewards::ActiveEmissionCurve` (r:1 w:1)
+	/// Proof: `Rewards::ActiveEmissionCurve` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `p` is `[1, 32]`.
+	fn set_emission_curve(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `43 + p * (20 ±0)`
+		//  Estimated: `1528 + p * (20 ±0)`
+		// Minimum execution time: 10_415_000 picoseconds.
+		Weight::from_parts(10_873_000, 1528)
+			// Standard Error: 1_146
+			.saturating_add(Weight::from_parts(236_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 20).saturating_mul(p.into()))
+	}
 }
\ No newline at end of file
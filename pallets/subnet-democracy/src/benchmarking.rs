@@ -23,13 +23,13 @@
 use super::*;
 use frame_benchmarking::{account, benchmarks, whitelist_account, BenchmarkError};
 use frame_support::{
-	assert_noop, assert_ok, assert_err,
+	assert_noop, assert_ok, assert_err, BoundedVec,
 	traits::{Currency, EnsureOrigin, Get, OnInitialize, UnfilteredDispatchable},
 };
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use crate::Pallet as SubnetVoting;
 use crate::{
-  SubnetNode, PropsType, SubnetVote, VotesBalance, ReservableCurrency, PropCount, VoteType,
+  SubnetNode, PropsType, SubnetVote, VotesBalance, ReservableCurrency, PropCount, VoteType, Conviction,
   ActiveProposalsCount, Proposals, PropsStatus, RegistrationSubnetData
 };
 // use frame_support::dispatch::Vec;
@@ -45,6 +45,8 @@ const DEFAULT_DEPOSIT_AMOUNT: u128 = 10000000000000000000000; // 10,000
 const DEFAULT_MODEL_PATH: &str = "hf/llama2";
 const DEFAULT_EXISTING_MODEL_PATH: &str = "hf/baluga";
 const DEFAUT_VOTE_AMOUNT: u128 = 1000e+18 as u128;
+// Network pallets default ``MinSubnetRegistrationBlocks``
+const DEFAULT_REGISTRATION_BLOCKS: u64 = 50;
 
 fn peer(id: u32) -> PeerId {
   let peer_id = format!("QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N{id}"); 
@@ -59,10 +61,11 @@ fn default_ip() -> Vec<u8> {
   DEFAULT_IP.into()
 }
 
-fn default_add_subnet_data() -> RegistrationSubnetData {
+fn default_add_subnet_data<T: Config>() -> RegistrationSubnetData<T::MaxPathLength> {
   let subnet_data = RegistrationSubnetData {
-    path: DEFAULT_MODEL_PATH.into(),
+    path: BoundedVec::truncate_from(DEFAULT_MODEL_PATH.into()),
 		memory_mb: 50000,
+    registration_blocks: DEFAULT_REGISTRATION_BLOCKS,
   };
   subnet_data
 }
@@ -83,7 +86,7 @@ pub fn block_to_u64<T: frame_system::Config>(input: BlockNumberFor<T>) -> u64 {
 
 fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
 	let caller: T::AccountId = account(name, index, SEED);
-	let deposit_amount: u128 = T::SubnetVote::get_subnet_initialization_cost();
+	let deposit_amount: u128 = T::SubnetVote::get_subnet_initialization_cost(default_add_subnet_data::<T>().memory_mb);
   T::Currency::deposit_creating(&caller, deposit_amount.try_into().ok().expect("REASON"));
 	caller
 }
@@ -122,6 +125,7 @@ fn post_proposal_concluded<T: Config>(proposal_index: u32, proposer: T::AccountI
       proposal_index,
       DEFAUT_VOTE_AMOUNT.try_into().ok().expect("REASON"),
       VoteType::Yay,
+      Conviction::None,
     ),
     Error::<T>::VotingNotOpen
   );
@@ -144,7 +148,7 @@ fn post_success_proposal_activate_ensures<T: Config>(path: Vec<u8>, proposal_ind
   // assert_eq!(proposal.subnet_nodes, path);
   assert_eq!(proposal.end_vote_block, proposal_start_block + block_to_u64::<T>(T::VotingPeriod::get()));
 
-  let subnet_initialization_cost = T::SubnetVote::get_subnet_initialization_cost();
+  let subnet_initialization_cost = T::SubnetVote::get_subnet_initialization_cost(default_add_subnet_data::<T>().memory_mb);
   // assert_eq!(VotesBalance::<T>::get(proposal_index, proposer), subnet_initialization_cost.clone());
 
   // let reserved_balance = <pallet_balances::Pallet<T> as ReservableCurrency<T>>::reserved_balance(&proposer);
@@ -167,7 +171,7 @@ fn post_activate_cancel_ensures<T: Config>(proposal_index: u32, proposer: T::Acc
 
 fn post_cast_vote_ensures<T: Config>(proposal_index: u32, voter: u32) {
   assert_err!(
-    SubnetVoting::<T>::unreserve(
+    SubnetVoting::<T>::unlock(
       RawOrigin::Signed(funded_account::<T>("voter", voter)).into(),
       proposal_index, 
     ),
@@ -182,9 +186,12 @@ fn build_propose_activate<T: Config>(path: Vec<u8>, start: u32, end: u32, deposi
   assert_ok!(
     SubnetVoting::<T>::propose(
       RawOrigin::Signed(proposer.clone()).into(),
-      default_add_subnet_data(), 
+      default_add_subnet_data::<T>(), 
       subnet_nodes,
       PropsType::Activate,
+      None,
+      BoundedVec::new(),
+      None,
     )
   );
   0
@@ -200,6 +207,7 @@ fn build_cast_vote<T: Config>(proposal_index: u32, start: u32, end: u32, vote: V
         proposal_index,
         DEFAUT_VOTE_AMOUNT.try_into().ok().expect("REASON"),
         vote.clone(),
+        Conviction::None,
       )
     );
   }
@@ -212,7 +220,7 @@ benchmarks! {
     let min_subnet_nodes: u32 = T::SubnetVote::get_min_subnet_nodes(1000);
 		let proposer = funded_account::<T>("account", 0);
     let subnet_nodes = build_subnet_nodes::<T>(0, min_subnet_nodes, min_stake);
-	}: propose(RawOrigin::Signed(proposer.clone()), default_add_subnet_data(), subnet_nodes, PropsType::Activate)
+	}: propose(RawOrigin::Signed(proposer.clone()), default_add_subnet_data::<T>(), subnet_nodes, PropsType::Activate, None, BoundedVec::new(), None)
 	verify {
     assert_eq!(1, 1);
 		post_success_proposal_activate_ensures::<T>(
@@ -230,7 +238,7 @@ benchmarks! {
 		let voter = funded_account::<T>("voter", 0);
     let subnet_nodes = build_subnet_nodes::<T>(0, min_subnet_nodes, min_stake);
     let proposal_index = build_propose_activate::<T>(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
-	}: cast_vote(RawOrigin::Signed(voter.clone()), proposal_index, DEFAUT_VOTE_AMOUNT.try_into().ok().expect("REASON"), VoteType::Yay)
+	}: cast_vote(RawOrigin::Signed(voter.clone()), proposal_index, DEFAUT_VOTE_AMOUNT.try_into().ok().expect("REASON"), VoteType::Yay, Conviction::None)
 	verify {
     assert_eq!(1, 1);
     post_cast_vote_ensures::<T>(proposal_index, 0)
@@ -268,7 +276,7 @@ benchmarks! {
     assert_eq!(1, 1);
   }
 
-  unreserve {
+  unlock {
     let prop_count = PropCount::<T>::get();
     let min_stake = T::SubnetVote::get_min_stake_balance();
     let min_subnet_nodes: u32 = T::SubnetVote::get_min_subnet_nodes(1000);
@@ -283,7 +291,8 @@ benchmarks! {
         RawOrigin::Signed(voter.clone()).into(), 
         proposal_index, 
         DEFAUT_VOTE_AMOUNT.try_into().ok().expect("REASON"), 
-        VoteType::Yay      
+        VoteType::Yay,
+        Conviction::None,
       )
     );
 
@@ -298,7 +307,7 @@ benchmarks! {
       )
     );
   
-	}: unreserve(RawOrigin::Signed(voter.clone()), proposal_index)
+	}: unlock(RawOrigin::Signed(voter.clone()), proposal_index)
 	verify {
     assert_eq!(1, 1);
   }
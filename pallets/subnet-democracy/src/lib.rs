@@ -51,25 +51,31 @@ use sp_core::{
 };
 use frame_system::{
   pallet_prelude::{OriginFor, BlockNumberFor},
-  ensure_signed, ensure_root,
+  ensure_signed, ensure_root, RawOrigin,
   offchain::{
     AppCrypto, CreateSignedTransaction, SendSignedTransaction, SendUnsignedTransaction,
     SignedPayload, Signer, SigningTypes, SubmitTransaction,
   },
 };
 use frame_support::{
-  pallet_prelude::DispatchResult,
+  pallet_prelude::{DispatchResult, DispatchError, Weight},
+  BoundedVec, BoundedBTreeSet, BoundedBTreeMap,
   ensure,
-  traits::{Currency, LockableCurrency, ReservableCurrency, WithdrawReasons, LockIdentifier},
+  traits::{
+    StorageVersion,
+    Currency, LockableCurrency, ReservableCurrency, WithdrawReasons, LockIdentifier,
+    Bounded, OriginTrait, QueryPreimage, StorePreimage,
+    schedule::{DispatchTime, v3::{Named as ScheduleNamed, TaskName}},
+  },
 };
+use codec::Encode;
 use sp_runtime::Vec;
 use sp_runtime::{
-  traits::Zero,
+  traits::{Zero, Dispatchable},
   Saturating, Perbill, Percent
 };
-use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 
-use pallet_network::{SubnetVote, RegistrationSubnetData, SubnetDemocracySubnetData};
+use pallet_network::{SubnetVote, RegistrationSubnetData, SubnetDemocracySubnetData, SubnetRemovalReason, DefaultProposalEvidenceLimit, SubnetUpdateData};
 
 #[cfg(test)]
 mod mock;
@@ -94,6 +100,20 @@ const SUBNET_DEMOCRACY_ID: LockIdentifier = *b"sndemocr";
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+pub type CallOf<T> = <T as Config>::RuntimeCall;
+pub type BoundedCallOf<T> = Bounded<CallOf<T>, <T as frame_system::Config>::Hashing>;
+pub type PalletsOriginOf<T> =
+	<<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin;
+
+/// Priority of scheduled proposal enactments
+const ENACTMENT_PRIORITY: u8 = 63;
+
+/// The in-code storage version
+// The pallet has no on-chain state from before it was added to the runtime, so its
+// storage starts at version 0 without migrations. Bump it with a migration on the
+// first layout change after it's deployed
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 #[frame_support::pallet]
 pub mod pallet {
   use super::*;
@@ -144,11 +164,47 @@ pub mod pallet {
     #[pallet::constant]
 		type CancelSlashPercent: Get<u8>;
 
+    /// Maximum length of a proposals subnet path, shared with the network pallet through ``SubnetVote``
+    #[pallet::constant]
+		type MaxPathLength: Get<u32>;
+
+    /// Maximum subnet nodes entered into an activation proposal
+    #[pallet::constant]
+		type MaxProposalSubnetNodes: Get<u32>;
+
+    /// Maximum length of a proposal subnet nodes peer ID
+    #[pallet::constant]
+		type MaxPeerIdLength: Get<u32>;
+
+    /// Blocks a concluded proposal is kept before it is pruned, leaving only its ``ProposalOutcomes`` record
+    // Must outlast the longest conviction lock so pruned vote locks are already unlockable
+    #[pallet::constant]
+		type ProposalRetentionPeriod: Get<BlockNumberFor<Self>>;
+
+    /// Maximum votes pruned per block
+    #[pallet::constant]
+		type MaxVotesPrunedPerBlock: Get<u32>;
+
     // type SubnetVote: SubnetVote<Self::AccountId>; 
-    type SubnetVote: SubnetVote<OriginFor<Self>, Self::AccountId>; 
+    type SubnetVote: SubnetVote<OriginFor<Self>, Self::AccountId, Self::MaxPathLength>; 
+
+    type Currency: Currency<Self::AccountId> 
+      + ReservableCurrency<Self::AccountId> 
+      + LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>> 
+      + Send 
+      + Sync;
 
-    // type Currency: Currency<Self::AccountId> + LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>> + Send + Sync;
-    type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId> + Send + Sync;
+    /// The overarching call type
+    type RuntimeCall: Parameter
+      + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin>
+      + From<Call<Self>>
+      + IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+    /// Schedules each proposals enactment at the end of its voting period
+    type Scheduler: ScheduleNamed<BlockNumberFor<Self>, CallOf<Self>, PalletsOriginOf<Self>, Hasher = Self::Hashing>;
+
+    /// Bounds the scheduled enactment calls
+    type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
 
     type WeightInfo: WeightInfo;
   }
@@ -222,6 +278,18 @@ pub mod pallet {
     /// Not a subnet node for activation proposal
     NotSubnetNode,
     SubnetMemoryIsZero,
+    /// Vote balance is locked by its conviction
+    VoteLocked,
+    /// Deactivation proposals require a vote out reason and evidence
+    VoteOutReasonRequired,
+    /// Activation proposals can't have a vote out reason or evidence
+    VoteOutReasonInvalid,
+    /// Update proposals require subnet update data, other proposals can't have it
+    SubnetUpdateInvalid,
+    /// Proposal enactment could not be scheduled
+    EnactmentScheduleFailed,
+    /// Subnet nodes exceed ``MaxProposalSubnetNodes``
+    TooManySubnetNodes,
   }
 
   /// `pallet-rewards` events
@@ -250,20 +318,35 @@ pub mod pallet {
     pub end_vote_block: u64,
 	}
 
-  #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
-	pub struct PropsParams<AccountId> {
-    pub proposer: AccountId,
+  /// Subnet node entered into an activation proposal
+  #[derive(Encode, Decode, MaxEncodedLen, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, scale_info::TypeInfo)]
+  #[scale_info(skip_type_params(T))]
+  #[codec(mel_bound())]
+	pub struct ProposalSubnetNode<T: Config> {
+    pub account_id: T::AccountId,
+		pub peer_id: BoundedVec<u8, T::MaxPeerIdLength>,
+	}
+
+  #[derive(Encode, Decode, MaxEncodedLen, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, scale_info::TypeInfo)]
+  #[scale_info(skip_type_params(T))]
+  #[codec(mel_bound())]
+	pub struct PropsParams<T: Config> {
+    pub proposer: T::AccountId,
     pub proposer_stake: u128, // Activate: Non refundable, Deactivate: Refundable
     pub proposal_status: PropsStatus,
     pub proposal_type: PropsType, // Activation or Deactivation
-    pub path: Vec<u8>, // path for downloading subnet used in subnet, can be anything (HuggingFace, IPFS, etc.)
-    pub subnet_data: RegistrationSubnetData,
-		pub subnet_nodes: Vec<SubnetNode<AccountId>>,
-    pub subnet_nodes_verified: BTreeSet<AccountId>,
-    pub subnet_nodes_bonded: BTreeMap<AccountId, u128>,
+    pub path: BoundedVec<u8, T::MaxPathLength>, // path for downloading subnet used in subnet, can be anything (HuggingFace, IPFS, etc.)
+    pub memory_mb: u128,
+    pub registration_blocks: u64,
+		pub subnet_nodes: BoundedVec<ProposalSubnetNode<T>, T::MaxProposalSubnetNodes>,
+    pub subnet_nodes_verified: BoundedBTreeSet<T::AccountId, T::MaxProposalSubnetNodes>,
+    pub subnet_nodes_bonded: BoundedBTreeMap<T::AccountId, u128, T::MaxProposalSubnetNodes>,
     pub start_block: u64, // used for data only, not in logic
     pub start_vote_block: u64, // block start voting, and end verify period
     pub end_vote_block: u64, // block ending voting
+    pub vote_out_reason: Option<VoteOutReason>, // Deactivate: reason the subnet is being voted out
+    pub vote_out_evidence: BoundedVec<u8, DefaultProposalEvidenceLimit>, // Deactivate: evidence hash or URI
+    pub subnet_update: Option<SubnetUpdateData>, // Update: subnet parameters to update
 	}
 
   // #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
@@ -272,7 +355,20 @@ pub mod pallet {
 	// 	pub subnet_nodes: Vec<SubnetNode<AccountId>>,
 	// }
 
-  #[derive(Default, Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+  /// Compact record of a concluded proposal, kept after the proposal is pruned
+  #[derive(Encode, Decode, MaxEncodedLen, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, scale_info::TypeInfo)]
+  #[scale_info(skip_type_params(T))]
+  #[codec(mel_bound())]
+	pub struct ProposalOutcome<T: Config> {
+    pub proposer: T::AccountId,
+    pub proposal_type: PropsType,
+    pub proposal_status: PropsStatus,
+    pub path: BoundedVec<u8, T::MaxPathLength>,
+    pub votes: VotesParams,
+    pub concluded_block: u64,
+	}
+
+  #[derive(Default, Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
 	pub struct VotesParams {
     pub yay: u128,
 		pub nay: u128,
@@ -297,7 +393,7 @@ pub mod pallet {
     };
 	}
 	#[pallet::type_value]
-	pub fn DefaultActivatePropsParams<T: Config>() -> ActivatePropsParams<T::AccountId> {
+	pub fn DefaultActivatePropsParams<T: Config>() -> ActivatePropsParams<T> {
 		return ActivatePropsParams {
 			path: Vec::new(),
 			subnet_nodes: Vec::new(),
@@ -305,20 +401,24 @@ pub mod pallet {
     };
 	}
   #[pallet::type_value]
-	pub fn DefaultPropsParams<T: Config>() -> PropsParams<T::AccountId> {
+	pub fn DefaultPropsParams<T: Config>() -> PropsParams<T> {
 		return PropsParams {
       proposer: T::AccountId::decode(&mut TrailingZeroInput::zeroes()).unwrap(),
       proposer_stake: 0,
       proposal_status: PropsStatus::None,
       proposal_type: PropsType::None,
-			path: Vec::new(),
-      subnet_data: RegistrationSubnetData::default(),
-      subnet_nodes_verified: BTreeSet::new(),
-      subnet_nodes_bonded: BTreeMap::new(),
-			subnet_nodes: Vec::new(),
+			path: BoundedVec::new(),
+      memory_mb: 0,
+      registration_blocks: 0,
+      subnet_nodes_verified: BoundedBTreeSet::new(),
+      subnet_nodes_bonded: BoundedBTreeMap::new(),
+			subnet_nodes: BoundedVec::new(),
       start_block: 0,
       start_vote_block: 0,
       end_vote_block: 0,
+      vote_out_reason: None,
+      vote_out_evidence: BoundedVec::new(),
+      subnet_update: None,
     };
 	}
   #[pallet::type_value]
@@ -337,7 +437,7 @@ pub mod pallet {
   //   }
 	// }
   // #[pallet::type_value]
-	// pub fn DefaultDeactivatePropsParams<T: Config>() -> DeactivatePropsParams<T::AccountId> {
+	// pub fn DefaultDeactivatePropsParams<T: Config>() -> DeactivatePropsParams<T> {
 	// 	return DeactivatePropsParams {
 	// 		path: Vec::new(),
 	// 		subnet_nodes: Vec::new(),
@@ -360,8 +460,8 @@ pub mod pallet {
 		66
 	}
 
-  #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
-  enum VoteOutReason {
+  #[derive(Encode, Decode, MaxEncodedLen, Copy, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+  pub enum VoteOutReason {
     // If subnet peers are performing manipulation for rewards
     SubnetEmissionsManipulation,
     // If the subnet is down
@@ -378,6 +478,20 @@ pub mod pallet {
     Other,
   }
 
+  impl From<VoteOutReason> for SubnetRemovalReason {
+    fn from(reason: VoteOutReason) -> Self {
+      match reason {
+        VoteOutReason::SubnetEmissionsManipulation => SubnetRemovalReason::SubnetEmissionsManipulation,
+        VoteOutReason::SubnetDown => SubnetRemovalReason::SubnetDown,
+        VoteOutReason::SubnetCloseSourced => SubnetRemovalReason::SubnetCloseSourced,
+        VoteOutReason::SubnetBroken => SubnetRemovalReason::SubnetBroken,
+        VoteOutReason::SubnetMinimumNodes => SubnetRemovalReason::SubnetMinimumNodes,
+        VoteOutReason::SubnetIllicit => SubnetRemovalReason::SubnetIllicit,
+        VoteOutReason::Other => SubnetRemovalReason::SubnetDemocracy,
+      }
+    }
+  }
+
   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
   pub enum VoteType {
     Yay,
@@ -385,11 +499,50 @@ pub mod pallet {
     Abstain,
   }
 
-  #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+  /// Conviction of a vote
+  // Each step increases the voting power multiplier and the amount of ``EnactmentPeriod``s
+  // the vote balance stays locked after the proposal concludes
+  #[derive(Default, Copy, Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, scale_info::TypeInfo)]
+  pub enum Conviction {
+    /// 1x voting power, unlocked on conclusion
+    #[default]
+    None,
+    /// 2x voting power, locked for 1 enactment period after conclusion
+    Locked2x,
+    /// 3x voting power, locked for 2 enactment periods after conclusion
+    Locked3x,
+    /// 4x voting power, locked for 3 enactment periods after conclusion
+    Locked4x,
+  }
+
+  impl Conviction {
+    pub fn multiplier(&self) -> u128 {
+      match self {
+        Conviction::None => 1,
+        Conviction::Locked2x => 2,
+        Conviction::Locked3x => 3,
+        Conviction::Locked4x => 4,
+      }
+    }
+
+    pub fn lock_periods(&self) -> u64 {
+      self.multiplier() as u64 - 1
+    }
+  }
+
+  /// Vote balance locked on a proposal
+  #[derive(Default, Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+	pub struct VoteLock {
+    pub amount: u128,
+    pub conviction: Conviction,
+	}
+
+  #[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
   pub enum PropsType {
     None,
     Activate,
     Deactivate,
+    Update,
   }
 
   impl Default for PropsType {
@@ -398,7 +551,7 @@ pub mod pallet {
     }
   }
 
-  #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+  #[derive(Encode, Decode, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
   pub enum PropsStatus {
     // Default status
     None,
@@ -422,24 +575,40 @@ pub mod pallet {
 
   // #[pallet::storage]
   // pub type ModelTypes<T: Config> =
-  //   StorageMap<_, Blake2_128Concat, PropIndex, PropsParams<T::AccountId>, ValueQuery, DefaultPropsParams<T>>;
+  //   StorageMap<_, Blake2_128Concat, PropIndex, PropsParams<T>, ValueQuery, DefaultPropsParams<T>>;
 
 	// #[pallet::storage]
 	// #[pallet::getter(fn activate_props)]
 	// pub type ActivateProps<T: Config> =
-	// 	StorageMap<_, Blake2_128Concat, PropIndex, ActivatePropsParams<T::AccountId>, ValueQuery, DefaultActivatePropsParams<T>>;
+	// 	StorageMap<_, Blake2_128Concat, PropIndex, ActivatePropsParams<T>, ValueQuery, DefaultActivatePropsParams<T>>;
 
   #[pallet::storage]
   #[pallet::getter(fn props)]
   pub type Proposals<T: Config> =
-    StorageMap<_, Blake2_128Concat, PropIndex, PropsParams<T::AccountId>, ValueQuery, DefaultPropsParams<T>>;
+    StorageMap<_, Blake2_128Concat, PropIndex, PropsParams<T>, ValueQuery, DefaultPropsParams<T>>;
+
+  /// Outcome of each concluded proposal, kept after the proposal is pruned
+  #[pallet::storage]
+  pub type ProposalOutcomes<T: Config> = StorageMap<_, Blake2_128Concat, PropIndex, ProposalOutcome<T>, OptionQuery>;
+
+  /// Concluded proposals in the order they concluded, and so in the order their retention period passes
+  #[pallet::storage]
+  pub type ConcludedProposals<T> = StorageMap<_, Identity, u32, PropIndex, OptionQuery>;
+
+  /// Number of proposals that have concluded
+  #[pallet::storage]
+  pub type ConcludedCount<T> = StorageValue<_, u32, ValueQuery>;
+
+  /// Position in ``ConcludedProposals`` of the next proposal to be pruned
+  #[pallet::storage]
+  pub type PruneCursor<T> = StorageValue<_, u32, ValueQuery>;
   
   // Activation proposals that are active for voting and verified by commited subnet nodes
-  // We only require that ``activate`` proposals are verified and stored into a BTreeSet
+  // We only require that ``activate`` proposals are verified and stored into a BoundedBTreeSet
   // Deactivate proposals are only checked by its current count of deactivate proposals in ``DeactivateProposalsCount``
   #[pallet::storage]
   #[pallet::getter(fn active_props)]
-  pub type ActiveActivateProposals<T: Config> = StorageValue<_, BTreeSet<PropIndex>, ValueQuery>;
+  pub type ActiveActivateProposals<T: Config> = StorageValue<_, BoundedBTreeSet<PropIndex, T::MaxProposals>, ValueQuery>;
   
   // Track active proposals to ensure that we don't increase past the max proposals
   // This includes the sum of all activate and deactivate proposals
@@ -457,6 +626,10 @@ pub mod pallet {
   #[pallet::getter(fn votes)]
   pub type Votes<T: Config> =
     StorageMap<_, Blake2_128Concat, PropIndex, VotesParams, ValueQuery, DefaultVotes<T>>;
+
+  /// Yay and abstain vote balances of a proposal without conviction, counted towards its quorum
+  #[pallet::storage]
+  pub type QuorumVotes<T: Config> = StorageMap<_, Blake2_128Concat, PropIndex, u128, ValueQuery>;
   
   #[pallet::storage]
   #[pallet::getter(fn votes_balance)]
@@ -470,6 +643,35 @@ pub mod pallet {
     ValueQuery,
   >;
 
+  /// Voting power of an account on a proposal as of its ``start_vote_block``, cached on its first vote
+  #[pallet::storage]
+  pub type VotingPowerSnapshots<T: Config> = StorageDoubleMap<
+    _,
+    Blake2_128Concat,
+    PropIndex,
+    Identity,
+    T::AccountId,
+    u128,
+    OptionQuery,
+  >;
+
+  /// Total network voting power of a proposal as of its ``start_vote_block``, cached on its first vote
+  #[pallet::storage]
+  pub type TotalVotingPowerSnapshot<T: Config> = StorageMap<_, Blake2_128Concat, PropIndex, u128, OptionQuery>;
+
+  /// Vote balance locks by account and proposal
+  // The accounts balance lock is the largest of its vote locks
+  #[pallet::storage]
+  pub type VoteLocks<T: Config> = StorageDoubleMap<
+    _,
+    Blake2_128Concat,
+    T::AccountId,
+    Identity,
+    PropIndex,
+    VoteLock,
+    OptionQuery,
+  >;
+
   // #[pallet::storage]
   // pub type ActivateVotes<T: Config> =
   //   StorageMap<_, Blake2_128Concat, PropIndex, ActivateVotesParams, ValueQuery, DefaultActivateVotes<T>>;
@@ -485,7 +687,7 @@ pub mod pallet {
   // #[pallet::storage]
 	// #[pallet::getter(fn deactivate_props)]
 	// pub type DeactivateProps<T: Config> =
-	// 	StorageMap<_, Blake2_128Concat, PropIndex, ActivatePropsParams<T::AccountId>, ValueQuery, DefaultActivatePropsParams<T>>;
+	// 	StorageMap<_, Blake2_128Concat, PropIndex, ActivatePropsParams<T>, ValueQuery, DefaultActivatePropsParams<T>>;
 
   // #[pallet::storage]
 	// #[pallet::getter(fn deactivate_prop_count)]
@@ -493,7 +695,7 @@ pub mod pallet {
 
   #[pallet::storage]
 	pub type PropsPathStatus<T: Config> =
-		StorageMap<_, Blake2_128Concat, Vec<u8>, PropsStatus, ValueQuery, DefaultPropsStatus>;
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxPathLength>, PropsStatus, ValueQuery, DefaultPropsStatus>;
 
   // #[pallet::storage]
   // #[pallet::getter(fn quorum)]
@@ -506,7 +708,7 @@ pub mod pallet {
   pub type NodeVotePremium<T> = StorageValue<_, u128, ValueQuery>;
 
   #[pallet::pallet]
-  #[pallet::without_storage_info]
+  #[pallet::storage_version(STORAGE_VERSION)]
   pub struct Pallet<T>(_);
 
   #[pallet::call]
@@ -524,6 +726,12 @@ pub mod pallet {
 		/// May only be call to deactivate a subnet if 
     ///  - The subnet already does exist within the network pallet
     ///  - The subnet isn't already proposed to be deactivated via PropsStatus::Active
+    ///  - A ``VoteOutReason`` and evidence hash or URI are given
+    ///
+		/// May only be call to update a subnet if 
+    ///  - The subnet already does exist within the network pallet
+    ///  - The subnet isn't already proposed to be updated or deactivated via PropsStatus::Active
+    ///  - The ``SubnetUpdateData`` is within the network memory and subnet node bounds
     ///
     /// The RegistrationSubnetData is used to dictate the subnets rewards and node requirements.
     /// Memory must be accurate to usage of the subnet for servers/
@@ -534,9 +742,12 @@ pub mod pallet {
     // #[pallet::weight(0)]
     pub fn propose(
       origin: OriginFor<T>, 
-      subnet_data: RegistrationSubnetData,
+      subnet_data: RegistrationSubnetData<T::MaxPathLength>,
       mut subnet_nodes: Vec<SubnetNode<T::AccountId>>,
-      proposal_type: PropsType
+      proposal_type: PropsType,
+      vote_out_reason: Option<VoteOutReason>,
+      vote_out_evidence: BoundedVec<u8, DefaultProposalEvidenceLimit>,
+      subnet_update: Option<SubnetUpdateData>,
     ) -> DispatchResult {
       let account_id: T::AccountId = ensure_signed(origin)?;
 
@@ -552,14 +763,30 @@ pub mod pallet {
 				Error::<T>::MaxActiveProposals
 			);
 
+      // --- Ensure only update proposals carry subnet update data
+      ensure!(
+        (proposal_type == PropsType::Update) == subnet_update.is_some(),
+        Error::<T>::SubnetUpdateInvalid
+      );
+
+      // --- Bound proposal data to be stored
+      let path: BoundedVec<u8, T::MaxPathLength> = subnet_data.path.clone();
+      let bounded_subnet_nodes: BoundedVec<ProposalSubnetNode<T>, T::MaxProposalSubnetNodes> = 
+        Self::bound_subnet_nodes(subnet_nodes.clone())?;
+
       let proposal_index = PropCount::<T>::get();
 
       let mut proposer_stake: u128 = 0;
       let proposer_balance = T::Currency::free_balance(&account_id);
 
       if proposal_type == PropsType::Activate {
+        ensure!(
+          vote_out_reason.is_none() && vote_out_evidence.is_empty(),
+          Error::<T>::VoteOutReasonInvalid
+        );
+
         // --- Stake the value of initializing a new subnet
-        let subnet_initialization_cost = T::SubnetVote::get_subnet_initialization_cost();
+        let subnet_initialization_cost = T::SubnetVote::get_subnet_initialization_cost(subnet_data.memory_mb);
         proposer_stake = subnet_initialization_cost;
         let subnet_initialization_cost_as_balance = Self::u128_to_balance(subnet_initialization_cost);
     
@@ -587,6 +814,11 @@ pub mod pallet {
           subnet_nodes.clone()
         ).map_err(|e| e)?;
       } else if proposal_type == PropsType::Deactivate {
+        ensure!(
+          vote_out_reason.is_some() && !vote_out_evidence.is_empty(),
+          Error::<T>::VoteOutReasonRequired
+        );
+
         // --- Ensure zero subnet peers are submitted on deactivation proposals
         ensure!(
           subnet_nodes.clone().len() == 0,
@@ -611,10 +843,42 @@ pub mod pallet {
         );
 
         // --- Proposal prelims
-        Self::try_propose_deactivate(account_id.clone(), subnet_data.clone().path)
+        Self::try_propose_deactivate(account_id.clone(), subnet_data.path.clone())
+          .map_err(|e| e)?;
+      } else if proposal_type == PropsType::Update {
+        ensure!(
+          vote_out_reason.is_none() && vote_out_evidence.is_empty(),
+          Error::<T>::VoteOutReasonInvalid
+        );
+
+        ensure!(
+          subnet_nodes.clone().len() == 0,
+          Error::<T>::SubnetNodesLengthInvalid
+        );
+
+        proposer_stake = T::MinProposerStake::get();
+        let proposer_stake_as_balance = Self::u128_to_balance(proposer_stake);
+
+        ensure!(
+          proposer_balance >= proposer_stake_as_balance.unwrap(),
+          Error::<T>::NotEnoughSubnetInitializationBalance
+        );
+
+        // --- Reserve balance to be returned to the proposer on conclusion
+        T::Currency::reserve(
+          &account_id,
+          proposer_stake_as_balance.unwrap(),
+        );
+
+        // --- Proposal prelims
+        Self::try_propose_update(subnet_data.path.clone(), subnet_update.clone().unwrap())
           .map_err(|e| e)?;
       }
 
+      // --- Voting power is read as of the start of voting
+      let start_vote_block: u64 = Self::convert_block_as_u64(<frame_system::Pallet<T>>::block_number() + T::VerifyPeriod::get());
+      T::SubnetVote::add_voting_power_snapshot(start_vote_block);
+
       // --- Save proposal
       Proposals::<T>::insert(
         proposal_index,
@@ -623,19 +887,29 @@ pub mod pallet {
           proposer_stake: proposer_stake,
           proposal_status: PropsStatus::Active,
           proposal_type: proposal_type,
-          path: subnet_data.clone().path,
-          subnet_data: subnet_data.clone(),
-          subnet_nodes: subnet_nodes.clone(),
-          subnet_nodes_verified: BTreeSet::new(),
-          subnet_nodes_bonded: BTreeMap::new(),
+          path: path.clone(),
+          memory_mb: subnet_data.memory_mb,
+          registration_blocks: subnet_data.registration_blocks,
+          subnet_nodes: bounded_subnet_nodes,
+          subnet_nodes_verified: BoundedBTreeSet::new(),
+          subnet_nodes_bonded: BoundedBTreeMap::new(),
           start_block: Self::convert_block_as_u64(<frame_system::Pallet<T>>::block_number()),
-          start_vote_block: Self::convert_block_as_u64(<frame_system::Pallet<T>>::block_number() + T::VerifyPeriod::get()),
+          start_vote_block: start_vote_block,
           end_vote_block: Self::convert_block_as_u64(<frame_system::Pallet<T>>::block_number() + T::VerifyPeriod::get() + T::VotingPeriod::get()),
+          vote_out_reason: vote_out_reason,
+          vote_out_evidence: vote_out_evidence,
+          subnet_update: subnet_update,
         },
       );
   
+      // --- Schedule enactment for the block after voting ends
+      Self::schedule_enactment(
+        proposal_index,
+        <frame_system::Pallet<T>>::block_number() + T::VerifyPeriod::get() + T::VotingPeriod::get() + 1u32.into()
+      )?;
+
       // --- Set path to current proposal status to active
-      PropsPathStatus::<T>::insert(subnet_data.path, PropsStatus::Active);
+      PropsPathStatus::<T>::insert(path, PropsStatus::Active);
 
       // --- Increase proposals count
       PropCount::<T>::put(proposal_index + 1);
@@ -673,7 +947,8 @@ pub mod pallet {
 		/// May only vote if
     ///  - Voter has enough balance
     ///
-    /// Vote is based on balance and balance is staked until execution or defeat.
+    /// Vote is based on balance and balance is locked until execution or cancellation.
+    /// Votes with conviction stay locked for the convictions lock periods after conclusion.
     #[pallet::call_index(2)]
     // #[pallet::weight(0)]
     #[pallet::weight(T::WeightInfo::cast_vote())]
@@ -682,6 +957,7 @@ pub mod pallet {
       proposal_index: PropIndex,
       vote_amount: BalanceOf<T>,
       vote: VoteType,
+      conviction: Conviction,
     ) -> DispatchResult {
 			let account_id: T::AccountId = ensure_signed(origin)?;
 
@@ -692,7 +968,7 @@ pub mod pallet {
 
       let proposal = Proposals::<T>::get(proposal_index);
   
-      Self::try_cast_vote(account_id, proposal_index, proposal, vote_amount, vote)
+      Self::try_cast_vote(account_id, proposal_index, proposal, vote_amount, vote, conviction)
     }
 
     /// Execute completion of proposal 
@@ -703,7 +979,7 @@ pub mod pallet {
     ///
     /// Vote is based on balance and balance is staked until execution or defeat.
     ///
-    /// Anyone can call this
+    /// Anyone can call this, otherwise it is enacted by the scheduler at the end of the voting period
     //
     // This cannot fail as long as the proposal ID exists, isn't concluded, and the voting period has completed
     #[pallet::call_index(3)]
@@ -715,80 +991,10 @@ pub mod pallet {
     ) -> DispatchResult {
 			let account_id: T::AccountId = ensure_signed(origin)?;
 
-      // --- Ensure proposal exists
-      ensure!(
-        Proposals::<T>::contains_key(proposal_index),
-        Error::<T>::ProposalInvalid
-      );
-  
-      let proposal = Proposals::<T>::get(proposal_index);
-
-      // --- Ensure proposal is active and has not concluded
-      ensure!(
-        proposal.proposal_status == PropsStatus::Active,
-        Error::<T>::Concluded
-      );
-
-      ensure!(
-        !Self::is_voting_open(proposal.clone()),
-        Error::<T>::VotingOpen
-      );
-  
-      // --- Ensure voting has ended
-      let end_vote_block = proposal.end_vote_block;
-      let block = Self::get_current_block_as_u64();
-
-      ensure!(
-        block > end_vote_block,
-        Error::<T>::VoteActive
-      );
-
-      // --- We made it past the voting period, we cannot fail from here
-
-      if proposal.proposal_type == PropsType::Activate {
-        ActivateProposalsCount::<T>::mutate(|n: &mut u32| n.saturating_dec());
-      } else {
-        DeactivateProposalsCount::<T>::mutate(|n: &mut u32| n.saturating_dec());
-      }
-      
-      // --- If enactment period has passed, expire the proposal
-      // Don't revert here to allow expired paths to be reproposed
-      if block > end_vote_block + Self::convert_block_as_u64(T::EnactmentPeriod::get())  {
-        Self::try_expire(proposal_index, proposal.path.clone())
-          .map_err(|e| e)?;
-        return Ok(())
-      }
+      Self::try_execute(account_id, proposal_index)?;
 
-      // --- Get status of proposal
-      let votes = Votes::<T>::get(proposal_index);
-
-      let quorum_reached = Self::quorum_reached(votes.clone());
-      let vote_succeeded = Self::vote_succeeded(votes.clone());
-
-      let proposer_stake_as_balance = Self::u128_to_balance(proposal.proposer_stake);
-      // --- Unreserve here to pay for initialization fee or give back to proposer
-      T::Currency::unreserve(
-        &proposal.proposer,
-        proposer_stake_as_balance.unwrap(),
-      );
-
-      // --- Remove proposal from active proposals
-      Self::try_deactivate_proposal(
-        proposal_index,
-        proposal.clone(),
-      );
-    
-      // --- If quorum and vote YAYS aren greater than vote NAYS, then pass, else, defeat
-      if quorum_reached && vote_succeeded {
-        Self::try_succeed(account_id, proposal_index, proposal.clone())
-          .map_err(|e| e)?;
-      } else if quorum_reached && !vote_succeeded {
-        Self::try_defeat(proposal_index, proposal.path.clone())
-          .map_err(|e| e)?;
-      } else {
-        Self::try_expire(proposal_index, proposal.path.clone())
-          .map_err(|e| e)?;
-      }
+      // --- Enacted manually, the scheduled enactment is no longer needed
+      Self::cancel_enactment(proposal_index);
 
       Ok(())
     }
@@ -871,23 +1077,30 @@ pub mod pallet {
         proposer_stake_unreserve_as_balance.unwrap(),
       );
 
+      Self::unreserve_subnet_node_bonds(proposal.clone());
+
       // --- Remove proposal from active proposals
       Self::try_deactivate_proposal(
         proposal_index,
         proposal.clone(),
       );
+
+      Self::cancel_enactment(proposal_index);
       
       Self::try_cancel(proposal_index, proposal.path)
     }
 
-    /// NOTE: This function IS TO BE REMOVED
-    /// Unreserve vote stake
+    /// Unlock vote balance
     ///
     /// Proposal must be not Active
+    ///
+    /// Vote balances stay locked until each voter unlocks them, so concluding a proposal
+    /// doesn't iterate its voters
+    ///
+    /// Votes with conviction can only be unlocked once their lock periods have passed
     #[pallet::call_index(5)]
-    // #[pallet::weight(0)]
-    #[pallet::weight(T::WeightInfo::unreserve())]
-    pub fn unreserve(
+    #[pallet::weight(T::WeightInfo::unlock())]
+    pub fn unlock(
       origin: OriginFor<T>, 
       proposal_index: PropIndex,
     ) -> DispatchResult {
@@ -916,22 +1129,24 @@ pub mod pallet {
         Error::<T>::VotesBalanceInvalid
       );
 
-      // --- Get balance and remove from storage
-      let balance = VotesBalance::<T>::take(proposal_index, &account_id);
-
-      // ensure!(
-      //   Self::balance_to_u128(balance) > 0,
-      //   Error::<T>::VoteBalanceZero
-      // );
+      // --- Ensure conviction lock has passed
+      // Votes without conviction can be unlocked on conclusion
+      if let Some(vote_lock) = VoteLocks::<T>::get(&account_id, proposal_index) {
+        let outcome = ProposalOutcomes::<T>::get(proposal_index).ok_or(Error::<T>::ProposalInvalid)?;
+        let enactment_period = Self::convert_block_as_u64(T::EnactmentPeriod::get());
+        let unlock_block: u64 = outcome.concluded_block.saturating_add(
+          enactment_period.saturating_mul(vote_lock.conviction.lock_periods())
+        );
+        ensure!(
+          Self::get_current_block_as_u64() >= unlock_block,
+          Error::<T>::VoteLocked
+        );
+        VoteLocks::<T>::remove(&account_id, proposal_index);
+      }
 
-      // let reserved = T::Currency::reserved_balance(
-      //   &account_id,
-      // );
+      VotesBalance::<T>::remove(proposal_index, &account_id);
 
-      // T::Currency::unreserve(
-      //   &account_id,
-      //   balance,
-      // );
+      Self::update_vote_lock(&account_id);
   
       Ok(())
     }
@@ -1002,13 +1217,13 @@ pub mod pallet {
 
       // Ensure not already verified
       ensure!(
-        subnet_nodes_verified.insert(account_id.clone()),
+        subnet_nodes_verified.try_insert(account_id.clone()).unwrap_or(false),
         Error::<T>::SubnetNodeAlreadyVerified
       );
 
       Proposals::<T>::mutate(
         proposal_index,
-        |params: &mut PropsParams<T::AccountId>| {
+        |params: &mut PropsParams<T>| {
           params.subnet_nodes_verified = subnet_nodes_verified;
         },
       );
@@ -1086,13 +1301,13 @@ pub mod pallet {
 
       // Ensure not already bonded
       ensure!(
-        subnet_nodes_bonded.insert(account_id.clone(), min_stake) == None,
+        matches!(subnet_nodes_bonded.try_insert(account_id.clone(), min_stake), Ok(None)),
         Error::<T>::SubnetNodeAlreadyBonded
       );
 
       Proposals::<T>::mutate(
         proposal_index,
-        |params: &mut PropsParams<T::AccountId>| {
+        |params: &mut PropsParams<T>| {
           params.subnet_nodes_bonded = subnet_nodes_bonded;
         },
       );
@@ -1170,40 +1385,74 @@ pub mod pallet {
 
       // Ensure not already bonded
       ensure!(
-        subnet_nodes_bonded.insert(account_id.clone(), min_stake) == None,
+        matches!(subnet_nodes_bonded.try_insert(account_id.clone(), min_stake), Ok(None)),
         Error::<T>::SubnetNodeAlreadyBonded
       );
 
       Proposals::<T>::mutate(
         proposal_index,
-        |params: &mut PropsParams<T::AccountId>| {
+        |params: &mut PropsParams<T>| {
           params.subnet_nodes_bonded = subnet_nodes_bonded;
         },
       );
   
       Ok(())
     }
+
+    /// Enact a proposal at the end of its voting period
+    ///
+    /// Scheduled on ``propose`` and dispatched by the scheduler so passed proposals
+    /// don't lapse waiting for a caller to ``execute``
+    #[pallet::call_index(9)]
+    #[pallet::weight(T::WeightInfo::execute())]
+    pub fn enact(
+      origin: OriginFor<T>, 
+      proposal_index: PropIndex,
+    ) -> DispatchResult {
+      ensure_root(origin)?;
+
+      ensure!(
+        Proposals::<T>::contains_key(proposal_index),
+        Error::<T>::ProposalInvalid
+      );
+
+      let proposal = Proposals::<T>::get(proposal_index);
+
+      Self::try_execute(proposal.proposer, proposal_index)
+    }
   }
 
   #[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+    fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+      Self::prune_concluded_proposals(Self::convert_block_as_u64(block_number))
+    }
+
     fn offchain_worker(block_number: BlockNumberFor<T>) {
     }
+
+    fn integrity_test() {
+      assert!(
+        T::ProposalRetentionPeriod::get() >= 
+          T::EnactmentPeriod::get().saturating_mul((Conviction::Locked4x.lock_periods() as u32).into()),
+        "ProposalRetentionPeriod must outlast the longest conviction lock"
+      );
+    }
   }
 }
 
 // impl<T: Config + pallet::Config> Pallet<T> {
 impl<T: Config> Pallet<T> {
-  fn try_propose_activate(account_id: T::AccountId, subnet_data: RegistrationSubnetData, mut subnet_nodes: Vec<SubnetNode<T::AccountId>>) -> DispatchResult {
+  fn try_propose_activate(account_id: T::AccountId, subnet_data: RegistrationSubnetData<T::MaxPathLength>, mut subnet_nodes: Vec<SubnetNode<T::AccountId>>) -> DispatchResult {
     // --- Ensure path doesn't already exist in Network or SubnetVoting
     // If it doesn't already exist, then it has either been not proposed or deactivated
     ensure!(
-      !T::SubnetVote::get_subnet_path_exist(subnet_data.clone().path),
+      !T::SubnetVote::get_subnet_path_exist(subnet_data.path.to_vec()),
       Error::<T>::SubnetPathExists
     );
 
     // --- Ensure proposal on subnet path not already in progress
-    let proposal_status = PropsPathStatus::<T>::get(subnet_data.clone().path);
+    let proposal_status = PropsPathStatus::<T>::get(subnet_data.path.clone());
 
     // --- Ensure not active
     // A proposal can only be active if the subnet is not already initialized into the blockchain
@@ -1222,6 +1471,14 @@ impl<T: Config> Pallet<T> {
       Error::<T>::SubnetMemoryIsZero
     );
 
+    // --- Ensure the network pallet would accept the subnet if the proposal succeeds
+    T::SubnetVote::vote_subnet_in(
+      SubnetDemocracySubnetData {
+        data: subnet_data.clone(),
+        active: false,
+      }
+    ).map_err(|e| e)?;
+
     // Remove duplicates based on peer_id and account_id
     subnet_nodes.dedup_by(|a, b| a.peer_id == b.peer_id && a.account_id == b.account_id);
 
@@ -1268,9 +1525,9 @@ impl<T: Config> Pallet<T> {
     Ok(())
   }
 
-  fn try_propose_deactivate(account_id: T::AccountId, path: Vec<u8>) -> DispatchResult {
+  fn try_propose_deactivate(account_id: T::AccountId, path: BoundedVec<u8, T::MaxPathLength>) -> DispatchResult {
     // --- Ensure subnet ID exists to be removed
-    let subnet_id = T::SubnetVote::get_subnet_id_by_path(path.clone());
+    let subnet_id = T::SubnetVote::get_subnet_id_by_path(path.to_vec());
     ensure!(
       subnet_id != 0,
       Error::<T>::SubnetIdNotExists
@@ -1300,21 +1557,259 @@ impl<T: Config> Pallet<T> {
     Ok(())
   }
 
-  fn try_activate_proposal(
-    proposal_index: PropIndex,
-    proposal: PropsParams<T::AccountId>,
-  ) -> DispatchResult {
+  /// Bound the subnet nodes entered into a proposal
+  fn bound_subnet_nodes(
+    subnet_nodes: Vec<SubnetNode<T::AccountId>>
+  ) -> Result<BoundedVec<ProposalSubnetNode<T>, T::MaxProposalSubnetNodes>, DispatchError> {
+    let mut bounded_subnet_nodes: BoundedVec<ProposalSubnetNode<T>, T::MaxProposalSubnetNodes> = BoundedVec::new();
+    for subnet_node in subnet_nodes.into_iter() {
+      let peer_id: BoundedVec<u8, T::MaxPeerIdLength> = subnet_node.peer_id.0
+        .try_into()
+        .map_err(|_| Error::<T>::InvalidPeerId)?;
+      bounded_subnet_nodes
+        .try_push(ProposalSubnetNode { account_id: subnet_node.account_id, peer_id: peer_id })
+        .map_err(|_| Error::<T>::TooManySubnetNodes)?;
+    }
+    Ok(bounded_subnet_nodes)
+  }
+
+  /// Subnet data of a proposal as used by the network pallet
+  fn registration_subnet_data(proposal: &PropsParams<T>) -> RegistrationSubnetData<T::MaxPathLength> {
+    RegistrationSubnetData {
+      path: proposal.path.clone(),
+      memory_mb: proposal.memory_mb,
+      registration_blocks: proposal.registration_blocks,
+    }
+  }
+
+  /// Keep a compact record of a concluded proposal
+  fn record_outcome(proposal_index: PropIndex) {
+    let proposal = Proposals::<T>::get(proposal_index);
+
+    // --- Voting has closed, stake changes no longer need to be tracked for its voting power
+    T::SubnetVote::remove_voting_power_snapshot(proposal.start_vote_block);
+
+    ProposalOutcomes::<T>::insert(
+      proposal_index,
+      ProposalOutcome {
+        proposer: proposal.proposer,
+        proposal_type: proposal.proposal_type,
+        proposal_status: proposal.proposal_status,
+        path: proposal.path,
+        votes: Votes::<T>::get(proposal_index),
+        concluded_block: Self::get_current_block_as_u64(),
+      }
+    );
+
+    // --- Queue for pruning
+    let position = ConcludedCount::<T>::get();
+    ConcludedProposals::<T>::insert(position, proposal_index);
+    ConcludedCount::<T>::put(position.saturating_add(1));
+  }
+
+  /// Prune the concluded proposal at the ``PruneCursor`` once its retention period has passed
+  //
+  // Proposals are queued in the order they conclude so proposals still in voting never hold up pruning.
+  // Votes are pruned up to ``MaxVotesPrunedPerBlock`` per block, the proposal is removed 
+  // once all of its votes are and the cursor moves to the next concluded proposal
+  pub fn prune_concluded_proposals(block: u64) -> Weight {
+    let db_weight = T::DbWeight::get();
+    let mut weight = db_weight.reads(2);
+
+    let position = PruneCursor::<T>::get();
+    let proposal_index = match ConcludedProposals::<T>::get(position) {
+      Some(proposal_index) => proposal_index,
+      None => return weight,
+    };
+
+    weight = weight.saturating_add(db_weight.reads(1));
+    let outcome = match ProposalOutcomes::<T>::get(proposal_index) {
+      Some(outcome) => outcome,
+      None => return weight,
+    };
+
+    let retention_period = Self::convert_block_as_u64(T::ProposalRetentionPeriod::get());
+    if block < outcome.concluded_block.saturating_add(retention_period) {
+      return weight
+    }
+
+    let max_votes = T::MaxVotesPrunedPerBlock::get();
+    let voters: Vec<T::AccountId> = VotesBalance::<T>::iter_key_prefix(proposal_index)
+      .take(max_votes as usize)
+      .collect();
+
+    for account_id in voters.iter() {
+      VotesBalance::<T>::remove(proposal_index, account_id);
+      VotingPowerSnapshots::<T>::remove(proposal_index, account_id);
+      // Conviction locks have passed by the end of the retention period
+      if VoteLocks::<T>::take(account_id, proposal_index).is_some() {
+        Self::update_vote_lock(account_id);
+        weight = weight.saturating_add(db_weight.reads_writes(2, 1));
+      }
+      weight = weight.saturating_add(db_weight.reads_writes(2, 3));
+    }
+
+    // --- Continue pruning votes next block
+    if voters.len() as u32 == max_votes {
+      return weight
+    }
+
+    let proposal = Proposals::<T>::take(proposal_index);
+    Votes::<T>::remove(proposal_index);
+    QuorumVotes::<T>::remove(proposal_index);
+    TotalVotingPowerSnapshot::<T>::remove(proposal_index);
+
+    // --- Keep the paths status if a newer proposal is active on it
+    PropsPathStatus::<T>::mutate_exists(proposal.path, |status| {
+      if *status != Some(PropsStatus::Active) {
+        *status = None;
+      }
+    });
+
+    ConcludedProposals::<T>::remove(position);
+    PruneCursor::<T>::put(position.saturating_add(1));
+
+    weight.saturating_add(db_weight.reads_writes(2, 6))
+  }
+
+  /// Conclude a proposal once its voting period has ended
+  //
+  // This cannot fail as long as the proposal ID exists, isn't concluded, and the voting period has completed
+  fn try_execute(account_id: T::AccountId, proposal_index: PropIndex) -> DispatchResult {
+    // --- Ensure proposal exists
+    ensure!(
+      Proposals::<T>::contains_key(proposal_index),
+      Error::<T>::ProposalInvalid
+    );
+
+    let proposal = Proposals::<T>::get(proposal_index);
+
+    // --- Ensure proposal is active and has not concluded
     ensure!(
       proposal.proposal_status == PropsStatus::Active,
       Error::<T>::Concluded
     );
 
-    let mut active_activate_proposals: BTreeSet<PropIndex> = ActiveActivateProposals::<T>::get();
     ensure!(
-      active_activate_proposals.len() < T::MaxProposals::get() as usize,
+      !Self::is_voting_open(proposal.clone()),
+      Error::<T>::VotingOpen
+    );
+
+    // --- Ensure voting has ended
+    let end_vote_block = proposal.end_vote_block;
+    let block = Self::get_current_block_as_u64();
+
+    ensure!(
+      block > end_vote_block,
+      Error::<T>::VoteActive
+    );
+
+    // --- We made it past the voting period, we cannot fail from here
+
+    if proposal.proposal_type == PropsType::Activate {
+      ActivateProposalsCount::<T>::mutate(|n: &mut u32| n.saturating_dec());
+    } else if proposal.proposal_type == PropsType::Deactivate {
+      DeactivateProposalsCount::<T>::mutate(|n: &mut u32| n.saturating_dec());
+    }
+    
+    // --- If enactment period has passed, expire the proposal
+    // Don't revert here to allow expired paths to be reproposed
+    if block > end_vote_block + Self::convert_block_as_u64(T::EnactmentPeriod::get())  {
+      Self::unreserve_subnet_node_bonds(proposal.clone());
+      Self::try_expire(proposal_index, proposal.path.clone())
+        .map_err(|e| e)?;
+      return Ok(())
+    }
+
+    // --- Get status of proposal
+    let votes = Votes::<T>::get(proposal_index);
+
+    let quorum_reached = Self::quorum_reached(proposal_index);
+    let vote_succeeded = Self::vote_succeeded(votes.clone());
+
+    let proposer_stake_as_balance = Self::u128_to_balance(proposal.proposer_stake);
+    // --- Unreserve here to pay for initialization fee or give back to proposer
+    T::Currency::unreserve(
+      &proposal.proposer,
+      proposer_stake_as_balance.unwrap(),
+    );
+
+    // --- Unreserve bonds here to be staked into the subnet or given back to the subnet nodes
+    Self::unreserve_subnet_node_bonds(proposal.clone());
+
+    // --- Remove proposal from active proposals
+    Self::try_deactivate_proposal(
+      proposal_index,
+      proposal.clone(),
+    );
+  
+    // --- If quorum and vote YAYS aren greater than vote NAYS, then pass, else, defeat
+    if quorum_reached && vote_succeeded {
+      Self::try_succeed(account_id, proposal_index, proposal.clone())
+        .map_err(|e| e)?;
+    } else if quorum_reached && !vote_succeeded {
+      Self::try_defeat(proposal_index, proposal.path.clone())
+        .map_err(|e| e)?;
+    } else {
+      Self::try_expire(proposal_index, proposal.path.clone())
+        .map_err(|e| e)?;
+    }
+
+    Ok(())
+  }
+
+  fn enactment_task_name(proposal_index: PropIndex) -> TaskName {
+    (SUBNET_DEMOCRACY_ID, "enactment", proposal_index).using_encoded(sp_io::hashing::blake2_256)
+  }
+
+  /// Schedule the proposals enactment to be dispatched as root
+  fn schedule_enactment(proposal_index: PropIndex, when: BlockNumberFor<T>) -> DispatchResult {
+    let call: BoundedCallOf<T> = T::Preimages::bound(
+      CallOf::<T>::from(Call::enact { proposal_index })
+    )?;
+
+    T::Scheduler::schedule_named(
+      Self::enactment_task_name(proposal_index),
+      DispatchTime::At(when),
+      None,
+      ENACTMENT_PRIORITY,
+      frame_system::RawOrigin::Root.into(),
+      call,
+    ).map_err(|_| Error::<T>::EnactmentScheduleFailed)?;
+
+    Ok(())
+  }
+
+  /// Cancel the proposals scheduled enactment if it's still pending
+  fn cancel_enactment(proposal_index: PropIndex) {
+    let _ = T::Scheduler::cancel_named(Self::enactment_task_name(proposal_index));
+  }
+
+  fn try_propose_update(path: BoundedVec<u8, T::MaxPathLength>, subnet_update: SubnetUpdateData) -> DispatchResult {
+    // --- Ensure proposal on subnet path not already in progress
+    let proposal_status = PropsPathStatus::<T>::get(path.clone());
+
+    ensure!(
+      proposal_status != PropsStatus::Active,
       Error::<T>::ProposalInvalid
     );
 
+    // --- Ensure subnet exists and the update is valid as of now
+    // This is validated again on execution
+    T::SubnetVote::vote_subnet_update(path.to_vec(), subnet_update)
+  }
+
+  fn try_activate_proposal(
+    proposal_index: PropIndex,
+    proposal: PropsParams<T>,
+  ) -> DispatchResult {
+    ensure!(
+      proposal.proposal_status == PropsStatus::Active,
+      Error::<T>::Concluded
+    );
+
+    let mut active_activate_proposals: BoundedBTreeSet<PropIndex, T::MaxProposals> = ActiveActivateProposals::<T>::get();
+
     ensure!(
       Self::is_verified(proposal),
       Error::<T>::ProposalNotVerified
@@ -1325,30 +1820,31 @@ impl<T: Config> Pallet<T> {
     //   Error::<T>::ProposalNotVerified
     // );
 
-    active_activate_proposals.insert(proposal_index);
+    active_activate_proposals.try_insert(proposal_index)
+      .map_err(|_| Error::<T>::ProposalInvalid)?;
     ActiveActivateProposals::<T>::put(active_activate_proposals);
     Ok(())
   }
 
   fn try_deactivate_proposal(
     proposal_index: PropIndex,
-    proposal: PropsParams<T::AccountId>,
+    proposal: PropsParams<T>,
   ) -> DispatchResult {
-    let mut active_activate_proposals: BTreeSet<PropIndex> = ActiveActivateProposals::<T>::get();
+    let mut active_activate_proposals: BoundedBTreeSet<PropIndex, T::MaxProposals> = ActiveActivateProposals::<T>::get();
     active_activate_proposals.remove(&proposal_index);
     ActiveActivateProposals::<T>::put(active_activate_proposals);
     Ok(())
   }
 
   /// Cast vote on a proposal
-  // Each account must be a staker
-  // The ``vote_amount`` is not reserved
+  // The ``vote_amount`` is locked until the proposal concludes, or longer with conviction
   fn try_cast_vote(
     account_id: T::AccountId, 
     proposal_index: PropIndex, 
-    proposal: PropsParams<T::AccountId>,
+    proposal: PropsParams<T>,
     vote_amount: BalanceOf<T>,
     vote: VoteType,
+    conviction: Conviction,
   ) -> DispatchResult {
     ensure!(
       Self::is_proposal_active(proposal_index, proposal.clone()),
@@ -1361,8 +1857,15 @@ impl<T: Config> Pallet<T> {
       Error::<T>::VotingNotOpen
     );
 
-    // --- Get staked balance of voter
-    let available_balance = Self::get_available_vote_balance(proposal_index, account_id.clone());
+    // --- Ensure voting has started
+    // Voting power is read as of the ``start_vote_block``
+    ensure!(
+      Self::get_current_block_as_u64() >= proposal.start_vote_block,
+      Error::<T>::VotingNotOpen
+    );
+
+    // --- Get balance of voter not yet voted on this proposal
+    let available_balance = Self::get_available_vote_balance(proposal_index, proposal.start_vote_block, account_id.clone());
 
     // --- Ensure balance is some
     ensure!(
@@ -1377,29 +1880,45 @@ impl<T: Config> Pallet<T> {
       Error::<T>::NotEnoughBalanceToVote
     );
 
-    // --- Increase accounts reserved voting balance in relation to proposal index
+    // --- Increase accounts voting balance in relation to proposal index
     VotesBalance::<T>::mutate(proposal_index.clone(), account_id.clone(), |n| *n += vote_amount);
 
+    // --- Lock the vote balance
+    VoteLocks::<T>::mutate(&account_id, proposal_index, |maybe_vote_lock| {
+      let vote_lock = maybe_vote_lock.get_or_insert(VoteLock::default());
+      vote_lock.amount = vote_lock.amount.saturating_add(vote_amount_as_u128);
+      vote_lock.conviction = vote_lock.conviction.max(conviction);
+    });
+
+    Self::update_vote_lock(&account_id);
+
+    let voting_power: u128 = vote_amount_as_u128.saturating_mul(conviction.multiplier());
+
+    // --- The quorum is measured against the total voting power, so it counts vote balances without conviction
+    if vote != VoteType::Nay {
+      QuorumVotes::<T>::mutate(proposal_index, |n| n.saturating_accrue(vote_amount_as_u128));
+    }
+
     // --- Save vote
     if vote == VoteType::Yay {
       Votes::<T>::mutate(
         proposal_index.clone(),
         |params: &mut VotesParams| {
-          params.yay += vote_amount_as_u128;
+          params.yay += voting_power;
         }
       );
     } else if vote == VoteType::Nay {
       Votes::<T>::mutate(
         proposal_index.clone(),
         |params: &mut VotesParams| {
-          params.nay += vote_amount_as_u128;
+          params.nay += voting_power;
         }
       );  
     } else {
       Votes::<T>::mutate(
         proposal_index.clone(),
         |params: &mut VotesParams| {
-          params.abstain += vote_amount_as_u128;
+          params.abstain += voting_power;
         }
       );  
     }
@@ -1411,55 +1930,83 @@ impl<T: Config> Pallet<T> {
     Ok(())
   }
 
-  fn get_voting_power(account_id: T::AccountId, balance: BalanceOf<T>) -> u128 {
-    let is_submittable_subnet_node_account: bool = T::SubnetVote::is_submittable_subnet_node_account(account_id);
+  /// Get the voting power of an account from its stake as of ``block``
+  // Submittable subnet nodes receive the ``NodeVotePremium``
+  fn get_voting_power(account_id: T::AccountId, block: u64) -> u128 {
+    let stake_balance: u128 = Self::get_stake_balance(account_id.clone(), block);
 
-    if is_submittable_subnet_node_account {
+    if T::SubnetVote::is_submittable_subnet_node_account(account_id) {
       let peer_vote_premium = Perbill::from_rational(NodeVotePremium::<T>::get(), 100 as u128);
-      let voting_power = balance.saturating_add(peer_vote_premium * balance);
-   
-      return Self::balance_to_u128(voting_power)
+      return stake_balance.saturating_add(peer_vote_premium * stake_balance)
     }
 
-    Self::balance_to_u128(balance)
+    stake_balance
+  }
+
+  /// Get the voting power of an account on a proposal as of its ``start_vote_block``
+  // Stake moved after voting opens doesn't change the voting power of either account
+  fn get_voting_power_snapshot(proposal_index: PropIndex, start_vote_block: u64, account_id: T::AccountId) -> u128 {
+    if let Some(voting_power) = VotingPowerSnapshots::<T>::get(proposal_index, &account_id) {
+      return voting_power
+    }
+
+    // --- Cache the total voting power on the proposals first vote for its quorum
+    if !TotalVotingPowerSnapshot::<T>::contains_key(proposal_index) {
+      TotalVotingPowerSnapshot::<T>::insert(proposal_index, Self::get_total_voting_power(start_vote_block));
+    }
+
+    let voting_power = Self::get_voting_power(account_id.clone(), start_vote_block);
+    VotingPowerSnapshots::<T>::insert(proposal_index, &account_id, voting_power);
+    voting_power
   }
 
   fn try_succeed(
     activator: T::AccountId,
     proposal_index: PropIndex, 
-    proposal: PropsParams<T::AccountId>, 
+    proposal: PropsParams<T>, 
   ) -> DispatchResult {
     Proposals::<T>::mutate(
       proposal_index,
-      |params: &mut PropsParams<T::AccountId>| {
+      |params: &mut PropsParams<T>| {
         params.proposal_status = PropsStatus::Succeeded;
       },
     );
 
-    PropsPathStatus::<T>::insert(proposal.clone().subnet_data.path, PropsStatus::Succeeded);
+    PropsPathStatus::<T>::insert(proposal.path.clone(), PropsStatus::Succeeded);
 
     ActiveProposalsCount::<T>::mutate(|n: &mut u32| n.saturating_dec());
 
+    Self::record_outcome(proposal_index);
+
     if proposal.proposal_type == PropsType::Activate {
-      Self::try_activate_subnet(activator, proposal.clone().proposer, proposal.clone().subnet_data);
+      Self::try_activate_subnet(activator, proposal.clone())
+        .map_err(|e| e)?;
+    } else if proposal.proposal_type == PropsType::Update {
+      Self::try_update_subnet(proposal.clone())
+        .map_err(|e| e)?;
     } else {
-      Self::try_deactivate_subnet(activator, proposal.clone().proposer, proposal.clone().subnet_data);
+      Self::try_deactivate_subnet(
+        activator, 
+        proposal.clone().proposer, 
+        Self::registration_subnet_data(&proposal),
+        proposal.vote_out_reason.unwrap_or(VoteOutReason::Other),
+      ).map_err(|e| e)?;
     }
 
     // --- Proposal stake unservered in the `execute`, update to reflect no reserves 
     Proposals::<T>::mutate(
       proposal_index,
-      |params: &mut PropsParams<T::AccountId>| {
+      |params: &mut PropsParams<T>| {
         params.proposer_stake = 0;
       },
     );
     Ok(())
   }
 
-  fn try_defeat(proposal_index: PropIndex, path: Vec<u8>) -> DispatchResult {
+  fn try_defeat(proposal_index: PropIndex, path: BoundedVec<u8, T::MaxPathLength>) -> DispatchResult {
     Proposals::<T>::mutate(
       proposal_index,
-      |params: &mut PropsParams<T::AccountId>| {
+      |params: &mut PropsParams<T>| {
         params.proposal_status = PropsStatus::Defeated;
       },
     );
@@ -1468,13 +2015,15 @@ impl<T: Config> Pallet<T> {
 
     ActiveProposalsCount::<T>::mutate(|n: &mut u32| n.saturating_dec());
 
+    Self::record_outcome(proposal_index);
+
     Ok(())
   }
   
-  fn try_cancel(proposal_index: PropIndex, path: Vec<u8>) -> DispatchResult {
+  fn try_cancel(proposal_index: PropIndex, path: BoundedVec<u8, T::MaxPathLength>) -> DispatchResult {
     Proposals::<T>::mutate(
       proposal_index,
-      |params: &mut PropsParams<T::AccountId>| {
+      |params: &mut PropsParams<T>| {
         params.proposal_status = PropsStatus::Cancelled;
         params.proposer_stake = 0;
       },
@@ -1484,13 +2033,15 @@ impl<T: Config> Pallet<T> {
 
     ActiveProposalsCount::<T>::mutate(|n: &mut u32| n.saturating_dec());
 
+    Self::record_outcome(proposal_index);
+
     Ok(())
   }
 
-  fn try_expire(proposal_index: PropIndex, path: Vec<u8>) -> DispatchResult {
+  fn try_expire(proposal_index: PropIndex, path: BoundedVec<u8, T::MaxPathLength>) -> DispatchResult {
     Proposals::<T>::mutate(
       proposal_index,
-      |params: &mut PropsParams<T::AccountId>| {
+      |params: &mut PropsParams<T>| {
         params.proposal_status = PropsStatus::Expired;
       },
     );
@@ -1499,21 +2050,23 @@ impl<T: Config> Pallet<T> {
 
     ActiveProposalsCount::<T>::mutate(|n: &mut u32| n.saturating_dec());
 
+    Self::record_outcome(proposal_index);
+
     Ok(())
   }
 
   /// Is the proposal verified by subnet nodes entered in during activate proposals
   // If deactivate proposal, it will always return true since 0 == 0
-  pub fn is_verified(proposal: PropsParams<T::AccountId>) -> bool {
+  pub fn is_verified(proposal: PropsParams<T>) -> bool {
     proposal.subnet_nodes.len() == proposal.subnet_nodes_verified.len()
   }
 
-  pub fn is_bonded(proposal: PropsParams<T::AccountId>) -> bool {
+  pub fn is_bonded(proposal: PropsParams<T>) -> bool {
     proposal.subnet_nodes.len() == proposal.subnet_nodes_bonded.len()
   }
 
   /// Is voting active and within voting period
-  fn is_voting_open(proposal: PropsParams<T::AccountId>) -> bool {
+  fn is_voting_open(proposal: PropsParams<T>) -> bool {
     let block = Self::get_current_block_as_u64();
     let end_vote_block = proposal.end_vote_block;
     
@@ -1524,28 +2077,77 @@ impl<T: Config> Pallet<T> {
     votes.yay > votes.nay
   }
 
-  fn quorum_reached(votes: VotesParams) -> bool {
-    // let quorum = Quorum::<T>::get();
-    let total_quorum_votes = votes.yay + votes.abstain;
-    total_quorum_votes >= T::Quorum::get()
+  fn quorum_reached(proposal_index: PropIndex) -> bool {
+    QuorumVotes::<T>::get(proposal_index) >= Self::get_quorum(proposal_index)
   }
 
-  /// Activate subnet - Someone must add_subnet once activated
-  fn try_activate_subnet(activator: T::AccountId, proposer: T::AccountId, subnet_data: RegistrationSubnetData) -> DispatchResult {
+  /// Activate subnet - Registers the subnet and its bonded bootstrap subnet nodes
+  // The proposer stake and subnet node bonds are unreserved in ``execute`` before this is called
+  fn try_activate_subnet(activator: T::AccountId, proposal: PropsParams<T>) -> DispatchResult {
+    let subnet_data = Self::registration_subnet_data(&proposal);
+
     let vote_subnet_data = SubnetDemocracySubnetData {
       data: subnet_data.clone(),
       active: true,
     };
 
+    // --- Register subnet using the proposers initialization fee
+    // The fee was quoted and reserved on ``propose``, so later registrations don't raise it
     T::SubnetVote::vote_activated(
       activator.clone(),
-      subnet_data.clone().path, 
-      proposer.clone(),
-      vote_subnet_data.clone()
-    )
+      subnet_data.path.to_vec(), 
+      proposal.proposer.clone(),
+      vote_subnet_data.clone(),
+      proposal.proposer_stake,
+    ).map_err(|e| e)?;
+
+    let subnet_id = T::SubnetVote::get_subnet_id_by_path(subnet_data.path.to_vec());
+
+    // --- Register each bonded bootstrap subnet node using its bond as stake
+    for subnet_node in proposal.subnet_nodes.iter() {
+      let stake = match proposal.subnet_nodes_bonded.get(&subnet_node.account_id) {
+        Some(stake) => *stake,
+        None => continue,
+      };
+
+      T::SubnetVote::vote_add_subnet_node(
+        RawOrigin::Signed(subnet_node.account_id.clone()).into(),
+        subnet_id,
+        PeerId(subnet_node.peer_id.to_vec()),
+        stake,
+      ).map_err(|e| e)?;
+    }
+
+    Ok(())
+  }
+
+  /// Unreserve each subnet nodes bond on proposal conclusion
+  fn unreserve_subnet_node_bonds(proposal: PropsParams<T>) {
+    for (account_id, bond) in proposal.subnet_nodes_bonded.iter() {
+      if let Some(bond_as_balance) = Self::u128_to_balance(*bond) {
+        T::Currency::unreserve(
+          account_id,
+          bond_as_balance,
+        );
+      }
+    }
+  }
+
+  fn try_update_subnet(proposal: PropsParams<T>) -> DispatchResult {
+    let subnet_update = match proposal.subnet_update {
+      Some(subnet_update) => subnet_update,
+      None => return Err(Error::<T>::SubnetUpdateInvalid.into()),
+    };
+
+    T::SubnetVote::vote_updated(proposal.path.to_vec(), subnet_update)
   }
 
-  fn try_deactivate_subnet(activator: T::AccountId, proposer: T::AccountId, subnet_data: RegistrationSubnetData) -> DispatchResult {
+  fn try_deactivate_subnet(
+    activator: T::AccountId, 
+    proposer: T::AccountId, 
+    subnet_data: RegistrationSubnetData<T::MaxPathLength>,
+    vote_out_reason: VoteOutReason,
+  ) -> DispatchResult {
     let vote_subnet_data = SubnetDemocracySubnetData {
       data: subnet_data.clone(),
       active: false,
@@ -1553,22 +2155,29 @@ impl<T: Config> Pallet<T> {
 
     T::SubnetVote::vote_deactivated(
       activator.clone(),
-      subnet_data.clone().path, 
+      subnet_data.path.to_vec(), 
       proposer.clone(),
-      vote_subnet_data.clone()
+      vote_subnet_data.clone(),
+      vote_out_reason.into(),
     )
 
     // T::SubnetVote::vote_activated(subnet_data.clone().path, vote_subnet_data)
     // Ok(())
   }
 
-  /// Get total voting power at the time of the proposal
-  fn get_quorum() -> u128 {
-    // T::Quorum::get()
-    let network_voting_power: u128 = T::SubnetVote::get_voting_power();
+  /// Get the quorum of a proposal from its total voting power snapshot
+  // ``Quorum`` is the minimum quorum regardless of total voting power
+  fn get_quorum(proposal_index: PropIndex) -> u128 {
+    let total_voting_power: u128 = TotalVotingPowerSnapshot::<T>::get(proposal_index).unwrap_or(0);
+    let quorum: u128 = Percent::from_percent(T::QuorumVotingPowerPercentage::get()) * total_voting_power;
+    quorum.max(T::Quorum::get())
+  }
+
+  /// Get total voting power across the network as of ``block``
+  fn get_total_voting_power(block: u64) -> u128 {
+    let network_voting_power: u128 = T::SubnetVote::get_voting_power(block);
     let blockchain_voting_power: u128 = 0;
-    let total_voting_power = network_voting_power.saturating_add(blockchain_voting_power);
-    total_voting_power - Percent::from_percent(T::QuorumVotingPowerPercentage::get()) * total_voting_power
+    network_voting_power.saturating_add(blockchain_voting_power)
   }
 
   /// Get the accounts overall stake across:
@@ -1576,11 +2185,11 @@ impl<T: Config> Pallet<T> {
   /// - Blockchain delegate stake
   /// - Subnet stake
   /// - Subnet delegate stake
-  fn get_stake_balance(account_id: T::AccountId) -> u128 {
+  fn get_stake_balance(account_id: T::AccountId, block: u64) -> u128 {
     /// TODO: Add voting power based on validator level
     /// i.e. subnet nodes get 100%, blockchain nodes get 100%, subnet delegates get 50%, blockchain delegates get 50%
-    let subnet_stake_balance: u128 = T::SubnetVote::get_stake_balance(account_id.clone());
-    let subnet_delegate_stake_balance: u128 = T::SubnetVote::get_delegate_stake_balance(account_id.clone());
+    let subnet_stake_balance: u128 = T::SubnetVote::get_stake_balance(account_id.clone(), block);
+    let subnet_delegate_stake_balance: u128 = T::SubnetVote::get_delegate_stake_balance(account_id.clone(), block);
     let blockchain_stake_balance: u128 = 0;
     let blockchain_delegate_stake_balance: u128 = 0;
     subnet_stake_balance
@@ -1596,19 +2205,36 @@ impl<T: Config> Pallet<T> {
   }
 
   /// Get total available vote balance for a given proposal
-  fn get_available_vote_balance(proposal_index: PropIndex, account_id: T::AccountId) -> u128 {
+  // The vote balance can't exceed the accounts voting power as of the ``start_vote_block``
+  // Votes are locked in the free balance, so the vote balance can't exceed the lockable free balance either
+  fn get_available_vote_balance(proposal_index: PropIndex, start_vote_block: u64, account_id: T::AccountId) -> u128 {
     let vote_balance = Self::get_vote_balance(proposal_index, account_id.clone());
-    let stake_balance = Self::get_stake_balance(account_id.clone());
-    // Redundant but check regardless
-    if vote_balance >= stake_balance {
-      return 0;
+    let voting_power = Self::get_voting_power_snapshot(proposal_index, start_vote_block, account_id.clone());
+    let lockable_balance = Self::balance_to_u128(T::Currency::free_balance(&account_id));
+    voting_power.min(lockable_balance).saturating_sub(vote_balance)
+  }
+
+  /// Set the accounts democracy lock to the largest of its vote locks
+  fn update_vote_lock(account_id: &T::AccountId) {
+    let max_vote_lock: u128 = VoteLocks::<T>::iter_prefix_values(account_id)
+      .map(|vote_lock| vote_lock.amount)
+      .max()
+      .unwrap_or(0);
+
+    match Self::u128_to_balance(max_vote_lock) {
+      Some(balance) if max_vote_lock > 0 => T::Currency::set_lock(
+        SUBNET_DEMOCRACY_ID,
+        account_id,
+        balance,
+        WithdrawReasons::all(),
+      ),
+      _ => T::Currency::remove_lock(SUBNET_DEMOCRACY_ID, account_id),
     }
-    stake_balance - vote_balance
   }
 
-  fn is_proposal_active(proposal_index: PropIndex, proposal: PropsParams<T::AccountId>) -> bool {
-    // Deactivate proposals don't require validation from nodes so they are always active unless completed or cancelled
-    if proposal.proposal_type == PropsType::Deactivate {
+  fn is_proposal_active(proposal_index: PropIndex, proposal: PropsParams<T>) -> bool {
+    // Deactivate and update proposals don't require validation from nodes so they are always active unless completed or cancelled
+    if proposal.proposal_type != PropsType::Activate {
       if proposal.proposal_status == PropsStatus::Active {
         return true
       } else {
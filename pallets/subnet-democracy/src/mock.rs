@@ -17,12 +17,14 @@ use super::*;
 use crate as pallet_subnet_democracy;
 use frame_support::{
   parameter_types,
-  traits::Everything,
+  traits::{Everything, EqualPrivilegeOnly},
+  weights::Weight,
   PalletId,
   // sp_tracing,
   derive_impl
 };
 use frame_system as system;
+use frame_system::EnsureRoot;
 use sp_core::{ConstU128, ConstU32, ConstU64, H256, U256};
 use sp_runtime::BuildStorage;
 use sp_runtime::{
@@ -44,6 +46,8 @@ frame_support::construct_runtime!(
     InsecureRandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip,
     Balances: pallet_balances,
     Network: pallet_network,
+    Preimage: pallet_preimage,
+    Scheduler: pallet_scheduler,
     SubnetVoting: pallet_subnet_democracy,
 	}
 );
@@ -96,7 +100,7 @@ impl pallet_balances::Config for Test {
   // type AccountStore = StoredMap<Self::AccountId, AccountData<Self::Balance>>;
   // type AccountStore = AccountData<Balance>;
   type AccountStore = System;
-  type MaxLocks = ();
+  type MaxLocks = ConstU32<50>;
   type WeightInfo = ();
   type MaxReserves = ();
   type ReserveIdentifier = [u8; 8];
@@ -193,9 +197,36 @@ impl pallet_network::Config for Test {
 	type DelegateStakeEpochsRemovalWindow = DelegateStakeEpochsRemovalWindow;
   type MaxDelegateStakeUnlockings = MaxDelegateStakeUnlockings;
   type MaxStakeUnlockings = MaxStakeUnlockings;
+  type MaxSubnetNodes = ConstU32<1000>;
+  type MaxPathLength = ConstU32<256>;
   type MinProposalStake = MinProposalStake;
 }
 
+impl pallet_preimage::Config for Test {
+  type RuntimeEvent = RuntimeEvent;
+  type WeightInfo = ();
+  type Currency = Balances;
+  type ManagerOrigin = EnsureRoot<AccountId>;
+  type Consideration = ();
+}
+
+parameter_types! {
+  pub MaximumSchedulerWeight: Weight = Weight::from_parts(u64::MAX, u64::MAX);
+}
+
+impl pallet_scheduler::Config for Test {
+  type RuntimeEvent = RuntimeEvent;
+  type RuntimeOrigin = RuntimeOrigin;
+  type PalletsOrigin = OriginCaller;
+  type RuntimeCall = RuntimeCall;
+  type MaximumWeight = MaximumSchedulerWeight;
+  type ScheduleOrigin = EnsureRoot<AccountId>;
+  type MaxScheduledPerBlock = ConstU32<100>;
+  type WeightInfo = ();
+  type OriginPrivilegeCmp = EqualPrivilegeOnly;
+  type Preimages = Preimage;
+}
+
 parameter_types! {
 	pub const VotingPeriod: BlockNumber = DAYS * 21;
 	pub const EnactmentPeriod: BlockNumber = DAYS * 7;
@@ -204,6 +235,7 @@ parameter_types! {
   pub const Quorum: u128 = 100_000_000_000_000_000_000; // 100 * 1e18
   pub const CancelSlashPercent: u8 = 5;
   pub const QuorumVotingPowerPercentage: u8 = 40;
+  pub const ProposalRetentionPeriod: BlockNumber = DAYS * 28;
 }
 
 impl Config for Test {
@@ -211,6 +243,9 @@ impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type SubnetVote = Network;
 	type Currency = Balances;
+  type RuntimeCall = RuntimeCall;
+  type Scheduler = Scheduler;
+  type Preimages = Preimage;
 	type MaxActivateProposals = ConstU32<32>;
 	type MaxDeactivateProposals = ConstU32<32>;
 	type MaxProposals = ConstU32<32>;
@@ -221,6 +256,11 @@ impl Config for Test {
   type Quorum = Quorum;
   type CancelSlashPercent = CancelSlashPercent;
   type QuorumVotingPowerPercentage = QuorumVotingPowerPercentage;
+  type MaxPathLength = ConstU32<256>;
+  type MaxProposalSubnetNodes = ConstU32<256>;
+  type MaxPeerIdLength = ConstU32<128>;
+  type ProposalRetentionPeriod = ProposalRetentionPeriod;
+  type MaxVotesPrunedPerBlock = ConstU32<64>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -17,20 +17,22 @@
 use crate::mock::*;
 use sp_core::crypto::AccountId32;
 use frame_support::{
-	assert_noop, assert_ok, assert_err
+	assert_noop, assert_ok, assert_err, BoundedVec
 };
 use frame_system::pallet_prelude::OriginFor;
 use sp_runtime::Percent;
 use log::info;
 use sp_core::{H256, U256};
-use frame_support::traits::Currency;
+use frame_support::traits::{Currency, OnInitialize, Get};
 use sp_core::OpaquePeerId as PeerId;
 use crate::{
-  Error, SubnetNode, PropsType, SubnetVote, VotesBalance, ReservableCurrency, PropCount, VoteType,
+  Error, SubnetNode, PropsType, SubnetVote, VotesBalance, ReservableCurrency, PropCount, VoteType, Conviction, VoteLocks, VoteOutReason,
+  VotingPowerSnapshots, TotalVotingPowerSnapshot, QuorumVotes,
   Votes, ActiveProposalsCount, Proposals, PropsStatus, PropsPathStatus, BalanceOf, RegistrationSubnetData,
-  ActivateProposalsCount, ActiveActivateProposals, DeactivateProposalsCount
+  ActivateProposalsCount, ActiveActivateProposals, DeactivateProposalsCount, ProposalOutcomes, PruneCursor, ConcludedProposals
 };
 use strum::IntoEnumIterator;
+use codec::{Decode, Encode};
 
 type AccountIdOf<Test> = <Test as frame_system::Config>::AccountId;
 
@@ -39,6 +41,7 @@ const DEFAULT_PORT: u16 = 5000;
 const DEFAULT_DEPOSIT_AMOUNT: u128 = 10000000000000000000000; // 10,000
 const DEFAULT_MODEL_PATH: &str = "hf/llama2";
 const DEFAULT_EXISTING_MODEL_PATH: &str = "hf/baluga";
+const DEFAULT_VOTE_OUT_EVIDENCE: &str = "QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N";
 // Defaut is minimum stake per subnet node
 const DEFAUT_VOTE_AMOUNT: u128 = 1000e+18 as u128;
 
@@ -55,28 +58,38 @@ fn default_subnet_path() -> Vec<u8> {
   DEFAULT_MODEL_PATH.into()
 }
 
-fn default_add_subnet_data() -> RegistrationSubnetData {
+fn bounded_path(path: Vec<u8>) -> BoundedVec<u8, <Test as crate::Config>::MaxPathLength> {
+  path.try_into().unwrap()
+}
+
+fn default_add_subnet_data() -> RegistrationSubnetData<<Test as crate::Config>::MaxPathLength> {
   let subnet_data = RegistrationSubnetData {
-    path: DEFAULT_MODEL_PATH.into(),
+    path: bounded_path(DEFAULT_MODEL_PATH.into()),
 		memory_mb: 50000,
+    registration_blocks: pallet_network::MinSubnetRegistrationBlocks::<Test>::get(),
   };
   subnet_data
 }
 
-fn default_existing_add_subnet_data() -> RegistrationSubnetData {
+fn default_existing_add_subnet_data() -> RegistrationSubnetData<<Test as crate::Config>::MaxPathLength> {
   let subnet_data = RegistrationSubnetData {
-    path: DEFAULT_EXISTING_MODEL_PATH.into(),
+    path: bounded_path(DEFAULT_EXISTING_MODEL_PATH.into()),
 		memory_mb: 50000,
+    registration_blocks: pallet_network::MinSubnetRegistrationBlocks::<Test>::get(),
   };
   subnet_data
 }
 
+fn default_vote_out_evidence() -> BoundedVec<u8, pallet_network::DefaultProposalEvidenceLimit> {
+  BoundedVec::try_from(DEFAULT_VOTE_OUT_EVIDENCE.as_bytes().to_vec()).unwrap()
+}
+
 fn default_ip() -> Vec<u8> {
   DEFAULT_IP.into()
 }
 
 fn get_default_min_subnet_nodes() -> u32 {
-  let min_subnet_nodes = <pallet_network::Pallet<Test> as SubnetVote<OriginFor<Test>, <Test as frame_system::Config>::AccountId>>::get_min_subnet_nodes(
+  let min_subnet_nodes = <pallet_network::Pallet<Test> as SubnetVote<OriginFor<Test>, <Test as frame_system::Config>::AccountId, <Test as crate::Config>::MaxPathLength>>::get_min_subnet_nodes(
     default_add_subnet_data().memory_mb
   );
 
@@ -87,7 +100,7 @@ fn get_default_min_subnet_nodes() -> u32 {
 }
 
 fn get_default_existing_min_subnet_nodes() -> u32 {
-  let min_subnet_nodes = <pallet_network::Pallet<Test> as SubnetVote<OriginFor<Test>, <Test as frame_system::Config>::AccountId>>::get_min_subnet_nodes(
+  let min_subnet_nodes = <pallet_network::Pallet<Test> as SubnetVote<OriginFor<Test>, <Test as frame_system::Config>::AccountId, <Test as crate::Config>::MaxPathLength>>::get_min_subnet_nodes(
     default_add_subnet_data().memory_mb
   );
 
@@ -99,54 +112,17 @@ fn get_default_existing_min_subnet_nodes() -> u32 {
 
 fn build_existing_subnet(start: u32, end: u32) {
   let subnet_path: Vec<u8> = DEFAULT_EXISTING_MODEL_PATH.into();
-  let min_subnet_nodes = pallet_network::MinSubnetNodes::<Test>::get();
 
   let subnet_initialization_cost = get_subnet_initialization_cost();
   let _ = Balances::deposit_creating(&account(0), subnet_initialization_cost+1000);
 
-  let add_subnet_data = RegistrationSubnetData {
-    path: subnet_path.clone(),
-    memory_mb: 50000,
-  };
   assert_ok!(
-    Network::activate_subnet(
-      account(0),
-      account(0),
-      add_subnet_data,
+    Network::register_subnet(
+      RuntimeOrigin::signed(account(0)),
+      default_existing_add_subnet_data(),
     )
   );
 
-  // assert_ok!(
-  //   Network::vote_subnet(
-  //     RuntimeOrigin::signed(account(0)), 
-  //     subnet_path.clone(),
-  //   )
-  // );
-
-  // let add_subnet_data = RegistrationSubnetData {
-  //   path: subnet_path.clone(),
-  //   memory_mb: 50000,
-  // };
-
-  // assert_ok!(
-  //   Network::add_subnet(
-  //     RuntimeOrigin::signed(account(0)),
-  //     add_subnet_data.clone(),
-  //   ) 
-  // );
-  // let add_subnet_data = RegistrationSubnetData {
-  //   path: subnet_path.clone(),
-  //   memory_mb: 50000,
-  // };
-
-  // assert_ok!(
-  //   Network::activate_subnet(
-  //     account(0),
-  //     account(0),
-  //     add_subnet_data,
-  //   )
-  // );  
-
   let subnet_id = pallet_network::SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
   let min_stake = pallet_network::MinStakeBalance::<Test>::get();
 
@@ -157,9 +133,10 @@ fn build_existing_subnet(start: u32, end: u32) {
         RuntimeOrigin::signed(account(n)),
         subnet_id,
         peer(n),
-        // "172.20.54.234".into(),
-        // 8888,
         min_stake,
+        None,
+        None,
+        None,
       ) 
     );
   }
@@ -170,14 +147,9 @@ fn build_existing_subnet(start: u32, end: u32) {
   System::set_block_number(starting_block + submit_epochs * epoch_length + 1);
 
   let epoch = System::block_number() / epoch_length;
-  let node_set = pallet_network::get_classified_accounts(subnet_id, &SubnetNodeClass::Submittable, epoch);
+  let node_set: Vec<AccountId> = Network::get_classified_accounts(subnet_id, &pallet_network::SubnetNodeClass::Submittable, epoch);
 
   assert_eq!(node_set.len(), end as usize - start as usize);
-
-
-  let last_class_id = pallet_network::SubnetNodeClass::iter().last().unwrap();
-  let starting_block = System::block_number();
-
 }
 
 fn build_subnet_nodes(start: u32, end: u32, deposit_amount: u128) -> Vec<SubnetNode<AccountId>> {
@@ -212,13 +184,13 @@ fn post_success_proposal_activate_ensures(
   let active_activate_proposals = ActiveProposalsCount::<Test>::get();
   assert_eq!(active_activate_proposals, proposal_index + 1);
 
-  let proposal_path_status = PropsPathStatus::<Test>::get(path.clone());
+  let proposal_path_status = PropsPathStatus::<Test>::get(bounded_path(path.clone()));
   assert_eq!(proposal_path_status, PropsStatus::Active);
 }
 
 fn post_cast_vote_ensures(proposal_index: u32, voter: u32) {
   assert_err!(
-    SubnetVoting::unreserve(
+    SubnetVoting::unlock(
       RuntimeOrigin::signed(account(voter)),
       proposal_index, 
     ),
@@ -227,7 +199,7 @@ fn post_cast_vote_ensures(proposal_index: u32, voter: u32) {
 }
 
 fn get_subnet_initialization_cost() -> u128 {
-  let subnet_initialization_cost = <pallet_network::Pallet<Test> as SubnetVote<OriginFor<Test>, <Test as frame_system::Config>::AccountId>>::get_subnet_initialization_cost();
+  let subnet_initialization_cost = <pallet_network::Pallet<Test> as SubnetVote<OriginFor<Test>, <Test as frame_system::Config>::AccountId, <Test as crate::Config>::MaxPathLength>>::get_subnet_initialization_cost(default_add_subnet_data().memory_mb);
   subnet_initialization_cost
 }
 
@@ -235,7 +207,7 @@ fn post_yay_ensures(proposal_index: u32, prev_votes: u128, voter: u32, vote_amou
   let subnet_initialization_cost = get_subnet_initialization_cost();
 
   let reserved_balance = Balances::reserved_balance(&account(voter));
-  let voting_power = SubnetVoting::get_voting_power(account(voter), vote_amount);
+  let voting_power = vote_amount;
 
   if voter == 0 {
     assert_eq!(reserved_balance, subnet_initialization_cost);
@@ -255,7 +227,7 @@ fn post_yay_ensures(proposal_index: u32, prev_votes: u128, voter: u32, vote_amou
 fn post_nay_ensures(proposal_index: u32, prev_votes: u128, voter: u32, vote_amount: u128) {
   let subnet_initialization_cost = get_subnet_initialization_cost();
   let reserved_balance = Balances::reserved_balance(&account(voter));
-  let voting_power = SubnetVoting::get_voting_power(account(voter), vote_amount);
+  let voting_power = vote_amount;
 
   if voter == 0 {
     assert_eq!(reserved_balance, subnet_initialization_cost);
@@ -283,7 +255,7 @@ fn post_abstain_ensures(proposal_index: u32, prev_votes: u128, voter: u32, vote_
     // assert_eq!(reserved_balance, vote_amount);  
   }
 
-  let voting_power = SubnetVoting::get_voting_power(account(voter), vote_amount);
+  let voting_power = vote_amount;
   assert_eq!(VotesBalance::<Test>::get(proposal_index, account(voter)), voting_power);
 
   let votes = Votes::<Test>::get(proposal_index);
@@ -305,7 +277,7 @@ fn post_activate_execute_succeeded_ensures(proposal_index: u32, path: Vec<u8>) {
 
   post_proposal_concluded(proposal_index, path.clone());
 
-  let proposal_path_status = PropsPathStatus::<Test>::get(path.clone());
+  let proposal_path_status = PropsPathStatus::<Test>::get(bounded_path(path.clone()));
   assert_eq!(proposal_path_status, PropsStatus::Succeeded);
 
   // Check that the subnet has been added to the network pallet
@@ -313,7 +285,7 @@ fn post_activate_execute_succeeded_ensures(proposal_index: u32, path: Vec<u8>) {
   assert!(subnet_id != 0, "Subnet path has no subnet ID");
   
   let subnet_data = pallet_network::SubnetsData::<Test>::get(subnet_id);
-  let subnet_path: Vec<u8> = subnet_data.unwrap().path;
+  let subnet_path: Vec<u8> = subnet_data.unwrap().path.into();
   assert_eq!(subnet_path, path);
 }
 
@@ -329,7 +301,7 @@ fn post_deactivate_succeeded_execute_ensures(proposal_index: u32, path: Vec<u8>)
 
   post_proposal_concluded(proposal_index, path.clone());
 
-  let proposal_path_status = PropsPathStatus::<Test>::get(path.clone());
+  let proposal_path_status = PropsPathStatus::<Test>::get(bounded_path(path.clone()));
   assert_eq!(proposal_path_status, PropsStatus::Succeeded);
 
   // Ensure path is removed
@@ -346,7 +318,7 @@ fn post_activate_cancel_ensures(proposal_index: u32, path: Vec<u8>) {
   let proposal = Proposals::<Test>::get(proposal_index);
   assert_eq!(proposal.proposal_status, PropsStatus::Cancelled);
 
-  let proposal_path_status = PropsPathStatus::<Test>::get(path.clone());
+  let proposal_path_status = PropsPathStatus::<Test>::get(bounded_path(path.clone()));
   assert_eq!(proposal.proposal_status, PropsStatus::Cancelled);
 
   post_proposal_concluded(proposal_index, path.clone());
@@ -363,7 +335,7 @@ fn post_success_proposal_deactivate_ensures(path: Vec<u8>, proposal_index: u32,
   let active_activate_proposals = ActiveProposalsCount::<Test>::get();
   assert_eq!(active_activate_proposals, proposal_index + 1);
 
-  let proposal_path_status = PropsPathStatus::<Test>::get(path.clone());
+  let proposal_path_status = PropsPathStatus::<Test>::get(bounded_path(path.clone()));
   assert_eq!(proposal_path_status, PropsStatus::Active);
 }
 
@@ -398,6 +370,7 @@ fn post_proposal_concluded(proposal_index: u32, path: Vec<u8>) {
       proposal_index,
       1000,
       VoteType::Yay,
+      Conviction::None,
     ),
     Error::<Test>::ProposalNotActive
   );
@@ -411,19 +384,19 @@ fn post_proposal_concluded(proposal_index: u32, path: Vec<u8>) {
     Error::<Test>::Concluded
   );
 
-  let proposal_path_status = PropsPathStatus::<Test>::get(path.clone());
+  let proposal_path_status = PropsPathStatus::<Test>::get(bounded_path(path.clone()));
   assert_ne!(proposal_path_status, PropsStatus::Active);
   assert_ne!(proposal_path_status, PropsStatus::None);
 }
 
-fn post_proposal_conclusion_unreserves(proposal_index: u32, start: u32, end: u32, vote_amount: u128) {
+fn post_proposal_conclusion_unlocks(proposal_index: u32, start: u32, end: u32, vote_amount: u128) {
   let proposal = Proposals::<Test>::get(proposal_index);
   for n in start..end {
     let beginning_balance = Balances::free_balance(&account(n));
     let votes_balance = VotesBalance::<Test>::get(proposal_index, account(n));
 
     assert_ok!(
-      SubnetVoting::unreserve(
+      SubnetVoting::unlock(
         RuntimeOrigin::signed(account(n)),
         proposal_index, 
       )
@@ -453,6 +426,9 @@ fn build_propose_activate(path: Vec<u8>, start: u32, end: u32, deposit_amount: u
       default_add_subnet_data(), 
       subnet_nodes,
       PropsType::Activate,
+      None,
+      BoundedVec::new(),
+      None,
     )
   );
   let proposal_index = PropCount::<Test>::get();
@@ -506,6 +482,9 @@ fn build_propose_deactivate(path: Vec<u8>, start: u32, end: u32, deposit_amount:
       default_existing_add_subnet_data(), 
       Vec::new(),
       PropsType::Deactivate,
+      Some(VoteOutReason::SubnetDown),
+      default_vote_out_evidence(),
+      None,
     )
   );
   0
@@ -529,13 +508,16 @@ fn test_propose_activate() {
         default_add_subnet_data(), 
         subnet_nodes.clone(),
         PropsType::Activate,
+        None,
+        BoundedVec::new(),
+        None,
       )
     );
 
     let activate_proposals = ActivateProposalsCount::<Test>::get();
 
     assert_eq!(activate_proposals, 1);
-    post_success_proposal_activate_ensures(default_add_subnet_data().path, prop_count, 0, System::block_number(), subnet_nodes.clone().len() as u32);
+    post_success_proposal_activate_ensures(default_add_subnet_data().path.into(), prop_count, 0, System::block_number(), subnet_nodes.clone().len() as u32);
   })
 }
 
@@ -556,12 +538,15 @@ fn test_propose_activate_and_verify() {
         default_add_subnet_data(), 
         subnet_nodes.clone(),
         PropsType::Activate,
+        None,
+        BoundedVec::new(),
+        None,
       )
     );
 
     let activate_proposals = ActivateProposalsCount::<Test>::get();
     assert_eq!(activate_proposals, 1);
-    post_success_proposal_activate_ensures(default_add_subnet_data().path, prop_count, 0, System::block_number(), subnet_nodes.clone().len() as u32);
+    post_success_proposal_activate_ensures(default_add_subnet_data().path.into(), prop_count, 0, System::block_number(), subnet_nodes.clone().len() as u32);
  
     for n in 0..min_subnet_nodes {
       let proposal = Proposals::<Test>::get(0);
@@ -605,6 +590,9 @@ fn test_propose_activate_duplicate_nodes() {
         default_add_subnet_data(), 
         subnet_nodes,
         PropsType::Activate,
+        None,
+        BoundedVec::new(),
+        None,
       ),
       Error::<Test>::SubnetNodesLengthInvalid,
     );
@@ -624,7 +612,7 @@ fn test_propose_activate_subnet_path_exists_err() {
     // Create existing subnet
     build_existing_subnet(0, min_subnet_nodes);
     let subnet_data = pallet_network::SubnetsData::<Test>::get(1);
-    let subnet_path: Vec<u8> = subnet_data.unwrap().path;
+    let subnet_path: Vec<u8> = subnet_data.unwrap().path.into();
 
     let min_stake = pallet_network::MinStakeBalance::<Test>::get();
     let subnet_initialization_cost = get_subnet_initialization_cost();
@@ -637,6 +625,9 @@ fn test_propose_activate_subnet_path_exists_err() {
         default_existing_add_subnet_data(),
         Vec::new(),
         PropsType::Activate,
+        None,
+        BoundedVec::new(),
+        None,
       ),
       Error::<Test>::SubnetPathExists
     );
@@ -661,6 +652,9 @@ fn test_propose_activate_already_active_err() {
         default_add_subnet_data(), 
         subnet_nodes.clone(),
         PropsType::Activate,
+        None,
+        BoundedVec::new(),
+        None,
       )
     );
 
@@ -673,6 +667,9 @@ fn test_propose_activate_already_active_err() {
         default_add_subnet_data(),
         subnet_nodes.clone(),
         PropsType::Activate,
+        None,
+        BoundedVec::new(),
+        None,
       ),
       Error::<Test>::ProposalInvalid
     );
@@ -694,6 +691,9 @@ fn test_propose_activate_peers_min_length_err() {
         default_add_subnet_data(),
         Vec::new(),
         PropsType::Activate,
+        None,
+        BoundedVec::new(),
+        None,
       ),
       Error::<Test>::SubnetNodesLengthInvalid
     );
@@ -717,6 +717,9 @@ fn test_propose_activate_peers_balance_err() {
         default_add_subnet_data(),
         subnet_nodes,
         PropsType::Activate,
+        None,
+        BoundedVec::new(),
+        None,
       ),
       Error::<Test>::NotEnoughMinStakeBalance
     );
@@ -755,6 +758,9 @@ fn test_propose_activate_subnet_init_balance_err() {
         default_add_subnet_data(), 
         subnet_nodes,
         PropsType::Activate,
+        None,
+        BoundedVec::new(),
+        None,
       ),
       Error::<Test>::NotEnoughSubnetInitializationBalance
     );
@@ -785,6 +791,7 @@ fn test_cast_vote_activate_yay() {
         prop_count,
         DEFAUT_VOTE_AMOUNT,
         VoteType::Yay,
+        Conviction::None,
       )
     );
 
@@ -802,6 +809,7 @@ fn test_cast_vote_activate_yay_props_exists_err() {
         0,
         DEFAUT_VOTE_AMOUNT,
         VoteType::Yay,
+        Conviction::None,
       ),
       Error::<Test>::ProposalInvalid
     );
@@ -831,6 +839,7 @@ fn test_cast_vote_activate_yay_voting_not_open_err() {
         proposal_index,
         DEFAUT_VOTE_AMOUNT,
         VoteType::Yay,
+        Conviction::None,
       ),
       Error::<Test>::VotingNotOpen
     );
@@ -857,6 +866,7 @@ fn test_cast_vote_activate_yay_not_enough_balance_err() {
         proposal_index,
         DEFAUT_VOTE_AMOUNT,
         VoteType::Yay,
+        Conviction::None,
       ),
       Error::<Test>::NotEnoughBalanceToVote
     );
@@ -888,6 +898,7 @@ fn test_cast_vote_activate_nay() {
         prop_count,
         DEFAUT_VOTE_AMOUNT,
         VoteType::Nay,
+        Conviction::None,
       )
     );
 
@@ -905,6 +916,7 @@ fn test_cast_vote_activate_nay_props_exists_err() {
         0,
         DEFAUT_VOTE_AMOUNT,
         VoteType::Nay,
+        Conviction::None,
       ),
       Error::<Test>::ProposalInvalid
     );
@@ -933,6 +945,7 @@ fn test_cast_vote_activate_nay_voting_not_open_err() {
         proposal_index,
         DEFAUT_VOTE_AMOUNT,
         VoteType::Nay,
+        Conviction::None,
       ),
       Error::<Test>::VotingNotOpen
     );
@@ -959,6 +972,7 @@ fn test_cast_vote_activate_nay_not_enough_balance_err() {
         proposal_index,
         DEFAUT_VOTE_AMOUNT,
         VoteType::Nay,
+        Conviction::None,
       ),
       Error::<Test>::NotEnoughBalanceToVote
     );
@@ -990,6 +1004,7 @@ fn test_cast_vote_activate_abstain() {
         prop_count,
         DEFAUT_VOTE_AMOUNT,
         VoteType::Abstain,
+        Conviction::None,
       )
     );
 
@@ -1007,6 +1022,7 @@ fn test_cast_vote_activate_abstain_props_exists_err() {
         0,
         DEFAUT_VOTE_AMOUNT,
         VoteType::Abstain,
+        Conviction::None,
       ),
       Error::<Test>::ProposalInvalid
     );
@@ -1035,6 +1051,7 @@ fn test_cast_vote_activate_abstain_voting_not_open_err() {
         proposal_index,
         DEFAUT_VOTE_AMOUNT,
         VoteType::Abstain,
+        Conviction::None,
       ),
       Error::<Test>::VotingNotOpen
     );
@@ -1060,6 +1077,7 @@ fn test_cast_vote_activate_abstain_not_enough_balance_err() {
         proposal_index,
         DEFAUT_VOTE_AMOUNT,
         VoteType::Abstain,
+        Conviction::None,
       ),
       Error::<Test>::NotEnoughBalanceToVote
     );
@@ -1088,6 +1106,7 @@ fn test_execute_activate_succeeded() {
           proposal_index,
           DEFAUT_VOTE_AMOUNT,
           VoteType::Yay,
+          Conviction::None,
         )
       );
       post_cast_vote_ensures(proposal_index, n);
@@ -1106,229 +1125,256 @@ fn test_execute_activate_succeeded() {
 
     post_activate_execute_succeeded_ensures(proposal_index, DEFAULT_MODEL_PATH.into());
 
-    post_proposal_conclusion_unreserves(proposal_index, 0, min_subnet_nodes, DEFAUT_VOTE_AMOUNT);
+    post_proposal_conclusion_unlocks(proposal_index, 0, min_subnet_nodes, DEFAUT_VOTE_AMOUNT);
   })
 }
 
 #[test]
-fn test_execute_activate_succeeded_reexecute() {
+fn test_execute_activate_registers_subnet_and_bonded_nodes() {
   new_test_ext().execute_with(|| {
-    // Should allow max activate proposals after execute()
     let min_subnet_nodes = get_default_min_subnet_nodes();
+    // --- Voters are the subnet nodes of an existing subnet
     build_existing_subnet(0, min_subnet_nodes);
-    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
 
-    for n in 1..2 {
-      let prop_count = PropCount::<Test>::get();
+    let min_stake = pallet_network::MinStakeBalance::<Test>::get();
 
-      let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
-      let active_activate_proposals = ActiveActivateProposals::<Test>::get();
-      assert_eq!(active_activate_proposals.len(), n);
-  
-      let activate_proposals = ActivateProposalsCount::<Test>::get();
-      assert_eq!(activate_proposals, 1);
-  
-      for n in 0..min_subnet_nodes {
-        let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
-    
-        assert_ok!(
-          SubnetVoting::cast_vote(
-            RuntimeOrigin::signed(account(n)),
-            proposal_index,
-            DEFAUT_VOTE_AMOUNT,
-            VoteType::Yay,
-          )
-        );
-        post_cast_vote_ensures(proposal_index, n);
-      }
-  
-      let verifying_period = VerifyPeriod::get();
-      let voting_period = VotingPeriod::get();
-      System::set_block_number(System::block_number() + verifying_period + voting_period + 1);
-    
+    let start = min_subnet_nodes;
+    let end = min_subnet_nodes * 2;
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), start, end, DEFAULT_DEPOSIT_AMOUNT);
+
+    for n in start..end {
       assert_ok!(
-        SubnetVoting::execute(
-          RuntimeOrigin::signed(account(0)),
+        SubnetVoting::bond_proposal(
+          RuntimeOrigin::signed(account(n)),
           proposal_index,
         )
       );
-  
-      post_activate_execute_succeeded_ensures(proposal_index, DEFAULT_MODEL_PATH.into());
-  
-      post_proposal_conclusion_unreserves(proposal_index, 0, min_subnet_nodes, DEFAUT_VOTE_AMOUNT);  
+      assert_eq!(Balances::reserved_balance(&account(n)), min_stake);
     }
-  })
-}
-
-#[test]
-fn test_execute_activate_succeeded_reexecute_expired_enactment() {
-  new_test_ext().execute_with(|| {
-    // Should allow max activate proposals after execute()
-    let min_subnet_nodes = get_default_min_subnet_nodes();
-    build_existing_subnet(0, min_subnet_nodes);
-
-    for p in 0..2 {
-      let prop_count = PropCount::<Test>::get();
-
-      let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
-      let active_activate_proposals = ActiveActivateProposals::<Test>::get();
-      assert_eq!(active_activate_proposals.len(), p+1);
-      
-      System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
-  
-      for n in 0..min_subnet_nodes {
-        let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
-    
-        assert_ok!(
-          SubnetVoting::cast_vote(
-            RuntimeOrigin::signed(account(n)),
-            proposal_index,
-            DEFAUT_VOTE_AMOUNT,
-            VoteType::Yay,
-          )
-        );
-        post_cast_vote_ensures(proposal_index, n);
-      }
-  
-      let voting_period = VotingPeriod::get();
-  
-      let enactment_period = EnactmentPeriod::get();
 
-      System::set_block_number(System::block_number() + voting_period + enactment_period + 1);
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
 
+    for n in 0..min_subnet_nodes {
+      let _ = Balances::deposit_creating(&account(n), min_stake);
       assert_ok!(
-        SubnetVoting::execute(
-          RuntimeOrigin::signed(account(0)),
+        SubnetVoting::cast_vote(
+          RuntimeOrigin::signed(account(n)),
           proposal_index,
+          min_stake,
+          VoteType::Yay,
+          Conviction::None,
         )
       );
+    }
 
-      let activate_proposals = ActivateProposalsCount::<Test>::get();
-      assert_eq!(activate_proposals, 0);
-  
-      let proposal = Proposals::<Test>::get(proposal_index);
-      assert_eq!(proposal.proposal_type, PropsType::Activate);
+    System::set_block_number(System::block_number() + VotingPeriod::get() + 1);
 
-      let path: Vec<u8> = DEFAULT_MODEL_PATH.into();
+    let proposer_balance = Balances::free_balance(&account(0));
 
-      let proposal_path_status = PropsPathStatus::<Test>::get(path.clone());
-      assert_eq!(proposal_path_status, PropsStatus::Expired);
-    
-      assert_eq!(proposal.proposal_status, PropsStatus::Expired);
-  
-      // let is_active = pallet_network::SubnetActivated::<Test>::get(path);
-      // assert_eq!(is_active.active, false);
+    assert_ok!(
+      SubnetVoting::execute(
+        RuntimeOrigin::signed(account(0)),
+        proposal_index,
+      )
+    );
+
+    post_activate_execute_succeeded_ensures(proposal_index, DEFAULT_MODEL_PATH.into());
+
+    // --- Proposer paid the initialization fee from the reserved balance
+    assert_eq!(Balances::reserved_balance(&account(0)), 0);
+    assert_eq!(Balances::free_balance(&account(0)), proposer_balance);
+
+    // --- Subnet is live in the network pallet with its bootstrap nodes
+    let subnet_id = pallet_network::SubnetPaths::<Test>::get(DEFAULT_MODEL_PATH.as_bytes().to_vec()).unwrap();
+    let subnet = pallet_network::SubnetsData::<Test>::get(subnet_id).unwrap();
+    assert_eq!(subnet.initialized, System::block_number());
+    assert_eq!(pallet_network::TotalSubnetNodes::<Test>::get(subnet_id), end - start);
+
+    let epoch = System::block_number() / EpochLength::get();
+
+    for n in start..end {
+      let subnet_node = pallet_network::SubnetNodesData::<Test>::get(subnet_id, account(n));
+      assert_eq!(subnet_node.peer_id, peer(n));
+      assert!(subnet_node.has_classification(&pallet_network::SubnetNodeClass::Submittable, epoch));
+
+      // --- Bond is now the subnet nodes stake
+      assert_eq!(Balances::reserved_balance(&account(n)), 0);
+      assert_eq!(pallet_network::AccountSubnetStake::<Test>::get(account(n), subnet_id), min_stake);
     }
   })
 }
 
 #[test]
-fn test_execute_voting_period_err() {
+fn test_execute_activate_charges_quoted_cost_after_cost_rises() {
   new_test_ext().execute_with(|| {
-    let prop_count = PropCount::<Test>::get();
     let min_subnet_nodes = get_default_min_subnet_nodes();
+    // --- Voters are the subnet nodes of an existing subnet
     build_existing_subnet(0, min_subnet_nodes);
 
-    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+    let min_stake = pallet_network::MinStakeBalance::<Test>::get();
+
+    let start = min_subnet_nodes;
+    let end = min_subnet_nodes * 2;
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), start, end, DEFAULT_DEPOSIT_AMOUNT);
+
+    // --- Cost quoted and reserved on propose
+    let quoted_cost = Proposals::<Test>::get(proposal_index).proposer_stake;
+    assert_eq!(Balances::reserved_balance(&account(0)), quoted_cost);
+
+    for n in start..end {
+      assert_ok!(
+        SubnetVoting::bond_proposal(
+          RuntimeOrigin::signed(account(n)),
+          proposal_index,
+        )
+      );
+    }
+
     System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
 
     for n in 0..min_subnet_nodes {
-      let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
-  
+      let _ = Balances::deposit_creating(&account(n), min_stake);
       assert_ok!(
         SubnetVoting::cast_vote(
           RuntimeOrigin::signed(account(n)),
           proposal_index,
-          DEFAUT_VOTE_AMOUNT,
+          min_stake,
           VoteType::Yay,
+          Conviction::None,
         )
       );
-      post_cast_vote_ensures(proposal_index, n);
     }
 
-    assert_err!(
+    System::set_block_number(System::block_number() + VotingPeriod::get() + 1);
+
+    // --- Another registration raised the cost since the proposal
+    pallet_network::LastSubnetRegistrationCost::<Test>::set(quoted_cost * 10);
+    pallet_network::LastSubnetRegistrationBlock::<Test>::set(System::block_number());
+    assert!(get_subnet_initialization_cost() > quoted_cost);
+
+    let proposer_balance = Balances::free_balance(&account(0));
+
+    assert_ok!(
       SubnetVoting::execute(
         RuntimeOrigin::signed(account(0)),
         proposal_index,
-      ),
-      Error::<Test>::VotingOpen,
+      )
     );
+
+    post_activate_execute_succeeded_ensures(proposal_index, DEFAULT_MODEL_PATH.into());
+
+    // --- Proposer paid the quoted cost from the reserved balance
+    assert_eq!(Balances::reserved_balance(&account(0)), 0);
+    assert_eq!(Balances::free_balance(&account(0)), proposer_balance);
+
+    let subnet_id = pallet_network::SubnetPaths::<Test>::get(DEFAULT_MODEL_PATH.as_bytes().to_vec()).unwrap();
+    assert_eq!(pallet_network::TotalSubnetNodes::<Test>::get(subnet_id), end - start);
   })
 }
 
 #[test]
-fn test_execute_enactment_period_err() {
+fn test_cast_vote_locks_balance() {
   new_test_ext().execute_with(|| {
-    let prop_count = PropCount::<Test>::get();
     let min_subnet_nodes = get_default_min_subnet_nodes();
+    // --- Voters are the subnet nodes of an existing subnet
     build_existing_subnet(0, min_subnet_nodes);
 
-    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), min_subnet_nodes, min_subnet_nodes * 2, DEFAULT_DEPOSIT_AMOUNT);
+
     System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
 
-    for n in 0..min_subnet_nodes {
-      let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
-  
-      assert_ok!(
-        SubnetVoting::cast_vote(
-          RuntimeOrigin::signed(account(n)),
-          proposal_index,
-          DEFAUT_VOTE_AMOUNT,
-          VoteType::Yay,
-        )
-      );
-      post_cast_vote_ensures(proposal_index, n);
-    }
+    let voter = account(1);
+    let _ = Balances::deposit_creating(&voter, DEFAUT_VOTE_AMOUNT);
 
-    let voting_period = VotingPeriod::get();
-    let enactment_period = EnactmentPeriod::get();
+    assert_ok!(
+      SubnetVoting::cast_vote(
+        RuntimeOrigin::signed(voter.clone()),
+        proposal_index,
+        DEFAUT_VOTE_AMOUNT,
+        VoteType::Nay,
+        Conviction::None,
+      )
+    );
 
-    System::set_block_number(System::block_number() + voting_period + enactment_period + 1);
+    let vote_lock = VoteLocks::<Test>::get(voter.clone(), proposal_index).unwrap();
+    assert_eq!(vote_lock.amount, DEFAUT_VOTE_AMOUNT);
+    assert_eq!(vote_lock.conviction, Conviction::None);
+    assert_eq!(Balances::locks(&voter)[0].amount, DEFAUT_VOTE_AMOUNT);
+
+    // --- Locked balance cannot be transferred to vote again
+    assert!(
+      Balances::transfer_allow_death(
+        RuntimeOrigin::signed(voter.clone()),
+        account(254),
+        DEFAUT_VOTE_AMOUNT,
+      ).is_err()
+    );
+
+    // --- Locked balance cannot vote on the same proposal twice
+    assert_err!(
+      SubnetVoting::cast_vote(
+        RuntimeOrigin::signed(voter.clone()),
+        proposal_index,
+        DEFAUT_VOTE_AMOUNT,
+        VoteType::Nay,
+        Conviction::None,
+      ),
+      Error::<Test>::NotEnoughBalanceToVote
+    );
+
+    System::set_block_number(System::block_number() + VotingPeriod::get() + 1);
 
     assert_ok!(
       SubnetVoting::execute(
         RuntimeOrigin::signed(account(0)),
         proposal_index,
       )
-      // Error::<Test>::EnactmentPeriodPassed,
     );
+
+    // --- Concluding doesn't release the voters, each voter unlocks their own vote
+    assert_ne!(VoteLocks::<Test>::get(voter.clone(), proposal_index), None);
+    assert_eq!(Balances::locks(&voter)[0].amount, DEFAUT_VOTE_AMOUNT);
+
+    // --- Votes without conviction can be unlocked on conclusion
+    assert_ok!(
+      SubnetVoting::unlock(
+        RuntimeOrigin::signed(voter.clone()),
+        proposal_index,
+      )
+    );
+
+    assert_eq!(VoteLocks::<Test>::get(voter.clone(), proposal_index), None);
+    assert_eq!(Balances::locks(&voter).len(), 0);
   })
 }
 
 #[test]
-fn test_execute_quorum_not_reached_err() {
+fn test_cast_vote_conviction_locked_after_conclusion() {
   new_test_ext().execute_with(|| {
-    // let quorum = Quorum::<Test>::get();
-    let quorum = Quorum::get();
-    let prop_count = PropCount::<Test>::get();
     let min_subnet_nodes = get_default_min_subnet_nodes();
+    // --- Voters are the subnet nodes of an existing subnet
     build_existing_subnet(0, min_subnet_nodes);
 
-    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), min_subnet_nodes, min_subnet_nodes * 2, DEFAULT_DEPOSIT_AMOUNT);
+
     System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
 
-    
-    for n in 0..min_subnet_nodes {
-      let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
-  
-      assert_ok!(
-        SubnetVoting::cast_vote(
-          RuntimeOrigin::signed(account(n)),
-          proposal_index,
-          1, // too low to reach minimum quorum
-          VoteType::Yay,
-        )
-      );
-      post_cast_vote_ensures(proposal_index, n);
-    }
+    let voter = account(1);
+    let _ = Balances::deposit_creating(&voter, DEFAUT_VOTE_AMOUNT);
 
-    let proposal = Proposals::<Test>::get(proposal_index);
+    assert_ok!(
+      SubnetVoting::cast_vote(
+        RuntimeOrigin::signed(voter.clone()),
+        proposal_index,
+        DEFAUT_VOTE_AMOUNT,
+        VoteType::Nay,
+        Conviction::Locked2x,
+      )
+    );
 
-    let voting_period = VotingPeriod::get();
-    let enactment_period = EnactmentPeriod::get();
+    assert_eq!(Votes::<Test>::get(proposal_index).nay, DEFAUT_VOTE_AMOUNT * 2);
 
-    System::set_block_number(proposal.end_vote_block + 1);
-    // System::set_block_number(proposal.end_vote_block + enactment_period + 1);
+    System::set_block_number(System::block_number() + VotingPeriod::get() + 1);
 
     assert_ok!(
       SubnetVoting::execute(
@@ -1337,33 +1383,497 @@ fn test_execute_quorum_not_reached_err() {
       )
     );
 
-    let proposal = Proposals::<Test>::get(proposal_index);
-
-    let path: Vec<u8> = DEFAULT_MODEL_PATH.into();
+    // --- Locked for one enactment period after conclusion
+    let outcome = ProposalOutcomes::<Test>::get(proposal_index).unwrap();
+    assert_eq!(outcome.concluded_block, System::block_number());
+    let unlock_block = outcome.concluded_block + EnactmentPeriod::get();
+    let vote_lock = VoteLocks::<Test>::get(voter.clone(), proposal_index).unwrap();
+    assert_eq!(vote_lock.conviction, Conviction::Locked2x);
+    assert_eq!(Balances::locks(&voter)[0].amount, DEFAUT_VOTE_AMOUNT);
 
-    let proposal_path_status = PropsPathStatus::<Test>::get(path.clone());
-    assert_eq!(proposal_path_status, PropsStatus::Expired);
-  
-    assert_eq!(proposal.proposal_status, PropsStatus::Expired);
+    assert_err!(
+      SubnetVoting::unlock(
+        RuntimeOrigin::signed(voter.clone()),
+        proposal_index,
+      ),
+      Error::<Test>::VoteLocked
+    );
 
-    // let is_active = pallet_network::SubnetActivated::<Test>::get(path);
-    // assert_eq!(is_active.active, false);
+    System::set_block_number(unlock_block);
 
+    assert_ok!(
+      SubnetVoting::unlock(
+        RuntimeOrigin::signed(voter.clone()),
+        proposal_index,
+      )
+    );
 
-    post_proposal_conclusion_unreserves(proposal_index, 0, min_subnet_nodes, DEFAUT_VOTE_AMOUNT);
+    assert_eq!(VoteLocks::<Test>::get(voter.clone(), proposal_index), None);
+    assert_eq!(VotesBalance::<Test>::get(proposal_index, voter.clone()), 0);
+    assert_eq!(Balances::locks(&voter).len(), 0);
   })
 }
 
 #[test]
-fn test_execute_defeated() {
+fn test_cast_vote_conviction_without_free_balance_err() {
   new_test_ext().execute_with(|| {
-    let prop_count = PropCount::<Test>::get();
-    // let min_subnet_nodes = get_default_min_subnet_nodes();
-    let min_subnet_nodes = 12;
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    // --- Voters are the subnet nodes of an existing subnet
     build_existing_subnet(0, min_subnet_nodes);
 
-    // if min_subnet_nodes < 12 {
-    //   min_subnet_nodes = 12
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), min_subnet_nodes, min_subnet_nodes * 2, DEFAULT_DEPOSIT_AMOUNT);
+
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+
+    // --- Staked voter with no free balance to lock
+    let voter = account(1);
+    let _ = Balances::make_free_balance_be(&voter, 0);
+    assert_eq!(Balances::free_balance(&voter), 0);
+
+    assert_err!(
+      SubnetVoting::cast_vote(
+        RuntimeOrigin::signed(voter.clone()),
+        proposal_index,
+        DEFAUT_VOTE_AMOUNT,
+        VoteType::Yay,
+        Conviction::Locked4x,
+      ),
+      Error::<Test>::NotEnoughBalanceToVote
+    );
+    assert_eq!(Votes::<Test>::get(proposal_index).yay, 0);
+
+    // --- The vote is capped by the free balance it locks
+    let free_balance = DEFAUT_VOTE_AMOUNT / 2;
+    let _ = Balances::deposit_creating(&voter, free_balance);
+
+    assert_err!(
+      SubnetVoting::cast_vote(
+        RuntimeOrigin::signed(voter.clone()),
+        proposal_index,
+        DEFAUT_VOTE_AMOUNT,
+        VoteType::Yay,
+        Conviction::Locked4x,
+      ),
+      Error::<Test>::NotEnoughBalanceToVote
+    );
+
+    assert_ok!(
+      SubnetVoting::cast_vote(
+        RuntimeOrigin::signed(voter.clone()),
+        proposal_index,
+        free_balance,
+        VoteType::Yay,
+        Conviction::Locked4x,
+      )
+    );
+
+    assert_eq!(Votes::<Test>::get(proposal_index).yay, free_balance * 4);
+    assert_eq!(Balances::locks(&voter)[0].amount, free_balance);
+  })
+}
+
+#[test]
+fn test_cast_vote_voting_power_snapshot() {
+  new_test_ext().execute_with(|| {
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    build_existing_subnet(0, min_subnet_nodes);
+
+    let min_stake = pallet_network::MinStakeBalance::<Test>::get();
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), min_subnet_nodes, min_subnet_nodes * 2, DEFAULT_DEPOSIT_AMOUNT);
+
+    assert_ok!(SubnetVoting::set_peer_vote_premium(10));
+
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+
+    // --- Submittable subnet node receives the premium on its stake
+    let voter = account(1);
+    let _ = Balances::deposit_creating(&voter, min_stake * 2);
+
+    assert_ok!(
+      SubnetVoting::cast_vote(
+        RuntimeOrigin::signed(voter.clone()),
+        proposal_index,
+        min_stake,
+        VoteType::Yay,
+        Conviction::None,
+      )
+    );
+
+    let voting_power = min_stake + min_stake / 10;
+    assert_eq!(VotingPowerSnapshots::<Test>::get(proposal_index, voter.clone()), Some(voting_power));
+    assert_eq!(TotalVotingPowerSnapshot::<Test>::get(proposal_index), Some(min_stake * min_subnet_nodes as u128));
+
+    // --- Cannot vote past the voting power snapshot regardless of free balance
+    assert_err!(
+      SubnetVoting::cast_vote(
+        RuntimeOrigin::signed(voter.clone()),
+        proposal_index,
+        min_stake,
+        VoteType::Yay,
+        Conviction::None,
+      ),
+      Error::<Test>::NotEnoughBalanceToVote
+    );
+
+    // --- Accounts without stake have no voting power
+    let _ = Balances::deposit_creating(&account(255), min_stake);
+
+    assert_err!(
+      SubnetVoting::cast_vote(
+        RuntimeOrigin::signed(account(255)),
+        proposal_index,
+        min_stake,
+        VoteType::Yay,
+        Conviction::None,
+      ),
+      Error::<Test>::NotEnoughBalanceToVote
+    );
+  })
+}
+
+#[test]
+fn test_cast_vote_voting_power_as_of_start_vote_block() {
+  new_test_ext().execute_with(|| {
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    build_existing_subnet(0, min_subnet_nodes);
+
+    let subnet_id = pallet_network::SubnetPaths::<Test>::get(Vec::from(DEFAULT_EXISTING_MODEL_PATH)).unwrap();
+    let min_stake = pallet_network::MinStakeBalance::<Test>::get();
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), min_subnet_nodes, min_subnet_nodes * 2, DEFAULT_DEPOSIT_AMOUNT);
+    let start_vote_block = Proposals::<Test>::get(proposal_index).start_vote_block;
+
+    assert!(pallet_network::VotingPowerSnapshotBlocks::<Test>::get().contains_key(&start_vote_block));
+
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+
+    // --- Stake added once voting opens doesn't count towards voting power
+    let voter = account(1);
+    let _ = Balances::deposit_creating(&voter, min_stake * 2);
+    assert_ok!(
+      Network::add_to_delegate_stake(
+        RuntimeOrigin::signed(voter.clone()),
+        subnet_id,
+        min_stake,
+      )
+    );
+
+    assert_ok!(
+      SubnetVoting::cast_vote(
+        RuntimeOrigin::signed(voter.clone()),
+        proposal_index,
+        min_stake,
+        VoteType::Abstain,
+        Conviction::Locked2x,
+      )
+    );
+
+    assert_eq!(VotingPowerSnapshots::<Test>::get(proposal_index, voter.clone()), Some(min_stake));
+    assert_eq!(Votes::<Test>::get(proposal_index).abstain, min_stake * 2);
+    // --- Conviction doesn't count towards the quorum
+    assert_eq!(QuorumVotes::<Test>::get(proposal_index), min_stake);
+
+    // --- Votes are locked in the free balance, so staked subnet nodes can only vote up to it
+    let voter = account(2);
+    let free_balance = Balances::free_balance(&voter);
+    assert!(free_balance < min_stake);
+
+    assert_err!(
+      SubnetVoting::cast_vote(
+        RuntimeOrigin::signed(voter.clone()),
+        proposal_index,
+        min_stake,
+        VoteType::Nay,
+        Conviction::None,
+      ),
+      Error::<Test>::NotEnoughBalanceToVote
+    );
+
+    assert_ok!(
+      SubnetVoting::cast_vote(
+        RuntimeOrigin::signed(voter.clone()),
+        proposal_index,
+        free_balance,
+        VoteType::Nay,
+        Conviction::None,
+      )
+    );
+    assert_eq!(Votes::<Test>::get(proposal_index).nay, free_balance);
+    assert_eq!(QuorumVotes::<Test>::get(proposal_index), min_stake);
+
+    // --- Stake changes are no longer tracked once the proposal concludes
+    System::set_block_number(System::block_number() + VotingPeriod::get() + 1);
+
+    assert_ok!(
+      SubnetVoting::execute(
+        RuntimeOrigin::signed(account(0)),
+        proposal_index,
+      )
+    );
+
+    assert!(!pallet_network::VotingPowerSnapshotBlocks::<Test>::get().contains_key(&start_vote_block));
+    assert!(pallet_network::VotingPowerSnapshotTeardowns::<Test>::get().contains(&start_vote_block));
+  })
+}
+
+#[test]
+fn test_execute_activate_succeeded_reexecute() {
+  new_test_ext().execute_with(|| {
+    // Should allow max activate proposals after execute()
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    build_existing_subnet(0, min_subnet_nodes);
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+
+    for n in 1..2 {
+      let prop_count = PropCount::<Test>::get();
+
+      let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+      let active_activate_proposals = ActiveActivateProposals::<Test>::get();
+      assert_eq!(active_activate_proposals.len(), n);
+  
+      let activate_proposals = ActivateProposalsCount::<Test>::get();
+      assert_eq!(activate_proposals, 1);
+  
+      for n in 0..min_subnet_nodes {
+        let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
+    
+        assert_ok!(
+          SubnetVoting::cast_vote(
+            RuntimeOrigin::signed(account(n)),
+            proposal_index,
+            DEFAUT_VOTE_AMOUNT,
+            VoteType::Yay,
+            Conviction::None,
+          )
+        );
+        post_cast_vote_ensures(proposal_index, n);
+      }
+  
+      let verifying_period = VerifyPeriod::get();
+      let voting_period = VotingPeriod::get();
+      System::set_block_number(System::block_number() + verifying_period + voting_period + 1);
+    
+      assert_ok!(
+        SubnetVoting::execute(
+          RuntimeOrigin::signed(account(0)),
+          proposal_index,
+        )
+      );
+  
+      post_activate_execute_succeeded_ensures(proposal_index, DEFAULT_MODEL_PATH.into());
+  
+      post_proposal_conclusion_unlocks(proposal_index, 0, min_subnet_nodes, DEFAUT_VOTE_AMOUNT);  
+    }
+  })
+}
+
+#[test]
+fn test_execute_activate_succeeded_reexecute_expired_enactment() {
+  new_test_ext().execute_with(|| {
+    // Should allow max activate proposals after execute()
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    build_existing_subnet(0, min_subnet_nodes);
+
+    for p in 0..2 {
+      let prop_count = PropCount::<Test>::get();
+
+      let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+      let active_activate_proposals = ActiveActivateProposals::<Test>::get();
+      assert_eq!(active_activate_proposals.len(), p+1);
+      
+      System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+  
+      for n in 0..min_subnet_nodes {
+        let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
+    
+        assert_ok!(
+          SubnetVoting::cast_vote(
+            RuntimeOrigin::signed(account(n)),
+            proposal_index,
+            DEFAUT_VOTE_AMOUNT,
+            VoteType::Yay,
+            Conviction::None,
+          )
+        );
+        post_cast_vote_ensures(proposal_index, n);
+      }
+  
+      let voting_period = VotingPeriod::get();
+  
+      let enactment_period = EnactmentPeriod::get();
+
+      System::set_block_number(System::block_number() + voting_period + enactment_period + 1);
+
+      assert_ok!(
+        SubnetVoting::execute(
+          RuntimeOrigin::signed(account(0)),
+          proposal_index,
+        )
+      );
+
+      let activate_proposals = ActivateProposalsCount::<Test>::get();
+      assert_eq!(activate_proposals, 0);
+  
+      let proposal = Proposals::<Test>::get(proposal_index);
+      assert_eq!(proposal.proposal_type, PropsType::Activate);
+
+      let path: Vec<u8> = DEFAULT_MODEL_PATH.into();
+
+      let proposal_path_status = PropsPathStatus::<Test>::get(bounded_path(path.clone()));
+      assert_eq!(proposal_path_status, PropsStatus::Expired);
+    
+      assert_eq!(proposal.proposal_status, PropsStatus::Expired);
+  
+      // let is_active = pallet_network::SubnetActivated::<Test>::get(path);
+      // assert_eq!(is_active.active, false);
+    }
+  })
+}
+
+#[test]
+fn test_execute_voting_period_err() {
+  new_test_ext().execute_with(|| {
+    let prop_count = PropCount::<Test>::get();
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    build_existing_subnet(0, min_subnet_nodes);
+
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+
+    for n in 0..min_subnet_nodes {
+      let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
+  
+      assert_ok!(
+        SubnetVoting::cast_vote(
+          RuntimeOrigin::signed(account(n)),
+          proposal_index,
+          DEFAUT_VOTE_AMOUNT,
+          VoteType::Yay,
+          Conviction::None,
+        )
+      );
+      post_cast_vote_ensures(proposal_index, n);
+    }
+
+    assert_err!(
+      SubnetVoting::execute(
+        RuntimeOrigin::signed(account(0)),
+        proposal_index,
+      ),
+      Error::<Test>::VotingOpen,
+    );
+  })
+}
+
+#[test]
+fn test_execute_enactment_period_err() {
+  new_test_ext().execute_with(|| {
+    let prop_count = PropCount::<Test>::get();
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    build_existing_subnet(0, min_subnet_nodes);
+
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+
+    for n in 0..min_subnet_nodes {
+      let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
+  
+      assert_ok!(
+        SubnetVoting::cast_vote(
+          RuntimeOrigin::signed(account(n)),
+          proposal_index,
+          DEFAUT_VOTE_AMOUNT,
+          VoteType::Yay,
+          Conviction::None,
+        )
+      );
+      post_cast_vote_ensures(proposal_index, n);
+    }
+
+    let voting_period = VotingPeriod::get();
+    let enactment_period = EnactmentPeriod::get();
+
+    System::set_block_number(System::block_number() + voting_period + enactment_period + 1);
+
+    assert_ok!(
+      SubnetVoting::execute(
+        RuntimeOrigin::signed(account(0)),
+        proposal_index,
+      )
+      // Error::<Test>::EnactmentPeriodPassed,
+    );
+  })
+}
+
+#[test]
+fn test_execute_quorum_not_reached_err() {
+  new_test_ext().execute_with(|| {
+    // let quorum = Quorum::<Test>::get();
+    let quorum = Quorum::get();
+    let prop_count = PropCount::<Test>::get();
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    build_existing_subnet(0, min_subnet_nodes);
+
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+
+    
+    for n in 0..min_subnet_nodes {
+      let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
+  
+      assert_ok!(
+        SubnetVoting::cast_vote(
+          RuntimeOrigin::signed(account(n)),
+          proposal_index,
+          1, // too low to reach minimum quorum
+          VoteType::Yay,
+          Conviction::None,
+        )
+      );
+      post_cast_vote_ensures(proposal_index, n);
+    }
+
+    let proposal = Proposals::<Test>::get(proposal_index);
+
+    let voting_period = VotingPeriod::get();
+    let enactment_period = EnactmentPeriod::get();
+
+    System::set_block_number(proposal.end_vote_block + 1);
+    // System::set_block_number(proposal.end_vote_block + enactment_period + 1);
+
+    assert_ok!(
+      SubnetVoting::execute(
+        RuntimeOrigin::signed(account(0)),
+        proposal_index,
+      )
+    );
+
+    let proposal = Proposals::<Test>::get(proposal_index);
+
+    let path: Vec<u8> = DEFAULT_MODEL_PATH.into();
+
+    let proposal_path_status = PropsPathStatus::<Test>::get(bounded_path(path.clone()));
+    assert_eq!(proposal_path_status, PropsStatus::Expired);
+  
+    assert_eq!(proposal.proposal_status, PropsStatus::Expired);
+
+    // let is_active = pallet_network::SubnetActivated::<Test>::get(path);
+    // assert_eq!(is_active.active, false);
+
+
+    post_proposal_conclusion_unlocks(proposal_index, 0, min_subnet_nodes, DEFAUT_VOTE_AMOUNT);
+  })
+}
+
+#[test]
+fn test_execute_defeated() {
+  new_test_ext().execute_with(|| {
+    let prop_count = PropCount::<Test>::get();
+    // let min_subnet_nodes = get_default_min_subnet_nodes();
+    let min_subnet_nodes = 12;
+    build_existing_subnet(0, min_subnet_nodes);
+
+    // if min_subnet_nodes < 12 {
+    //   min_subnet_nodes = 12
     // }
 
     // Get more nay voters than yay voters
@@ -1388,6 +1898,7 @@ fn test_execute_defeated() {
           proposal_index,
           vote_amount,
           VoteType::Yay,
+          Conviction::None,
         )
       );
       post_cast_vote_ensures(proposal_index, n);
@@ -1401,147 +1912,494 @@ fn test_execute_defeated() {
           proposal_index,
           vote_amount,
           VoteType::Nay,
+          Conviction::None,
+        )
+      );
+      post_cast_vote_ensures(proposal_index, n);
+    }
+
+    log::error!("total_vote_amount {:?}", total_vote_amount);
+    log::error!("quorum            {:?}", quorum);
+    assert!(total_vote_amount >= quorum, "Total votes must be greater than quorum");
+
+    let verifying_period = VerifyPeriod::get();
+    let voting_period = VotingPeriod::get();
+    System::set_block_number(System::block_number() + verifying_period + voting_period + 1);
+
+    assert_ok!(
+      SubnetVoting::execute(
+        RuntimeOrigin::signed(account(0)),
+        proposal_index,
+      )
+    );
+
+    let proposal = Proposals::<Test>::get(proposal_index);
+    assert_eq!(proposal.proposal_status, PropsStatus::Defeated);
+
+    post_proposal_conclusion_unlocks(proposal_index, 0, min_subnet_nodes, vote_amount);
+  })
+}
+
+#[test]
+fn test_execute_cancelled() {
+  new_test_ext().execute_with(|| {
+    let prop_count = PropCount::<Test>::get();
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    build_existing_subnet(0, min_subnet_nodes);
+
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+    System::set_block_number(System::block_number() + 1);
+    let proposal = Proposals::<Test>::get(proposal_index);
+    let proposer_stake = proposal.proposer_stake;
+    // let proposer_beginning_balance = Balances::free_balance(&account(0));
+    // let proposer_assumed_balance = proposer_beginning_balance - DEFAUT_VOTE_AMOUNT;
+
+    let subnet_initialization_cost = get_subnet_initialization_cost();
+
+    assert_ne!(subnet_initialization_cost, 0);
+
+    for n in 0..min_subnet_nodes {
+      let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
+  
+      assert_ok!(
+        SubnetVoting::cast_vote(
+          RuntimeOrigin::signed(account(n)),
+          proposal_index,
+          DEFAUT_VOTE_AMOUNT,
+          VoteType::Yay,
+          Conviction::None,
+        )
+      );
+      post_cast_vote_ensures(proposal_index, n);
+    }
+
+    let proposer_pre_cancel_balance = Balances::free_balance(&account(0));
+
+    assert_ok!(
+      SubnetVoting::cancel_proposal(
+        RuntimeOrigin::signed(account(0)),
+        proposal_index,
+      )
+    );
+
+    let proposer_stake_unreserve = proposer_stake - Percent::from_percent(CancelSlashPercent::get()) * proposer_stake;
+
+    let proposer_after_balance = Balances::free_balance(&account(0));
+    // assert_eq!(proposer_after_balance, proposer_beginning_balance + subnet_initialization_cost + DEFAUT_VOTE_AMOUNT);
+    assert_eq!(proposer_after_balance, proposer_pre_cancel_balance + proposer_stake_unreserve);
+
+    post_activate_cancel_ensures(prop_count, DEFAULT_MODEL_PATH.into());
+    // post_proposal_conclusion_unlocks(proposal_index, 0, min_subnet_nodes, DEFAUT_VOTE_AMOUNT);
+  })
+}
+
+#[test]
+fn test_execute_cancelled_not_proposer_err() {
+  new_test_ext().execute_with(|| {
+    let prop_count = PropCount::<Test>::get();
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    build_existing_subnet(0, min_subnet_nodes);
+
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+    System::set_block_number(System::block_number() + 1);
+
+    for n in 0..min_subnet_nodes {
+      let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
+  
+      assert_ok!(
+        SubnetVoting::cast_vote(
+          RuntimeOrigin::signed(account(n)),
+          proposal_index,
+          DEFAUT_VOTE_AMOUNT,
+          VoteType::Yay,
+          Conviction::None,
+        )
+      );
+      post_cast_vote_ensures(proposal_index, n);
+    }
+
+    assert_err!(
+      SubnetVoting::cancel_proposal(
+        RuntimeOrigin::signed(account(1)),
+        proposal_index,
+      ),
+      Error::<Test>::NotProposer
+    );
+  })
+}
+
+#[test]
+fn test_execute_cancelled_verified_err() {
+  new_test_ext().execute_with(|| {
+    let prop_count = PropCount::<Test>::get();
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    build_existing_subnet(0, min_subnet_nodes);
+
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+
+    assert_err!(
+      SubnetVoting::cancel_proposal(
+        RuntimeOrigin::signed(account(1)),
+        proposal_index,
+      ),
+      Error::<Test>::ProposalVerified
+    );
+  })
+}
+
+#[test]
+fn test_execute_cancelled_proposal_index_err() {
+  new_test_ext().execute_with(|| {
+    let prop_count = PropCount::<Test>::get();
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    build_existing_subnet(0, min_subnet_nodes);
+
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+
+    for n in 0..min_subnet_nodes {
+      let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
+  
+      assert_ok!(
+        SubnetVoting::cast_vote(
+          RuntimeOrigin::signed(account(n)),
+          proposal_index,
+          DEFAUT_VOTE_AMOUNT,
+          VoteType::Yay,
+          Conviction::None,
+        )
+      );
+      post_cast_vote_ensures(proposal_index, n);
+    }
+
+    assert_err!(
+      SubnetVoting::cancel_proposal(
+        RuntimeOrigin::signed(account(1)),
+        proposal_index + 1,
+      ),
+      Error::<Test>::ProposalInvalid
+    );
+  })
+}
+
+#[test]
+fn test_execute_cancelled_vote_completed_err() {
+  new_test_ext().execute_with(|| {
+    let prop_count = PropCount::<Test>::get();
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    build_existing_subnet(0, min_subnet_nodes);
+
+    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+
+    for n in 0..min_subnet_nodes {
+      let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
+  
+      assert_ok!(
+        SubnetVoting::cast_vote(
+          RuntimeOrigin::signed(account(n)),
+          proposal_index,
+          DEFAUT_VOTE_AMOUNT,
+          VoteType::Yay,
+          Conviction::None,
         )
       );
       post_cast_vote_ensures(proposal_index, n);
     }
 
-    log::error!("total_vote_amount {:?}", total_vote_amount);
-    log::error!("quorum            {:?}", quorum);
-    assert!(total_vote_amount >= quorum, "Total votes must be greater than quorum");
+    let verifying_period = VerifyPeriod::get();
+    let voting_period = VotingPeriod::get();
+    System::set_block_number(System::block_number() + verifying_period + voting_period + 1);
+
+    assert_err!(
+      SubnetVoting::cancel_proposal(
+        RuntimeOrigin::signed(account(0)),
+        proposal_index,
+      ),
+      Error::<Test>::VoteComplete
+    );
+  })
+}
+
+#[test]
+fn test_propose_activate_not_verified_err() {
+  new_test_ext().execute_with(|| {
+    let prop_count = PropCount::<Test>::get();
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    let min_stake = pallet_network::MinStakeBalance::<Test>::get();
+    let subnet_initialization_cost = get_subnet_initialization_cost();
+
+    let _ = Balances::deposit_creating(&account(0), subnet_initialization_cost);
+
+    let subnet_nodes = build_subnet_nodes(0, min_subnet_nodes, min_stake);
+
+    assert_ok!(
+      SubnetVoting::propose(
+        RuntimeOrigin::signed(account(0)),
+        default_add_subnet_data(), 
+        subnet_nodes.clone(),
+        PropsType::Activate,
+        None,
+        BoundedVec::new(),
+        None,
+      )
+    );
+
+    let activate_proposals = ActivateProposalsCount::<Test>::get();
+    assert_eq!(activate_proposals, 1);
+    post_success_proposal_activate_ensures(default_add_subnet_data().path.into(), prop_count, 0, System::block_number(), subnet_nodes.clone().len() as u32);
+    
+    let proposal_index = PropCount::<Test>::get();
+
+    // assert_err!(
+    //   SubnetVoting::cast_vote(
+    //     RuntimeOrigin::signed(account(0)),
+    //     proposal_index - 1,
+    //     DEFAUT_VOTE_AMOUNT,
+    //     VoteType::Yay,
+    //   ),
+    //   Error::<Test>::ProposalNotVerified
+    // );
+    assert_err!(
+      SubnetVoting::cast_vote(
+        RuntimeOrigin::signed(account(0)),
+        proposal_index - 1,
+        DEFAUT_VOTE_AMOUNT,
+        VoteType::Yay,
+        Conviction::None,
+      ),
+      Error::<Test>::ProposalNotActive
+    );
+
+  })
+}
 
-    let verifying_period = VerifyPeriod::get();
-    let voting_period = VotingPeriod::get();
-    System::set_block_number(System::block_number() + verifying_period + voting_period + 1);
+#[test]
+fn test_propose_deactivate() {
+  new_test_ext().execute_with(|| {
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    build_existing_subnet(0, min_subnet_nodes);
+    let prop_count = PropCount::<Test>::get();
 
+    let submit_epochs = pallet_network::MinRequiredSubnetConsensusSubmitEpochs::<Test>::get();
+    let epoch_length = EpochLength::get();
+
+    let subnet_path: Vec<u8> = DEFAULT_EXISTING_MODEL_PATH.into();
+    let subnet_id = pallet_network::SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+    pallet_network::SubnetPenaltyCount::<Test>::insert(subnet_id, 1);
+  
+    System::set_block_number(System::block_number() + submit_epochs * epoch_length + 1000);
+
+    let proposer_stake = MinProposerStake::get();
+    let _ = Balances::deposit_creating(&account(0), proposer_stake);
+  
     assert_ok!(
-      SubnetVoting::execute(
+      SubnetVoting::propose(
         RuntimeOrigin::signed(account(0)),
-        proposal_index,
+        default_existing_add_subnet_data(), 
+        Vec::new(),
+        PropsType::Deactivate,
+        Some(VoteOutReason::SubnetDown),
+        default_vote_out_evidence(),
+        None,
       )
     );
 
-    let proposal = Proposals::<Test>::get(proposal_index);
-    assert_eq!(proposal.proposal_status, PropsStatus::Defeated);
-
-    post_proposal_conclusion_unreserves(proposal_index, 0, min_subnet_nodes, vote_amount);
+    post_success_proposal_deactivate_ensures(DEFAULT_EXISTING_MODEL_PATH.into(), prop_count, 0, System::block_number());
   })
 }
 
 #[test]
-fn test_execute_cancelled() {
+fn test_propose_deactivate_peers_min_length_err() {
   new_test_ext().execute_with(|| {
-    let prop_count = PropCount::<Test>::get();
     let min_subnet_nodes = get_default_min_subnet_nodes();
     build_existing_subnet(0, min_subnet_nodes);
-
-    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
-    System::set_block_number(System::block_number() + 1);
-    let proposal = Proposals::<Test>::get(proposal_index);
-    let proposer_stake = proposal.proposer_stake;
-    // let proposer_beginning_balance = Balances::free_balance(&account(0));
-    // let proposer_assumed_balance = proposer_beginning_balance - DEFAUT_VOTE_AMOUNT;
-
+    let min_stake = pallet_network::MinStakeBalance::<Test>::get();
     let subnet_initialization_cost = get_subnet_initialization_cost();
 
-    assert_ne!(subnet_initialization_cost, 0);
+    let _ = Balances::deposit_creating(&account(0), subnet_initialization_cost);
 
-    for n in 0..min_subnet_nodes {
-      let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
-  
-      assert_ok!(
-        SubnetVoting::cast_vote(
-          RuntimeOrigin::signed(account(n)),
-          proposal_index,
-          DEFAUT_VOTE_AMOUNT,
-          VoteType::Yay,
-        )
-      );
-      post_cast_vote_ensures(proposal_index, n);
-    }
+    let subnet_nodes = build_subnet_nodes(0, min_subnet_nodes, min_stake);
 
-    let proposer_pre_cancel_balance = Balances::free_balance(&account(0));
+    let submit_epochs = pallet_network::MinRequiredSubnetConsensusSubmitEpochs::<Test>::get();
+    let epoch_length = EpochLength::get();
 
-    assert_ok!(
-      SubnetVoting::cancel_proposal(
+    System::set_block_number(System::block_number() + submit_epochs * epoch_length + 1000);
+
+    let proposer_stake = MinProposerStake::get();
+    let _ = Balances::deposit_creating(&account(0), proposer_stake);
+  
+    assert_err!(
+      SubnetVoting::propose(
         RuntimeOrigin::signed(account(0)),
-        proposal_index,
-      )
+        default_existing_add_subnet_data(), 
+        subnet_nodes,
+        PropsType::Deactivate,
+        Some(VoteOutReason::SubnetDown),
+        default_vote_out_evidence(),
+        None,
+      ),
+      Error::<Test>::SubnetNodesLengthInvalid
     );
+  })
+}
 
-    let proposer_stake_unreserve = proposer_stake - Percent::from_percent(CancelSlashPercent::get()) * proposer_stake;
-
-    let proposer_after_balance = Balances::free_balance(&account(0));
-    // assert_eq!(proposer_after_balance, proposer_beginning_balance + subnet_initialization_cost + DEFAUT_VOTE_AMOUNT);
-    assert_eq!(proposer_after_balance, proposer_pre_cancel_balance + proposer_stake_unreserve);
+#[test]
+fn test_propose_deactivate_subnet_id_exist_err() {
+  new_test_ext().execute_with(|| {
+    let min_subnet_nodes = get_default_min_subnet_nodes();
+    let proposer_stake = MinProposerStake::get();
+    let _ = Balances::deposit_creating(&account(0), proposer_stake);
 
-    post_activate_cancel_ensures(prop_count, DEFAULT_MODEL_PATH.into());
-    // post_proposal_conclusion_unreserves(proposal_index, 0, min_subnet_nodes, DEFAUT_VOTE_AMOUNT);
+    assert_err!(
+      SubnetVoting::propose(
+        RuntimeOrigin::signed(account(0)),
+        default_existing_add_subnet_data(), 
+        Vec::new(),
+        PropsType::Deactivate,
+        Some(VoteOutReason::SubnetDown),
+        default_vote_out_evidence(),
+        None,
+      ),
+      Error::<Test>::SubnetIdNotExists
+    );
   })
 }
 
 #[test]
-fn test_execute_cancelled_not_proposer_err() {
+fn test_propose_deactivate_already_active_err() {
   new_test_ext().execute_with(|| {
-    let prop_count = PropCount::<Test>::get();
-    let min_subnet_nodes = get_default_min_subnet_nodes();
+    let min_subnet_nodes = get_default_existing_min_subnet_nodes();
     build_existing_subnet(0, min_subnet_nodes);
 
-    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
-    System::set_block_number(System::block_number() + 1);
+    let submit_epochs = pallet_network::MinRequiredSubnetConsensusSubmitEpochs::<Test>::get();
+    let epoch_length = EpochLength::get();
 
-    for n in 0..min_subnet_nodes {
-      let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
+    let subnet_path: Vec<u8> = DEFAULT_EXISTING_MODEL_PATH.into();
+    let subnet_id = pallet_network::SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+    pallet_network::SubnetPenaltyCount::<Test>::insert(subnet_id, 1);
   
-      assert_ok!(
-        SubnetVoting::cast_vote(
-          RuntimeOrigin::signed(account(n)),
-          proposal_index,
-          DEFAUT_VOTE_AMOUNT,
-          VoteType::Yay,
-        )
-      );
-      post_cast_vote_ensures(proposal_index, n);
-    }
+    System::set_block_number(System::block_number() + submit_epochs * epoch_length + 1000);
+
+    let proposer_stake = MinProposerStake::get();
+    let _ = Balances::deposit_creating(&account(0), proposer_stake + 1000);
+  
+    assert_ok!(
+      SubnetVoting::propose(
+        RuntimeOrigin::signed(account(0)),
+        default_existing_add_subnet_data(), 
+        Vec::new(),
+        PropsType::Deactivate,
+        Some(VoteOutReason::SubnetDown),
+        default_vote_out_evidence(),
+        None,
+      )
+    );
+
+    let proposer_stake = MinProposerStake::get();
+    let _ = Balances::deposit_creating(&account(0), proposer_stake + 1000);
 
     assert_err!(
-      SubnetVoting::cancel_proposal(
-        RuntimeOrigin::signed(account(1)),
-        proposal_index,
+      SubnetVoting::propose(
+        RuntimeOrigin::signed(account(0)),
+        default_existing_add_subnet_data(), 
+        Vec::new(),
+        PropsType::Deactivate,
+        Some(VoteOutReason::SubnetDown),
+        default_vote_out_evidence(),
+        None,
       ),
-      Error::<Test>::NotProposer
+      Error::<Test>::ProposalInvalid
     );
   })
 }
 
 #[test]
-fn test_execute_cancelled_verified_err() {
+fn test_propose_deactivate_vote_out_reason() {
   new_test_ext().execute_with(|| {
-    let prop_count = PropCount::<Test>::get();
     let min_subnet_nodes = get_default_min_subnet_nodes();
     build_existing_subnet(0, min_subnet_nodes);
+    let prop_count = PropCount::<Test>::get();
 
-    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
-    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+    let submit_epochs = pallet_network::MinRequiredSubnetConsensusSubmitEpochs::<Test>::get();
+    let epoch_length = EpochLength::get();
+
+    System::set_block_number(System::block_number() + submit_epochs * epoch_length + 1000);
+
+    let proposer_stake = MinProposerStake::get();
+    let _ = Balances::deposit_creating(&account(0), proposer_stake);
 
+    // --- Deactivation requires a reason
     assert_err!(
-      SubnetVoting::cancel_proposal(
-        RuntimeOrigin::signed(account(1)),
-        proposal_index,
+      SubnetVoting::propose(
+        RuntimeOrigin::signed(account(0)),
+        default_existing_add_subnet_data(), 
+        Vec::new(),
+        PropsType::Deactivate,
+        None,
+        default_vote_out_evidence(),
+        None,
       ),
-      Error::<Test>::ProposalVerified
+      Error::<Test>::VoteOutReasonRequired
+    );
+
+    // --- Deactivation requires evidence
+    assert_err!(
+      SubnetVoting::propose(
+        RuntimeOrigin::signed(account(0)),
+        default_existing_add_subnet_data(), 
+        Vec::new(),
+        PropsType::Deactivate,
+        Some(VoteOutReason::SubnetIllicit),
+        BoundedVec::new(),
+        None,
+      ),
+      Error::<Test>::VoteOutReasonRequired
+    );
+
+    // --- Activation can't carry a vote out reason
+    assert_err!(
+      SubnetVoting::propose(
+        RuntimeOrigin::signed(account(0)),
+        default_add_subnet_data(), 
+        Vec::new(),
+        PropsType::Activate,
+        Some(VoteOutReason::SubnetIllicit),
+        BoundedVec::new(),
+        None,
+      ),
+      Error::<Test>::VoteOutReasonInvalid
+    );
+
+    assert_ok!(
+      SubnetVoting::propose(
+        RuntimeOrigin::signed(account(0)),
+        default_existing_add_subnet_data(), 
+        Vec::new(),
+        PropsType::Deactivate,
+        Some(VoteOutReason::SubnetIllicit),
+        default_vote_out_evidence(),
+        None,
+      )
     );
+
+    let proposal = Proposals::<Test>::get(prop_count);
+    assert_eq!(proposal.vote_out_reason, Some(VoteOutReason::SubnetIllicit));
+    assert_eq!(proposal.vote_out_evidence, default_vote_out_evidence());
   })
 }
 
 #[test]
-fn test_execute_cancelled_proposal_index_err() {
+fn test_execute_deactivate_succeeded() {
   new_test_ext().execute_with(|| {
     let prop_count = PropCount::<Test>::get();
-    let min_subnet_nodes = get_default_min_subnet_nodes();
-    build_existing_subnet(0, min_subnet_nodes);
+    let min_subnet_nodes = get_default_existing_min_subnet_nodes();
+
+    let proposal_index = build_propose_deactivate(DEFAULT_EXISTING_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+
+    assert_eq!(DeactivateProposalsCount::<Test>::get(), 1);
 
-    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
     System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
 
     for n in 0..min_subnet_nodes {
@@ -1553,29 +2411,56 @@ fn test_execute_cancelled_proposal_index_err() {
           proposal_index,
           DEFAUT_VOTE_AMOUNT,
           VoteType::Yay,
+          Conviction::None,
         )
       );
       post_cast_vote_ensures(proposal_index, n);
     }
 
-    assert_err!(
-      SubnetVoting::cancel_proposal(
-        RuntimeOrigin::signed(account(1)),
-        proposal_index + 1,
-      ),
-      Error::<Test>::ProposalInvalid
+    let verifying_period = VerifyPeriod::get();
+    let voting_period = VotingPeriod::get();
+    System::set_block_number(System::block_number() + verifying_period + voting_period + 1);
+
+    let subnet_path: Vec<u8> = DEFAULT_EXISTING_MODEL_PATH.into();
+    let subnet_id = pallet_network::SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+
+    assert_ok!(
+      SubnetVoting::execute(
+        RuntimeOrigin::signed(account(0)),
+        proposal_index,
+      )
+    );
+
+    post_deactivate_succeeded_execute_ensures(proposal_index, DEFAULT_EXISTING_MODEL_PATH.into());
+
+    // --- Vote out reason is passed through to the network pallet
+    System::assert_has_event(
+      RuntimeEvent::Network(
+        pallet_network::Event::SubnetDeactivated {
+          subnet_id: subnet_id,
+          reason: pallet_network::SubnetRemovalReason::SubnetDown,
+        }
+      )
     );
+
+    post_proposal_conclusion_unlocks(proposal_index, 0, min_subnet_nodes, DEFAUT_VOTE_AMOUNT);
   })
 }
 
 #[test]
-fn test_execute_cancelled_vote_completed_err() {
+fn test_scheduled_enactment_deactivate_succeeded() {
   new_test_ext().execute_with(|| {
-    let prop_count = PropCount::<Test>::get();
-    let min_subnet_nodes = get_default_min_subnet_nodes();
-    build_existing_subnet(0, min_subnet_nodes);
+    let min_subnet_nodes = get_default_existing_min_subnet_nodes();
+
+    let proposal_index = build_propose_deactivate(DEFAULT_EXISTING_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+
+    // --- Enactment is scheduled for the block after voting ends
+    let enactment_block = Proposals::<Test>::get(proposal_index).end_vote_block + 1;
+    assert_eq!(
+      pallet_scheduler::Agenda::<Test>::get(enactment_block).iter().filter(|s| s.is_some()).count(),
+      1
+    );
 
-    let proposal_index = build_propose_activate(DEFAULT_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
     System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
 
     for n in 0..min_subnet_nodes {
@@ -1587,208 +2472,219 @@ fn test_execute_cancelled_vote_completed_err() {
           proposal_index,
           DEFAUT_VOTE_AMOUNT,
           VoteType::Yay,
+          Conviction::None,
         )
       );
-      post_cast_vote_ensures(proposal_index, n);
     }
 
-    let verifying_period = VerifyPeriod::get();
-    let voting_period = VotingPeriod::get();
-    System::set_block_number(System::block_number() + verifying_period + voting_period + 1);
-
+    // --- Nobody calls ``execute``, the scheduler enacts the proposal
+    System::set_block_number(enactment_block);
+    Scheduler::on_initialize(enactment_block);
+
+    post_deactivate_succeeded_execute_ensures(proposal_index, DEFAULT_EXISTING_MODEL_PATH.into());
+    assert!(pallet_scheduler::Agenda::<Test>::get(enactment_block).iter().all(|s| s.is_none()));
+
+    // --- Can't be executed again
     assert_err!(
-      SubnetVoting::cancel_proposal(
+      SubnetVoting::execute(
         RuntimeOrigin::signed(account(0)),
         proposal_index,
       ),
-      Error::<Test>::VoteComplete
+      Error::<Test>::Concluded
     );
   })
 }
 
 #[test]
-fn test_propose_activate_not_verified_err() {
+fn test_scheduled_enactment_cancelled_on_execute_and_cancel() {
   new_test_ext().execute_with(|| {
-    let prop_count = PropCount::<Test>::get();
-    let min_subnet_nodes = get_default_min_subnet_nodes();
-    let min_stake = pallet_network::MinStakeBalance::<Test>::get();
-    let subnet_initialization_cost = get_subnet_initialization_cost();
-
-    let _ = Balances::deposit_creating(&account(0), subnet_initialization_cost);
-
-    let subnet_nodes = build_subnet_nodes(0, min_subnet_nodes, min_stake);
-
-    assert_ok!(
-      SubnetVoting::propose(
-        RuntimeOrigin::signed(account(0)),
-        default_add_subnet_data(), 
-        subnet_nodes.clone(),
-        PropsType::Activate,
-      )
-    );
+    let min_subnet_nodes = get_default_existing_min_subnet_nodes();
 
-    let activate_proposals = ActivateProposalsCount::<Test>::get();
-    assert_eq!(activate_proposals, 1);
-    post_success_proposal_activate_ensures(default_add_subnet_data().path, prop_count, 0, System::block_number(), subnet_nodes.clone().len() as u32);
-    
-    let proposal_index = PropCount::<Test>::get();
+    let proposal_index = build_propose_deactivate(DEFAULT_EXISTING_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+    let enactment_block = Proposals::<Test>::get(proposal_index).end_vote_block + 1;
 
-    // assert_err!(
-    //   SubnetVoting::cast_vote(
-    //     RuntimeOrigin::signed(account(0)),
-    //     proposal_index - 1,
-    //     DEFAUT_VOTE_AMOUNT,
-    //     VoteType::Yay,
-    //   ),
-    //   Error::<Test>::ProposalNotVerified
-    // );
+    // --- Only root can enact
     assert_err!(
-      SubnetVoting::cast_vote(
+      SubnetVoting::enact(
         RuntimeOrigin::signed(account(0)),
-        proposal_index - 1,
-        DEFAUT_VOTE_AMOUNT,
-        VoteType::Yay,
+        proposal_index,
       ),
-      Error::<Test>::ProposalNotActive
+      sp_runtime::DispatchError::BadOrigin
     );
 
-  })
-}
-
-#[test]
-fn test_propose_deactivate() {
-  new_test_ext().execute_with(|| {
-    let min_subnet_nodes = get_default_min_subnet_nodes();
-    build_existing_subnet(0, min_subnet_nodes);
-    let prop_count = PropCount::<Test>::get();
-
-    let submit_epochs = pallet_network::MinRequiredSubnetConsensusSubmitEpochs::<Test>::get();
-    let epoch_length = EpochLength::get();
+    assert_ok!(
+      SubnetVoting::cancel_proposal(
+        RuntimeOrigin::signed(account(0)),
+        proposal_index,
+      )
+    );
 
-    let subnet_path: Vec<u8> = DEFAULT_EXISTING_MODEL_PATH.into();
-    let subnet_id = pallet_network::SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
-    pallet_network::SubnetPenaltyCount::<Test>::insert(subnet_id, 1);
-  
-    System::set_block_number(System::block_number() + submit_epochs * epoch_length + 1000);
+    assert!(pallet_scheduler::Agenda::<Test>::get(enactment_block).iter().all(|s| s.is_none()));
 
+    // --- Manually executing removes the scheduled enactment
     let proposer_stake = MinProposerStake::get();
     let _ = Balances::deposit_creating(&account(0), proposer_stake);
-  
+
+    let proposal_index = PropCount::<Test>::get();
     assert_ok!(
       SubnetVoting::propose(
         RuntimeOrigin::signed(account(0)),
         default_existing_add_subnet_data(), 
         Vec::new(),
         PropsType::Deactivate,
+        Some(VoteOutReason::SubnetDown),
+        default_vote_out_evidence(),
+        None,
       )
     );
+    let enactment_block = Proposals::<Test>::get(proposal_index).end_vote_block + 1;
 
-    post_success_proposal_deactivate_ensures(DEFAULT_EXISTING_MODEL_PATH.into(), prop_count, 0, System::block_number());
+    System::set_block_number(enactment_block);
+
+    assert_ok!(
+      SubnetVoting::execute(
+        RuntimeOrigin::signed(account(0)),
+        proposal_index,
+      )
+    );
+
+    assert!(pallet_scheduler::Agenda::<Test>::get(enactment_block).iter().all(|s| s.is_none()));
   })
 }
 
 #[test]
-fn test_propose_deactivate_peers_min_length_err() {
+fn test_propose_update() {
   new_test_ext().execute_with(|| {
     let min_subnet_nodes = get_default_min_subnet_nodes();
     build_existing_subnet(0, min_subnet_nodes);
-    let min_stake = pallet_network::MinStakeBalance::<Test>::get();
-    let subnet_initialization_cost = get_subnet_initialization_cost();
-
-    let _ = Balances::deposit_creating(&account(0), subnet_initialization_cost);
-
-    let subnet_nodes = build_subnet_nodes(0, min_subnet_nodes, min_stake);
-
-    let submit_epochs = pallet_network::MinRequiredSubnetConsensusSubmitEpochs::<Test>::get();
-    let epoch_length = EpochLength::get();
-
-    System::set_block_number(System::block_number() + submit_epochs * epoch_length + 1000);
+    let prop_count = PropCount::<Test>::get();
 
     let proposer_stake = MinProposerStake::get();
     let _ = Balances::deposit_creating(&account(0), proposer_stake);
-  
+
+    let subnet_update = pallet_network::SubnetUpdateData {
+      memory_mb: Some(100000),
+      registration_blocks: None,
+      min_nodes: None,
+      target_nodes: None,
+    };
+
+    // --- Update proposals require update data
     assert_err!(
       SubnetVoting::propose(
         RuntimeOrigin::signed(account(0)),
         default_existing_add_subnet_data(), 
-        subnet_nodes,
-        PropsType::Deactivate,
+        Vec::new(),
+        PropsType::Update,
+        None,
+        BoundedVec::new(),
+        None,
       ),
-      Error::<Test>::SubnetNodesLengthInvalid
+      Error::<Test>::SubnetUpdateInvalid
     );
-  })
-}
-
-#[test]
-fn test_propose_deactivate_subnet_id_exist_err() {
-  new_test_ext().execute_with(|| {
-    let min_subnet_nodes = get_default_min_subnet_nodes();
-    let proposer_stake = MinProposerStake::get();
-    let _ = Balances::deposit_creating(&account(0), proposer_stake);
 
+    // --- Other proposals can't carry update data
     assert_err!(
       SubnetVoting::propose(
         RuntimeOrigin::signed(account(0)),
         default_existing_add_subnet_data(), 
         Vec::new(),
         PropsType::Deactivate,
+        Some(VoteOutReason::SubnetDown),
+        default_vote_out_evidence(),
+        Some(subnet_update.clone()),
       ),
-      Error::<Test>::SubnetIdNotExists
+      Error::<Test>::SubnetUpdateInvalid
     );
-  })
-}
-
-#[test]
-fn test_propose_deactivate_already_active_err() {
-  new_test_ext().execute_with(|| {
-    let min_subnet_nodes = get_default_existing_min_subnet_nodes();
-    build_existing_subnet(0, min_subnet_nodes);
-
-    let submit_epochs = pallet_network::MinRequiredSubnetConsensusSubmitEpochs::<Test>::get();
-    let epoch_length = EpochLength::get();
-
-    let subnet_path: Vec<u8> = DEFAULT_EXISTING_MODEL_PATH.into();
-    let subnet_id = pallet_network::SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
-    pallet_network::SubnetPenaltyCount::<Test>::insert(subnet_id, 1);
-  
-    System::set_block_number(System::block_number() + submit_epochs * epoch_length + 1000);
 
-    let proposer_stake = MinProposerStake::get();
-    let _ = Balances::deposit_creating(&account(0), proposer_stake + 1000);
-  
-    assert_ok!(
+    // --- Memory is validated against the network
+    assert_err!(
       SubnetVoting::propose(
         RuntimeOrigin::signed(account(0)),
         default_existing_add_subnet_data(), 
         Vec::new(),
-        PropsType::Deactivate,
-      )
+        PropsType::Update,
+        None,
+        BoundedVec::new(),
+        Some(pallet_network::SubnetUpdateData {
+          memory_mb: Some(pallet_network::MaxSubnetMemoryMB::<Test>::get() + 1),
+          ..subnet_update.clone()
+        }),
+      ),
+      pallet_network::Error::<Test>::MaxSubnetMemory
     );
 
-    let proposer_stake = MinProposerStake::get();
-    let _ = Balances::deposit_creating(&account(0), proposer_stake + 1000);
-
+    // --- Registration blocks are validated against the network
     assert_err!(
       SubnetVoting::propose(
         RuntimeOrigin::signed(account(0)),
         default_existing_add_subnet_data(), 
         Vec::new(),
-        PropsType::Deactivate,
+        PropsType::Update,
+        None,
+        BoundedVec::new(),
+        Some(pallet_network::SubnetUpdateData {
+          registration_blocks: Some(pallet_network::MaxSubnetRegistrationBlocks::<Test>::get() + 1),
+          ..subnet_update.clone()
+        }),
       ),
-      Error::<Test>::ProposalInvalid
+      pallet_network::Error::<Test>::InvalidSubnetRegistrationBlocks
+    );
+
+    assert_ok!(
+      SubnetVoting::propose(
+        RuntimeOrigin::signed(account(0)),
+        default_existing_add_subnet_data(), 
+        Vec::new(),
+        PropsType::Update,
+        None,
+        BoundedVec::new(),
+        Some(subnet_update.clone()),
+      )
     );
+
+    let proposal = Proposals::<Test>::get(prop_count);
+    assert_eq!(proposal.proposal_type, PropsType::Update);
+    assert_eq!(proposal.proposal_status, PropsStatus::Active);
+    assert_eq!(proposal.proposer_stake, proposer_stake);
+    assert_eq!(proposal.subnet_update, Some(subnet_update));
+    assert_eq!(PropsPathStatus::<Test>::get(default_existing_add_subnet_data().path), PropsStatus::Active);
   })
 }
 
 #[test]
-fn test_execute_deactivate_succeeded() {
+fn test_execute_update_succeeded() {
   new_test_ext().execute_with(|| {
-    let prop_count = PropCount::<Test>::get();
     let min_subnet_nodes = get_default_existing_min_subnet_nodes();
+    build_existing_subnet(0, min_subnet_nodes);
+    let proposal_index = PropCount::<Test>::get();
 
-    let proposal_index = build_propose_deactivate(DEFAULT_EXISTING_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+    let subnet_path: Vec<u8> = DEFAULT_EXISTING_MODEL_PATH.into();
+    let subnet_id = pallet_network::SubnetPaths::<Test>::get(subnet_path.clone()).unwrap();
+    let subnet = pallet_network::SubnetsData::<Test>::get(subnet_id).unwrap();
+    let total_subnet_memory_mb = pallet_network::TotalSubnetMemoryMB::<Test>::get();
 
-    assert_eq!(DeactivateProposalsCount::<Test>::get(), 1);
+    let proposer_stake = MinProposerStake::get();
+    let _ = Balances::deposit_creating(&account(0), proposer_stake);
+
+    let memory_mb: u128 = 100000;
+
+    assert_ok!(
+      SubnetVoting::propose(
+        RuntimeOrigin::signed(account(0)),
+        default_existing_add_subnet_data(), 
+        Vec::new(),
+        PropsType::Update,
+        None,
+        BoundedVec::new(),
+        Some(pallet_network::SubnetUpdateData {
+          memory_mb: Some(memory_mb),
+          registration_blocks: None,
+          min_nodes: None,
+          target_nodes: None,
+        }),
+      )
+    );
 
     System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
 
@@ -1801,14 +2697,12 @@ fn test_execute_deactivate_succeeded() {
           proposal_index,
           DEFAUT_VOTE_AMOUNT,
           VoteType::Yay,
+          Conviction::None,
         )
       );
-      post_cast_vote_ensures(proposal_index, n);
     }
 
-    let verifying_period = VerifyPeriod::get();
-    let voting_period = VotingPeriod::get();
-    System::set_block_number(System::block_number() + verifying_period + voting_period + 1);
+    System::set_block_number(System::block_number() + VotingPeriod::get() + 1);
 
     assert_ok!(
       SubnetVoting::execute(
@@ -1817,9 +2711,27 @@ fn test_execute_deactivate_succeeded() {
       )
     );
 
-    post_deactivate_succeeded_execute_ensures(proposal_index, DEFAULT_EXISTING_MODEL_PATH.into());
+    let proposal = Proposals::<Test>::get(proposal_index);
+    assert_eq!(proposal.proposal_status, PropsStatus::Succeeded);
+    assert_eq!(proposal.proposer_stake, 0);
+    assert_eq!(Balances::reserved_balance(&account(0)), 0);
+
+    let min_nodes = pallet_network::Pallet::<Test>::get_min_subnet_nodes(
+      pallet_network::BaseSubnetNodeMemoryMB::<Test>::get(),
+      memory_mb
+    );
+
+    let updated_subnet = pallet_network::SubnetsData::<Test>::get(subnet_id).unwrap();
+    assert_eq!(updated_subnet.memory_mb, memory_mb);
+    assert_eq!(updated_subnet.min_nodes, min_nodes);
+    assert_eq!(updated_subnet.target_nodes, pallet_network::Pallet::<Test>::get_target_subnet_nodes(min_nodes));
+    assert_eq!(updated_subnet.registration_blocks, subnet.registration_blocks);
+    assert_eq!(
+      pallet_network::TotalSubnetMemoryMB::<Test>::get(),
+      total_subnet_memory_mb - subnet.memory_mb + memory_mb
+    );
 
-    post_proposal_conclusion_unreserves(proposal_index, 0, min_subnet_nodes, DEFAUT_VOTE_AMOUNT);
+    post_proposal_conclusion_unlocks(proposal_index, 0, min_subnet_nodes, DEFAUT_VOTE_AMOUNT);
   })
 }
 
@@ -1850,7 +2762,7 @@ fn test_propose_activate_expired() {
     let proposal = Proposals::<Test>::get(proposal_index);
     let path: Vec<u8> = DEFAULT_MODEL_PATH.into();
 
-    let proposal_path_status = PropsPathStatus::<Test>::get(path.clone());
+    let proposal_path_status = PropsPathStatus::<Test>::get(bounded_path(path.clone()));
     assert_eq!(proposal_path_status, PropsStatus::Expired);
   
     assert_eq!(proposal.proposal_status, PropsStatus::Expired);
@@ -1881,6 +2793,7 @@ fn test_balance_on_multiple_votes() {
           proposal_index,
           DEFAUT_VOTE_AMOUNT,
           VoteType::Yay,
+          Conviction::None,
         )
       );
     }
@@ -1917,6 +2830,7 @@ fn test_balance_on_multiple_votes() {
           proposal_index,
           DEFAUT_VOTE_AMOUNT,
           VoteType::Nay,
+          Conviction::None,
         ),
         Error::<Test>::NotEnoughBalanceToVote
       );
@@ -1979,12 +2893,15 @@ fn test_propose_activate_verify_period_passed_err() {
         default_add_subnet_data(), 
         subnet_nodes.clone(),
         PropsType::Activate,
+        None,
+        BoundedVec::new(),
+        None,
       )
     );
 
     let activate_proposals = ActivateProposalsCount::<Test>::get();
     assert_eq!(activate_proposals, 1);
-    post_success_proposal_activate_ensures(default_add_subnet_data().path, prop_count, 0, System::block_number(), subnet_nodes.clone().len() as u32);
+    post_success_proposal_activate_ensures(default_add_subnet_data().path.into(), prop_count, 0, System::block_number(), subnet_nodes.clone().len() as u32);
   
     let proposal_index = PropCount::<Test>::get();
 
@@ -2028,6 +2945,9 @@ fn test_propose_deactivate_verify_proposal_is_deactivate_err() {
         default_existing_add_subnet_data(), 
         Vec::new(),
         PropsType::Deactivate,
+        Some(VoteOutReason::SubnetDown),
+        default_vote_out_evidence(),
+        None,
       )
     );
   
@@ -2062,6 +2982,9 @@ fn test_propose_deactivate_verify_not_verify_subnet_node_err() {
         default_add_subnet_data(), 
         subnet_nodes,
         PropsType::Activate,
+        None,
+        BoundedVec::new(),
+        None,
       )
     );
 
@@ -2083,3 +3006,136 @@ fn test_propose_deactivate_verify_not_verify_subnet_node_err() {
   })
 }
 
+#[test]
+fn test_prune_concluded_proposal() {
+  new_test_ext().execute_with(|| {
+    let min_subnet_nodes = get_default_existing_min_subnet_nodes();
+
+    let proposal_index = build_propose_deactivate(DEFAULT_EXISTING_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+
+    for n in 0..min_subnet_nodes {
+      let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
+  
+      assert_ok!(
+        SubnetVoting::cast_vote(
+          RuntimeOrigin::signed(account(n)),
+          proposal_index,
+          DEFAUT_VOTE_AMOUNT,
+          VoteType::Yay,
+          Conviction::None,
+        )
+      );
+    }
+
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + VotingPeriod::get() + 1);
+
+    assert_ok!(
+      SubnetVoting::execute(
+        RuntimeOrigin::signed(account(0)),
+        proposal_index,
+      )
+    );
+
+    let outcome = ProposalOutcomes::<Test>::get(proposal_index).unwrap();
+    assert_eq!(outcome.proposal_status, PropsStatus::Succeeded);
+    assert_eq!(outcome.proposal_type, PropsType::Deactivate);
+    assert_eq!(outcome.path, bounded_path(DEFAULT_EXISTING_MODEL_PATH.into()));
+    assert_eq!(outcome.votes, Votes::<Test>::get(proposal_index));
+    assert_eq!(outcome.concluded_block, System::block_number());
+
+    // --- Not pruned within the retention period
+    let retention_block = System::block_number() + ProposalRetentionPeriod::get();
+    SubnetVoting::on_initialize(retention_block - 1);
+    assert!(Proposals::<Test>::contains_key(proposal_index));
+    assert_eq!(PruneCursor::<Test>::get(), 0);
+    assert_eq!(ConcludedProposals::<Test>::get(0), Some(proposal_index));
+    // --- Votes that weren't unlocked by their voters are released by pruning
+    assert_eq!(Balances::locks(&account(0))[0].amount, DEFAUT_VOTE_AMOUNT);
+
+    SubnetVoting::on_initialize(retention_block);
+    assert!(!Proposals::<Test>::contains_key(proposal_index));
+    assert!(!Votes::<Test>::contains_key(proposal_index));
+    assert!(!TotalVotingPowerSnapshot::<Test>::contains_key(proposal_index));
+    assert!(!PropsPathStatus::<Test>::contains_key(bounded_path(DEFAULT_EXISTING_MODEL_PATH.into())));
+    assert_eq!(PruneCursor::<Test>::get(), 1);
+    assert_eq!(ConcludedProposals::<Test>::get(0), None);
+
+    for n in 0..min_subnet_nodes {
+      assert_eq!(VotesBalance::<Test>::get(proposal_index, account(n)), 0);
+      assert_eq!(VotingPowerSnapshots::<Test>::get(proposal_index, account(n)), None);
+      assert_eq!(VoteLocks::<Test>::get(account(n), proposal_index), None);
+      assert_eq!(Balances::locks(&account(n)).len(), 0);
+    }
+
+    // --- Outcome record is kept
+    assert_eq!(ProposalOutcomes::<Test>::get(proposal_index), Some(outcome));
+  })
+}
+
+#[test]
+fn test_prune_concluded_proposal_behind_open_proposal() {
+  new_test_ext().execute_with(|| {
+    let min_subnet_nodes = get_default_existing_min_subnet_nodes();
+
+    // --- An older proposal is still open and has no outcome
+    PropCount::<Test>::put(1);
+
+    build_propose_deactivate(DEFAULT_EXISTING_MODEL_PATH.into(), 0, min_subnet_nodes, DEFAULT_DEPOSIT_AMOUNT);
+    let proposal_index = PropCount::<Test>::get() - 1;
+    assert_eq!(proposal_index, 1);
+
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+
+    for n in 0..min_subnet_nodes {
+      let _ = Balances::deposit_creating(&account(n), DEFAUT_VOTE_AMOUNT);
+  
+      assert_ok!(
+        SubnetVoting::cast_vote(
+          RuntimeOrigin::signed(account(n)),
+          proposal_index,
+          DEFAUT_VOTE_AMOUNT,
+          VoteType::Yay,
+          Conviction::None,
+        )
+      );
+    }
+
+    System::set_block_number(System::block_number() + VerifyPeriod::get() + VotingPeriod::get() + 1);
+
+    assert_ok!(
+      SubnetVoting::execute(
+        RuntimeOrigin::signed(account(0)),
+        proposal_index,
+      )
+    );
+
+    assert_eq!(ProposalOutcomes::<Test>::get(0), None);
+    assert_eq!(ConcludedProposals::<Test>::get(0), Some(proposal_index));
+
+    let retention_block = System::block_number() + ProposalRetentionPeriod::get();
+    SubnetVoting::on_initialize(retention_block);
+    assert!(!Proposals::<Test>::contains_key(proposal_index));
+    assert!(!Votes::<Test>::contains_key(proposal_index));
+    assert_eq!(PruneCursor::<Test>::get(), 1);
+  })
+}
+
+#[test]
+fn test_propose_path_too_long() {
+  new_test_ext().execute_with(|| {
+    let max_path_length = <Test as crate::Config>::MaxPathLength::get();
+
+    // --- Subnet data with a path over ``MaxPathLength`` can't be decoded into a call
+    let encoded = (vec![0u8; max_path_length as usize + 1], 50000u128, 0u64).encode();
+    assert!(
+      RegistrationSubnetData::<<Test as crate::Config>::MaxPathLength>::decode(&mut &encoded[..]).is_err()
+    );
+
+    let encoded = (vec![0u8; max_path_length as usize], 50000u128, 0u64).encode();
+    assert!(
+      RegistrationSubnetData::<<Test as crate::Config>::MaxPathLength>::decode(&mut &encoded[..]).is_ok()
+    );
+  })
+}
@@ -35,7 +35,7 @@ pub trait WeightInfo {
 	fn cast_vote() -> Weight;
 	fn execute() -> Weight;
 	fn cancel_proposal() -> Weight;
-	fn unreserve() -> Weight;
+	fn unlock() -> Weight;
 }
 
 /// Weights for `pallet_subnet_democracy` using the Substrate node and recommended hardware.
@@ -131,16 +131,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `SubnetVoting::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SubnetVoting::VotesBalance` (r:1 w:1)
 	/// Proof: `SubnetVoting::VotesBalance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetVoting::VoteLocks` (r:2 w:1)
+	/// Proof: `SubnetVoting::VoteLocks` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `SubnetVoting::ProposalOutcomes` (r:1 w:0)
+	/// Proof: `SubnetVoting::ProposalOutcomes` (`max_values`: None, `max_size`: Some(358), added: 2833, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn unreserve() -> Weight {
+	fn unlock() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `841`
-		//  Estimated: `4306`
-		// Minimum execution time: 43_200_000 picoseconds.
-		Weight::from_parts(44_990_000, 4306)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		//  Measured:  `1262`
+		//  Estimated: `6066`
+		// Minimum execution time: 61_870_000 picoseconds.
+		Weight::from_parts(63_912_000, 6066)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 }
 
@@ -236,15 +242,21 @@ impl WeightInfo for () {
 	/// Proof: `SubnetVoting::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SubnetVoting::VotesBalance` (r:1 w:1)
 	/// Proof: `SubnetVoting::VotesBalance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetVoting::VoteLocks` (r:2 w:1)
+	/// Proof: `SubnetVoting::VoteLocks` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `SubnetVoting::ProposalOutcomes` (r:1 w:0)
+	/// Proof: `SubnetVoting::ProposalOutcomes` (`max_values`: None, `max_size`: Some(358), added: 2833, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn unreserve() -> Weight {
+	fn unlock() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `841`
-		//  Estimated: `4306`
-		// Minimum execution time: 43_200_000 picoseconds.
-		Weight::from_parts(44_990_000, 4306)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `1262`
+		//  Estimated: `6066`
+		// Minimum execution time: 61_870_000 picoseconds.
+		Weight::from_parts(63_912_000, 6066)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 }
\ No newline at end of file
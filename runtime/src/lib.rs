@@ -4,6 +4,8 @@
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 pub mod impls;
+#[cfg(test)]
+mod tests;
 extern crate alloc;
 use alloc::{vec, vec::Vec};
 use pallet_grandpa::AuthorityId as GrandpaId;
@@ -56,7 +58,7 @@ pub use sp_runtime::BuildStorage;
 pub use sp_runtime::{Perbill, Permill};
 
 pub use pallet_network;
-// pub use pallet_subnet_democracy;
+pub use pallet_subnet_democracy;
 pub use pallet_admin;
 pub use pallet_rewards;
 
@@ -480,10 +482,11 @@ parameter_types! {
 	pub const DelegateStakeEpochsRemovalWindow: u64 = 10;
 	pub const MaxDelegateStakeUnlockings: u32 = 32;
 	pub const MaxStakeUnlockings: u32 = 32;
+	pub const MaxSubnetNodes: u32 = 1000;
 }
 
 impl pallet_network::Config for Runtime {
-	type WeightInfo = ();
+	type WeightInfo = pallet_network::weights::SubstrateWeight<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type EpochLength = EpochLength;
@@ -497,6 +500,8 @@ impl pallet_network::Config for Runtime {
 	type DelegateStakeEpochsRemovalWindow = DelegateStakeEpochsRemovalWindow;
 	type MaxDelegateStakeUnlockings = MaxDelegateStakeUnlockings;
 	type MaxStakeUnlockings = MaxStakeUnlockings;
+	type MaxSubnetNodes = MaxSubnetNodes;
+	type MaxPathLength = ConstU32<256>;
 	type StakeCooldownEpochs = StakeCooldownEpochs;
 	type Randomness = InsecureRandomnessCollectiveFlip;
 	type MinProposalStake = MinProposalStake;
@@ -515,28 +520,37 @@ parameter_types! {
 	pub const Quorum: u128 = 10_000_000_000_000_000_000_000; // 10,000 * 1e18
 	pub const CancelSlashPercent: u8 = 5;
 	pub const QuorumVotingPowerPercentage: u8 = 40;
+	pub const ProposalRetentionPeriod: BlockNumber = DAYS * 42;
 	// Local
 	// pub const VotingPeriod: BlockNumber = 50; // ~5 minutes
 	// pub const EnactmentPeriod: BlockNumber = 600; // ~60 minutes
 
 }
 
-// impl pallet_subnet_democracy::Config for Runtime {
-// 	type WeightInfo = ();
-// 	type RuntimeEvent = RuntimeEvent;
-// 	type SubnetVote = Network;
-// 	type Currency = Balances;
-// 	type MaxActivateProposals = ConstU32<1>;
-// 	type MaxDeactivateProposals = ConstU32<32>;
-// 	type MaxProposals = ConstU32<32>;
-// 	type VotingPeriod = VotingPeriod;
-// 	type EnactmentPeriod = EnactmentPeriod;
-// 	type VerifyPeriod = VerifyPeriod;
-// 	type MinProposerStake = MinProposerStake;
-// 	type Quorum = Quorum;
-// 	type CancelSlashPercent = CancelSlashPercent;
-// 	type QuorumVotingPowerPercentage = QuorumVotingPowerPercentage;
-// }
+impl pallet_subnet_democracy::Config for Runtime {
+	type WeightInfo = pallet_subnet_democracy::weights::SubstrateWeight<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+	type SubnetVote = Network;
+	type Currency = Balances;
+	type RuntimeCall = RuntimeCall;
+	type Scheduler = Scheduler;
+	type Preimages = Preimage;
+	type MaxActivateProposals = ConstU32<1>;
+	type MaxDeactivateProposals = ConstU32<32>;
+	type MaxProposals = ConstU32<32>;
+	type VotingPeriod = VotingPeriod;
+	type EnactmentPeriod = EnactmentPeriod;
+	type VerifyPeriod = VerifyPeriod;
+	type MinProposerStake = MinProposerStake;
+	type Quorum = Quorum;
+	type CancelSlashPercent = CancelSlashPercent;
+	type QuorumVotingPowerPercentage = QuorumVotingPowerPercentage;
+	type MaxPathLength = ConstU32<256>;
+	type MaxProposalSubnetNodes = ConstU32<256>;
+	type MaxPeerIdLength = ConstU32<128>;
+	type ProposalRetentionPeriod = ProposalRetentionPeriod;
+	type MaxVotesPrunedPerBlock = ConstU32<64>;
+}
 
 pub struct AuraAccountAdapter;
 impl frame_support::traits::FindAuthor<AccountId> for AuraAccountAdapter {
@@ -559,13 +573,33 @@ parameter_types! {
 	pub const InitialBlockSubsidy: u128 = INITIAL_REWARD_PER_BLOCK;
 }
 
+/// Issues half of the remaining ``TARGET_MAX_TOTAL_SUPPLY`` every ``BLOCKS_PER_HALVING``
+pub struct TargetSupplyEmissionCurve;
+impl frame_support::traits::Get<pallet_rewards::EmissionCurve> for TargetSupplyEmissionCurve {
+	fn get() -> pallet_rewards::EmissionCurve {
+		let points: Vec<(u64, Perbill)> = (1..=32u32)
+			.map(|k| (
+				BLOCKS_PER_HALVING as u64 * k as u64,
+				Perbill::one() - Perbill::from_rational(1u64, 1u64 << k),
+			))
+			.collect();
+		pallet_rewards::EmissionCurve::TargetSupply {
+			target_supply: TARGET_MAX_TOTAL_SUPPLY,
+			points: frame_support::BoundedVec::truncate_from(points),
+		}
+	}
+}
+
 impl pallet_rewards::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type FindAuthor = AuraAccountAdapter;
 	type HalvingInterval = HalvingInterval;
 	type InitialBlockSubsidy = InitialBlockSubsidy;
+	type InitialEmissionCurve = TargetSupplyEmissionCurve;
 	type IncreaseStakeVault = Network;
+	type Treasury = Network;
+	type WeightInfo = pallet_rewards::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_admin::Config for Runtime {
@@ -630,9 +664,6 @@ mod runtime {
 	#[runtime::pallet_index(10)]
 	pub type Network = pallet_network;
 
-	// #[runtime::pallet_index(11)]
-	// pub type SubnetDemocracy = pallet_subnet_democracy;
-
 	#[runtime::pallet_index(11)]
 	pub type Rewards = pallet_rewards;
 
@@ -656,6 +687,9 @@ mod runtime {
 
 	#[runtime::pallet_index(18)]
 	pub type AtomicSwap = pallet_atomic_swap;
+
+	#[runtime::pallet_index(19)]
+	pub type SubnetDemocracy = pallet_subnet_democracy;
 }
 
 /// The address format for describing accounts.
@@ -680,7 +714,13 @@ pub type SignedExtra = (
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_rewards::migrations::v1::MigrateToV1<Runtime>,
+	pallet_network::migrations::v1::MigrateToV1<Runtime>,
+	pallet_network::migrations::v2::MigrateToV2<Runtime>,
+	pallet_network::migrations::v3::MigrateToV3<Runtime>,
+	pallet_network::migrations::v4::MigrateToV4<Runtime>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -707,7 +747,8 @@ mod benches {
 		[pallet_sudo, Sudo]
 		[pallet_network, Network]
 		[pallet_collective, Collective]
-		// [pallet_subnet_democracy, SubnetDemocracy]
+		[pallet_subnet_democracy, SubnetDemocracy]
+		[pallet_rewards, Rewards]
 	);
 }
 
@@ -919,7 +960,40 @@ impl_runtime_apis! {
 		fn is_subnet_node_by_peer_id(subnet_id: u32, peer_id: Vec<u8>) -> bool {
 			let result = Network::is_subnet_node_by_peer_id(subnet_id, peer_id);
 			result
-		}	
+		}
+		fn get_subnet_emissions_preview() -> Vec<u8> {
+			let result = Network::get_subnet_emissions_preview();
+			result.encode()
+		}
+		fn get_subnet_rewards_receipt(subnet_id: u32, epoch: u32) -> Vec<u8> {
+			let result = Network::get_subnet_rewards_receipt(subnet_id, epoch);
+			result.encode()
+		}
+		fn get_subnet_registration_cost(memory_mb: u128) -> u128 {
+			let result = Network::get_subnet_registration_cost_quote(memory_mb);
+			result
+		}
+		fn get_subnet_metadata(subnet_id: u32) -> Vec<u8> {
+			let result = Network::get_subnet_metadata(subnet_id);
+			result.encode()
+		}
+		fn get_subnet_node_by_params(subnet_id: u32, a: Vec<u8>) -> Vec<u8> {
+			let result = Network::get_subnet_node_by_params(subnet_id, a);
+			result.encode()
+		}
+	}
+
+	impl rewards_custom_rpc_runtime_api::RewardsRuntimeApi<Block> for Runtime {
+		fn get_emission_curve() -> Vec<u8> {
+			let result = Rewards::get_emission_curve();
+			result.encode()
+		}
+		fn get_block_subsidy(block: u64) -> u128 {
+			Rewards::block_subsidy(block)
+		}
+		fn get_projected_issuance(block: u64) -> u128 {
+			Rewards::get_projected_issuance(block)
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -0,0 +1,162 @@
+use crate::*;
+use frame_support::{
+	assert_ok, BoundedVec,
+	traits::{Currency, Get, ReservableCurrency},
+};
+use pallet_network::SubnetVote;
+use pallet_subnet_democracy::{Conviction, PropCount, PropsType, RegistrationSubnetData, SubnetNode, VoteType};
+use sp_core::OpaquePeerId as PeerId;
+
+const EXISTING_SUBNET_PATH: &str = "hf/baluga";
+const SUBNET_PATH: &str = "hf/llama2";
+const SUBNET_MEMORY_MB: u128 = 50000;
+const DEPOSIT_AMOUNT: u128 = 10000000000000000000000; // 10,000
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+		.build_storage()
+		.unwrap()
+		.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn account(id: u32) -> AccountId {
+	[id as u8; 32].into()
+}
+
+fn peer(id: u32) -> PeerId {
+	let peer_id = format!("QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N{id}");
+	PeerId(peer_id.into())
+}
+
+fn subnet_data(path: &str) -> RegistrationSubnetData<<Runtime as pallet_subnet_democracy::Config>::MaxPathLength> {
+	RegistrationSubnetData {
+		path: path.as_bytes().to_vec().try_into().unwrap(),
+		memory_mb: SUBNET_MEMORY_MB,
+		registration_blocks: pallet_network::MinSubnetRegistrationBlocks::<Runtime>::get(),
+	}
+}
+
+fn subnet_initialization_cost() -> u128 {
+	<Network as SubnetVote<RuntimeOrigin, AccountId, <Runtime as pallet_network::Config>::MaxPathLength>>::get_subnet_initialization_cost(
+		SUBNET_MEMORY_MB
+	)
+}
+
+fn min_subnet_nodes() -> u32 {
+	<Network as SubnetVote<RuntimeOrigin, AccountId, <Runtime as pallet_network::Config>::MaxPathLength>>::get_min_subnet_nodes(
+		SUBNET_MEMORY_MB
+	)
+}
+
+// Registers a subnet whose ``n`` submittable nodes each stake ``stake`` and are the democracy voters
+fn build_voters_subnet(n: u32, stake: u128) {
+	let _ = Balances::deposit_creating(&account(0), subnet_initialization_cost() + 1000);
+
+	assert_ok!(
+		Network::register_subnet(
+			RuntimeOrigin::signed(account(0)),
+			subnet_data(EXISTING_SUBNET_PATH),
+		)
+	);
+
+	let subnet_id = pallet_network::SubnetPaths::<Runtime>::get(EXISTING_SUBNET_PATH.as_bytes().to_vec()).unwrap();
+
+	for i in 0..n {
+		let _ = Balances::deposit_creating(&account(i), stake + 100000);
+		assert_ok!(
+			Network::add_subnet_node(
+				RuntimeOrigin::signed(account(i)),
+				subnet_id,
+				peer(i),
+				stake,
+				None,
+				None,
+				None,
+			)
+		);
+	}
+
+	let submit_epochs = pallet_network::MinRequiredSubnetConsensusSubmitEpochs::<Runtime>::get();
+	System::set_block_number(System::block_number() + (submit_epochs * EpochLength::get()) as BlockNumber + 1);
+}
+
+#[test]
+fn test_democracy_activate_registers_subnet_in_network() {
+	new_test_ext().execute_with(|| {
+		let n_voters = min_subnet_nodes();
+		let min_stake = pallet_network::MinStakeBalance::<Runtime>::get();
+		// --- Each voter stakes enough for the voters to reach the quorum together
+		let voter_stake = min_stake.max(Quorum::get() / n_voters as u128 + 1);
+		build_voters_subnet(n_voters, voter_stake);
+
+		// --- Bootstrap nodes of the proposed subnet
+		let start = n_voters;
+		let end = n_voters * 2;
+		let mut subnet_nodes: Vec<SubnetNode<AccountId>> = Vec::new();
+		for n in start..end {
+			let _ = Balances::deposit_creating(&account(n), DEPOSIT_AMOUNT);
+			subnet_nodes.push(SubnetNode { account_id: account(n), peer_id: peer(n) });
+		}
+
+		let _ = Balances::deposit_creating(&account(0), subnet_initialization_cost() + 1000);
+
+		assert_ok!(
+			SubnetDemocracy::propose(
+				RuntimeOrigin::signed(account(0)),
+				subnet_data(SUBNET_PATH),
+				subnet_nodes,
+				PropsType::Activate,
+				None,
+				BoundedVec::new(),
+				None,
+			)
+		);
+		let proposal_index = PropCount::<Runtime>::get() - 1;
+
+		for n in start..end {
+			assert_ok!(SubnetDemocracy::verify_proposal(RuntimeOrigin::signed(account(n)), proposal_index));
+		}
+
+		assert_ok!(SubnetDemocracy::activate_proposal(RuntimeOrigin::signed(account(0)), proposal_index));
+
+		for n in start..end {
+			assert_ok!(SubnetDemocracy::bond_proposal(RuntimeOrigin::signed(account(n)), proposal_index));
+		}
+
+		System::set_block_number(System::block_number() + VerifyPeriod::get() + 1);
+
+		for n in 0..n_voters {
+			let _ = Balances::deposit_creating(&account(n), voter_stake);
+			assert_ok!(
+				SubnetDemocracy::cast_vote(
+					RuntimeOrigin::signed(account(n)),
+					proposal_index,
+					voter_stake,
+					VoteType::Yay,
+					Conviction::None,
+				)
+			);
+		}
+
+		System::set_block_number(System::block_number() + VotingPeriod::get() + 1);
+
+		assert_ok!(SubnetDemocracy::execute(RuntimeOrigin::signed(account(0)), proposal_index));
+
+		// --- The subnet and its bootstrap nodes are registered through the runtime's ``SubnetVote``
+		let subnet_id = pallet_network::SubnetPaths::<Runtime>::get(SUBNET_PATH.as_bytes().to_vec()).unwrap();
+		let subnet = pallet_network::SubnetsData::<Runtime>::get(subnet_id).unwrap();
+		assert_eq!(subnet.initialized, System::block_number() as u64);
+		assert_eq!(pallet_network::TotalSubnetNodes::<Runtime>::get(subnet_id), end - start);
+
+		for n in start..end {
+			let subnet_node = pallet_network::SubnetNodesData::<Runtime>::get(subnet_id, account(n));
+			assert_eq!(subnet_node.peer_id, peer(n));
+			assert_eq!(Balances::reserved_balance(&account(n)), 0);
+			assert_eq!(pallet_network::AccountSubnetStake::<Runtime>::get(account(n), subnet_id), min_stake);
+		}
+
+		assert_eq!(Balances::reserved_balance(&account(0)), 0);
+	})
+}